@@ -1,39 +1,39 @@
-use crate::app::Tool::{Jira, ServiceStatus, TokenGenerator};
 use crate::client::auth_zero::api::{AuthZeroApi, ImmediateAuthZeroApi};
 use crate::client::healthcheck::api::{HealthcheckApi, ImmediateHealthcheckApi};
 use crate::client::jira::api::{ImmediateJiraApi, JiraApi};
-use crate::config::loader::ConfigLoader;
+use crate::config::loader::{CONFIG_VERSION, ConfigLoader};
 use crate::config::model::Config;
+use crate::config::validate::Problem;
+use crate::error::model::ConfigError;
 use crate::event::events::AppEvent::*;
 use crate::event::events::GenericEvent::{
     CopyToClipboard, OpenInBrowser, Quit, QuitConfirm, SetFocus,
 };
-use crate::event::events::JiraEvent::ScanTickets;
-use crate::event::events::ServiceStatusEvent::Scan;
 use crate::event::events::{AppEvent, Event, GenericEvent};
 use crate::event::handler::EventHandler;
-use crate::event::handlers::config::{
-    jira as jira_config, service_status as service_status_config,
-    token_generator as token_generator_config,
-};
-use crate::event::handlers::tools::{jira, service_status, token_generator};
 use crate::event::sender::EventSender;
 use crate::input::key_bindings::register_bindings;
 use crate::input::key_context::KeyContext;
-use crate::input::key_context::KeyContext::{
-    Editing, Global, List, Logs, Popup as PopupCtx, TokenGen, ToolConfigEditing, ToolIgnore,
-};
+use crate::input::key_context::KeyContext::{Editing, Global, List, Logs, Popup as PopupCtx};
 use crate::input::key_event_map::KeyEventMap;
 use crate::popup::model::Popup;
-pub(crate) use crate::state::app::{AppFocus, Tool};
+pub(crate) use crate::state::app::{AppFocus, ToolId};
 use crate::state::log::{LogEntry, LogLevel, log_source};
+use crate::token_export::token_file::write_token_file;
+use crate::token_export::token_server::{self, Access, TokenStore};
+use crate::tools;
 use crate::ui::widgets::popup::{Part, Type};
+use crate::ui::widgets::tools::logs;
 use crate::utils::update_list_state;
 use crate::{state::app::AppState, ui::layout, ui::widgets::*};
 use crossterm::event::{self, KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{DefaultTerminal, Frame};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// How often config.yaml is checked for outside edits.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 /// The main application which holds the state and logic of the application.
 pub struct App {
@@ -50,6 +50,11 @@ pub struct App {
     pub(crate) jira_api: Arc<dyn JiraApi>,
     pub(crate) auth_zero_api: Arc<dyn AuthZeroApi>,
     pub(crate) healthcheck_api: Arc<dyn HealthcheckApi>,
+
+    // Latest tokens, shared with the token server
+    pub(crate) token_store: TokenStore,
+    /// The port the token server was started on, and its task.
+    token_server: Option<(u16, JoinHandle<()>)>,
 }
 
 impl App {
@@ -70,34 +75,73 @@ impl App {
             jira_api: Arc::new(ImmediateJiraApi::new()),
             auth_zero_api: Arc::new(ImmediateAuthZeroApi::new()),
             healthcheck_api: Arc::new(ImmediateHealthcheckApi::new()),
+
+            token_store: TokenStore::default(),
+            token_server: None,
         }
     }
 
+    /// Starts the app without a config, showing what's wrong with config.yaml
+    /// until it is fixed and reloads.
+    pub fn with_config_problems(mut self, problems: Vec<Problem>) -> Self {
+        self.state.config_problems = problems;
+        self
+    }
+
     pub async fn run(mut self, mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
         // Log app startup
-        self.state.log.push_log(LogEntry::new(
-            LogLevel::Info,
-            log_source::APP,
-            "App started — config loaded",
-        ));
+        if self.state.config_problems.is_empty() {
+            self.state.log.push_log(LogEntry::new(
+                LogLevel::Info,
+                log_source::APP,
+                "App started — config loaded",
+            ));
+        } else {
+            let problems = ConfigError::Invalid(self.state.config_problems.clone());
+            self.state.log.push_log(
+                LogEntry::new(
+                    LogLevel::Warning,
+                    log_source::APP,
+                    "App started without config — config.yaml has problems",
+                )
+                .with_detail(problems.to_string()),
+            );
+        }
+        self.log_config_migration();
+
+        for job in tools::all().iter().flat_map(|tool| tool.jobs()) {
+            let async_sender = self.event_sender.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(job.every);
+                loop {
+                    interval.tick().await; // This should go first.
+                    async_sender.send_event(job.event.clone());
+                }
+            });
+        }
 
         let async_sender = self.event_sender.clone();
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_mins(15));
+            let mut interval = tokio::time::interval(CONFIG_POLL_INTERVAL);
             loop {
-                interval.tick().await; // This should go first.
-                async_sender.send_service_status_event(Scan);
-                async_sender.send_jira_event(ScanTickets);
+                interval.tick().await;
+                async_sender.send_app_event(ReloadConfig);
             }
         });
 
+        self.sync_token_server();
+
         // Register bindings
         register_bindings(&mut self.key_event_map);
 
         while self.running {
             terminal.draw(|frame| self.render(frame))?;
             match self.event_handler.next().await? {
-                Event::Tick => {}
+                Event::Tick => {
+                    for tool in tools::all() {
+                        tool.handle_tick(&mut self);
+                    }
+                }
                 Event::Crossterm(event) => match event {
                     event::Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                         self.handle_key_events(key_event)?
@@ -105,22 +149,91 @@ impl App {
                     _ => {}
                 },
                 Event::App(event) => self.handle_app_event(event),
-                Event::ServiceStatus(event) => service_status::handle_event(&mut self, event),
-                Event::ServiceStatusConfig(event) => {
-                    service_status_config::handle_event(&mut self, event)
-                }
-                Event::TokenGenerator(event) => token_generator::handle_event(&mut self, event),
-                Event::TokenGeneratorConfig(event) => {
-                    token_generator_config::handle_event(&mut self, event)
-                }
-                Event::Jira(event) => jira::handle_event(&mut self, event),
-                Event::JiraConfig(event) => jira_config::handle_event(&mut self, event),
                 Event::Generic(event) => self.handle_generic_event(event),
+                event => tools::dispatch(&mut self, event),
             }
         }
         Ok(())
     }
 
+    /// Starts, moves or stops the token server to match the config's port.
+    fn sync_token_server(&mut self) {
+        let port = self
+            .config
+            .tokengenerator
+            .server
+            .as_ref()
+            .map(|server| server.port);
+        if self.token_server.as_ref().map(|(running, _)| *running) == port {
+            return;
+        }
+        if let Some((old_port, task)) = self.token_server.take() {
+            task.abort();
+            self.event_sender.send_app_event(AppLog(LogEntry::new(
+                LogLevel::Info,
+                log_source::TOKEN_GENERATOR,
+                format!("Token server on port {} stopped", old_port),
+            )));
+        }
+        self.token_server = port.map(|port| (port, self.start_token_server(port)));
+    }
+
+    /// Serves tokens on `port`, to callers presenting a secret that is written to
+    /// `~/.devtool/token-server.secret` and changes every time the server starts.
+    fn start_token_server(&self, port: u16) -> JoinHandle<()> {
+        let sender = self.event_sender.clone();
+        let store = self.token_store.clone();
+        tokio::spawn(async move {
+            let access = Access::new(port);
+            let Some(home) = dirs::home_dir() else {
+                sender.send_app_event(AppLog(LogEntry::new(
+                    LogLevel::Error,
+                    log_source::TOKEN_GENERATOR,
+                    "Token server not started — no home directory to save its secret in",
+                )));
+                return;
+            };
+            let secret_path = home.join(".devtool").join("token-server.secret");
+            if let Err(e) = write_token_file(&secret_path, access.secret()) {
+                sender.send_app_event(AppLog(
+                    LogEntry::new(
+                        LogLevel::Error,
+                        log_source::TOKEN_GENERATOR,
+                        "Token server not started — its secret couldn't be saved",
+                    )
+                    .with_detail(format!("{}: {}", secret_path.display(), e)),
+                ));
+                return;
+            }
+            match tokio::net::TcpListener::bind(("127.0.0.1", port)).await {
+                Ok(listener) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Info,
+                            log_source::TOKEN_GENERATOR,
+                            format!("Token server listening on http://127.0.0.1:{}", port),
+                        )
+                        .with_detail(format!(
+                            "Requests need Authorization: Bearer <the secret in {}>",
+                            secret_path.display()
+                        )),
+                    ));
+                    token_server::serve(listener, store, access).await;
+                }
+                Err(e) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::TOKEN_GENERATOR,
+                            format!("Token server could not start on port {}", port),
+                        )
+                        .with_detail(e.to_string()),
+                    ));
+                }
+            }
+        })
+    }
+
     fn handle_app_event(&mut self, app_event: AppEvent) {
         match app_event {
             OpenLogs => {
@@ -197,11 +310,7 @@ impl App {
                 if let Some((tool, now_enabled)) = self.state.config_editor.toggle_selected() {
                     // If the user is trying to enable a feature, check minimum config is present.
                     // If not, revert the toggle so the feature stays disabled.
-                    let has_min_config = match tool {
-                        ServiceStatus => !self.config.servicestatus.is_empty(),
-                        TokenGenerator => !self.config.tokengenerator.services.is_empty(),
-                        Jira => self.config.jira.is_some(),
-                    };
+                    let has_min_config = tool.tool().is_configured(&self.config);
                     if now_enabled && !has_min_config {
                         // Revert — toggle back to disabled without persisting.
                         self.state.config_editor.toggle_selected();
@@ -221,19 +330,15 @@ impl App {
                     self.state.focus = AppFocus::ToolConfig(item.tool);
                 }
             }
+            ReloadConfig => self.reload_config(),
             CloseToolConfig => {
                 // Close inline edit form if open, otherwise exit tool config.
-                let ss_form_open = self.state.service_status_config_editor.has_open_form();
-                let tg_form_open = self.state.token_generator_config_editor.has_open_form();
-                let jira_form_open = self.state.jira_config_editor.has_open_form();
-                if ss_form_open {
-                    self.state.service_status_config_editor.close_form();
-                } else if tg_form_open {
-                    self.state.token_generator_config_editor.close_form();
-                } else if jira_form_open {
-                    self.state.jira_config_editor.close_form();
-                } else {
-                    self.state.focus = AppFocus::Config;
+                match tools::all()
+                    .iter()
+                    .find(|tool| tool.has_open_config_form(&self.state))
+                {
+                    Some(tool) => tool.close_config_form(&mut self.state),
+                    None => self.state.focus = AppFocus::Config,
                 }
             }
         }
@@ -253,18 +358,80 @@ impl App {
             }
             QuitConfirm => self.running = false,
             SetFocus(focus) => self.state.focus = focus,
-            CopyToClipboard => match self.state.current_tool {
-                ServiceStatus => service_status::handle_generic_event(self, CopyToClipboard),
-                TokenGenerator => token_generator::handle_generic_event(self, CopyToClipboard),
-                _ => {}
-            },
-            OpenInBrowser => match self.state.current_tool {
-                ServiceStatus => service_status::handle_generic_event(self, OpenInBrowser),
-                Jira => jira::handle_generic_event(self, OpenInBrowser),
-                _ => {}
-            },
+            CopyToClipboard | OpenInBrowser => {
+                let tool = self.state.current_tool.tool();
+                tool.handle_generic_event(self, event);
+            }
         }
     }
+    /// Swaps in config.yaml if it has been edited since we last read or wrote it.
+    /// An invalid file is reported and the running config kept.
+    fn reload_config(&mut self) {
+        let Some(result) = self.config_loader.reload_if_changed() else {
+            return;
+        };
+        self.log_config_migration();
+        let broken = !self.state.config_problems.is_empty();
+        let config = match result {
+            Ok(config) if config == self.config && !broken => return,
+            Ok(config) => config,
+            // Already showing the problems, so just bring them up to date.
+            Err(ConfigError::Invalid(problems)) if broken => {
+                self.state.config_problems = problems;
+                return;
+            }
+            Err(e) => {
+                self.event_sender.send_app_event(AppLog(
+                    LogEntry::new(
+                        LogLevel::Error,
+                        log_source::APP,
+                        "Config reload failed — keeping the previous config",
+                    )
+                    .with_detail(e.to_string()),
+                ));
+                return;
+            }
+        };
+
+        self.state.config_problems.clear();
+        let old = std::mem::replace(&mut self.config, config);
+        for tool in tools::all() {
+            // Open forms point at entries by index, which may have moved.
+            tool.close_config_form(&mut self.state);
+            tool.reload_state(self, &old);
+        }
+        self.state
+            .config_editor
+            .sync_from_features(&self.config.features);
+        self.state.rebuild_tool_list(self.config.jira.is_some());
+        self.sync_token_server();
+        self.event_sender.send_app_event(AppLog(LogEntry::new(
+            LogLevel::Info,
+            log_source::APP,
+            "Config reloaded from disk",
+        )));
+    }
+
+    /// Logs the upgrade the loader made to config.yaml on its last read, if any.
+    fn log_config_migration(&mut self) {
+        if let Some(migrated) = self.config_loader.take_migrated() {
+            self.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Notice,
+                    log_source::APP,
+                    format!(
+                        "Config upgraded from version {} to {}",
+                        migrated.from, CONFIG_VERSION
+                    ),
+                )
+                .with_detail(format!(
+                    "The original was kept at {}",
+                    migrated.backup.display()
+                )),
+            ));
+        }
+    }
+
     fn render(&mut self, frame: &mut Frame) {
         let areas = layout::main(frame.area(), self.state.effective_focus());
 
@@ -276,7 +443,7 @@ impl App {
 
         if matches!(self.state.focus, AppFocus::Logs) {
             let focused = true;
-            tools::logs::render(frame, areas.content, &self.state.log, focused);
+            logs::render(frame, areas.content, &self.state.log, focused);
         } else {
             tool::render(frame, areas.content, &mut self.state, &self.config);
         }
@@ -326,28 +493,12 @@ impl App {
                     stack.push(List);
                 }
                 AppFocus::Tool => {
-                    stack.push(KeyContext::Tool(self.state.current_tool));
-                    if self.state.current_tool == TokenGenerator {
-                        stack.push(TokenGen(self.state.token_generator.focus))
-                    } else {
-                        stack.push(ToolIgnore(TokenGenerator));
-                    }
+                    let tool = self.state.current_tool.tool();
+                    stack.extend(tool.key_contexts(&self.state));
                 }
                 AppFocus::ToolConfig(tool) => {
                     // Use editing context when inline edit form is open
-                    if tool == ServiceStatus
-                        && self.state.service_status_config_editor.has_open_form()
-                    {
-                        stack.push(Editing(ServiceStatus));
-                    } else if tool == TokenGenerator
-                        && self.state.token_generator_config_editor.has_open_form()
-                    {
-                        stack.push(Editing(TokenGenerator));
-                    } else if tool == Jira && self.state.jira_config_editor.has_open_form() {
-                        stack.push(ToolConfigEditing(Jira));
-                    } else {
-                        stack.push(KeyContext::ToolConfig(tool));
-                    }
+                    stack.push(tool.tool().config_key_context(&self.state));
                 }
                 AppFocus::Config => {
                     stack.push(KeyContext::Config);
@@ -356,7 +507,7 @@ impl App {
                     stack.push(Logs);
                 }
                 AppFocus::JiraInput => {
-                    stack.push(Editing(Jira));
+                    stack.push(Editing(ToolId::Jira));
                 }
             }
         }
@@ -1,28 +1,56 @@
 use crate::client::auth_zero::auth_zero_client;
+use crate::client::auth_zero::jwt::{self, Expectations, Jwks};
 use crate::config::model::TokenGenerator;
 use crate::error::model::ClientError;
-use crate::event::events::TokenGeneratorEvent::{TokenFailed, TokenGenerated};
+use crate::event::events::TokenGeneratorEvent::{
+    TokenFailed, TokenGenerated, TokenVerified, TokenVerifyFailed,
+};
 use crate::event::sender::EventSender;
-use reqwest::Client;
+use reqwest::{Client, Url};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Where the signing keys for a verification come from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JwksSource {
+    Url(String),
+    File(PathBuf),
+}
 
 pub trait AuthZeroApi {
     fn fetch_token(
         &self,
         service_idx: usize,
         env_idx: usize,
+        generation: u64,
         config: TokenGenerator,
         sender: EventSender,
     );
+
+    fn verify_token(
+        &self,
+        token: String,
+        source: JwksSource,
+        expected: Expectations,
+        cache_ttl: Duration,
+        sender: EventSender,
+    );
 }
 
+type JwksCache = Arc<Mutex<HashMap<String, (Instant, Jwks)>>>;
+
 pub struct ImmediateAuthZeroApi {
     client: Client,
+    jwks_cache: JwksCache,
 }
 
 impl ImmediateAuthZeroApi {
     pub fn new() -> Self {
         Self {
             client: Client::new(),
+            jwks_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
@@ -38,6 +66,7 @@ impl AuthZeroApi for ImmediateAuthZeroApi {
         &self,
         service_idx: usize,
         env_idx: usize,
+        generation: u64,
         config: TokenGenerator,
         sender: EventSender,
     ) {
@@ -45,18 +74,96 @@ impl AuthZeroApi for ImmediateAuthZeroApi {
         tokio::spawn(async move {
             match get_token(client, service_idx, env_idx, config).await {
                 Ok(token) => {
-                    sender.send_token_generator_event(TokenGenerated(token, service_idx, env_idx));
+                    sender.send_token_generator_event(TokenGenerated(
+                        token,
+                        service_idx,
+                        env_idx,
+                        generation,
+                    ));
                 }
                 Err(err) => {
                     sender.send_token_generator_event(TokenFailed(
                         err.to_string(),
                         service_idx,
                         env_idx,
+                        generation,
                     ));
                 }
             }
         });
     }
+
+    fn verify_token(
+        &self,
+        token: String,
+        source: JwksSource,
+        expected: Expectations,
+        cache_ttl: Duration,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        let cache = self.jwks_cache.clone();
+        tokio::spawn(async move {
+            match load_jwks(client, &source, &cache, cache_ttl).await {
+                Ok(jwks) => {
+                    let now = chrono::Utc::now().timestamp();
+                    let report = jwt::verify(&token, &jwks, &expected, now);
+                    sender.send_token_generator_event(TokenVerified(report));
+                }
+                Err(err) => {
+                    sender.send_token_generator_event(TokenVerifyFailed(err));
+                }
+            }
+        });
+    }
+}
+
+/// The issuer Auth0 stamps on tokens from the tenant serving `token_url`.
+pub fn issuer_from_token_url(token_url: &str) -> Option<String> {
+    Url::parse(token_url)
+        .and_then(|url| url.join("/"))
+        .ok()
+        .map(String::from)
+}
+
+/// The tenant's published key set, derived from its token endpoint.
+pub fn jwks_url_from_token_url(token_url: &str) -> Option<String> {
+    Url::parse(token_url)
+        .and_then(|url| url.join("/.well-known/jwks.json"))
+        .ok()
+        .map(String::from)
+}
+
+async fn load_jwks(
+    client: Client,
+    source: &JwksSource,
+    cache: &JwksCache,
+    cache_ttl: Duration,
+) -> Result<Jwks, String> {
+    match source {
+        JwksSource::File(path) => {
+            let contents = std::fs::read_to_string(path)
+                .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+            serde_json::from_str(&contents)
+                .map_err(|e| format!("Invalid JWKS in {}: {}", path.display(), e))
+        }
+        JwksSource::Url(url) => {
+            if let Some((fetched_at, jwks)) = cache.lock().unwrap().get(url)
+                && fetched_at.elapsed() < cache_ttl
+            {
+                return Ok(jwks.clone());
+            }
+
+            let jwks = auth_zero_client::get_jwks(client, url)
+                .await
+                .map_err(|e| e.to_string())?;
+            cache
+                .lock()
+                .unwrap()
+                .insert(url.clone(), (Instant::now(), jwks.clone()));
+            Ok(jwks)
+        }
+    }
 }
 
 async fn get_token(
@@ -78,3 +185,133 @@ async fn get_token(
     .await?
     .access_token)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::auth_zero::jwt::Jwk;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn jwks() -> Jwks {
+        Jwks {
+            keys: vec![Jwk {
+                kid: Some("key-1".to_string()),
+                kty: "RSA".to_string(),
+                n: Some("AQAB".to_string()),
+                e: Some("AQAB".to_string()),
+            }],
+        }
+    }
+
+    #[test]
+    fn issuer_from_token_url_returns_tenant_root() {
+        assert_eq!(
+            issuer_from_token_url("https://tenant.eu.auth0.com/oauth/token"),
+            Some("https://tenant.eu.auth0.com/".to_string())
+        );
+    }
+
+    #[test]
+    fn jwks_url_from_token_url_returns_well_known_path() {
+        assert_eq!(
+            jwks_url_from_token_url("https://tenant.eu.auth0.com/oauth/token"),
+            Some("https://tenant.eu.auth0.com/.well-known/jwks.json".to_string())
+        );
+    }
+
+    #[test]
+    fn urls_from_invalid_token_url_are_none() {
+        assert_eq!(issuer_from_token_url(""), None);
+        assert_eq!(jwks_url_from_token_url("not a url"), None);
+    }
+
+    #[tokio::test]
+    async fn load_jwks_reads_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("jwks.json");
+        fs::write(
+            &path,
+            r#"{"keys":[{"kid":"key-1","kty":"RSA","n":"AQAB","e":"AQAB"}]}"#,
+        )
+        .unwrap();
+
+        let cache = JwksCache::default();
+        let result = load_jwks(
+            Client::new(),
+            &JwksSource::File(path),
+            &cache,
+            Duration::ZERO,
+        )
+        .await;
+
+        assert_eq!(result, Ok(jwks()));
+    }
+
+    #[tokio::test]
+    async fn load_jwks_reports_missing_file() {
+        let cache = JwksCache::default();
+        let result = load_jwks(
+            Client::new(),
+            &JwksSource::File(PathBuf::from("/nonexistent/jwks.json")),
+            &cache,
+            Duration::ZERO,
+        )
+        .await;
+
+        assert!(
+            result
+                .unwrap_err()
+                .starts_with("Unable to read /nonexistent/jwks.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn load_jwks_uses_cache_within_ttl() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/.well-known/jwks.json")
+            .with_status(200)
+            .with_body(r#"{"keys":[{"kid":"key-1","kty":"RSA","n":"AQAB","e":"AQAB"}]}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = format!("{}/.well-known/jwks.json", server.url());
+        let source = JwksSource::Url(url);
+        let cache = JwksCache::default();
+        let ttl = Duration::from_secs(60);
+
+        let first = load_jwks(Client::new(), &source, &cache, ttl).await;
+        let second = load_jwks(Client::new(), &source, &cache, ttl).await;
+
+        assert_eq!(first, Ok(jwks()));
+        assert_eq!(second, Ok(jwks()));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn load_jwks_refetches_after_ttl() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/.well-known/jwks.json")
+            .with_status(200)
+            .with_body(r#"{"keys":[{"kid":"key-1","kty":"RSA","n":"AQAB","e":"AQAB"}]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let url = format!("{}/.well-known/jwks.json", server.url());
+        let source = JwksSource::Url(url);
+        let cache = JwksCache::default();
+
+        load_jwks(Client::new(), &source, &cache, Duration::ZERO)
+            .await
+            .unwrap();
+        load_jwks(Client::new(), &source, &cache, Duration::ZERO)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+}
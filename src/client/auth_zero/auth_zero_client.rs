@@ -1,9 +1,10 @@
+use crate::client::auth_zero::jwt::Jwks;
 use crate::client::auth_zero::models::AuthZeroResponse;
 use crate::client::auth_zero::models::AuthZeroResponse::ErrorResponse as AuthZeroErrorResponse;
 use crate::client::auth_zero::models::AuthZeroResponse::TokenResponse as AuthZeroTokenResponse;
 use crate::client::auth_zero::models::TokenResponse;
 use crate::error::model::ClientError;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -38,6 +39,19 @@ pub async fn get_token(
     }
 }
 
+pub async fn get_jwks(client: Client, jwks_url: &str) -> Result<Jwks, ClientError> {
+    let response = client
+        .get(jwks_url)
+        .timeout(Duration::from_secs(3))
+        .send()
+        .await?;
+
+    match response.status() {
+        StatusCode::OK => Ok(serde_json::from_str(response.text().await?.as_str())?),
+        status => Err(ClientError::Api(format!("JWKS request failed: {}", status))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +139,51 @@ mod tests {
 
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn get_jwks_returns_keys() {
+        let mut server = mockito::Server::new_async().await;
+
+        let response = serde_json::json!({
+            "keys": [{ "kid": "key-1", "kty": "RSA", "n": "AQAB", "e": "AQAB", "use": "sig" }]
+        })
+        .to_string();
+
+        let mock = server
+            .mock("GET", "/.well-known/jwks.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(response)
+            .create_async()
+            .await;
+
+        let url = format!("{}/.well-known/jwks.json", server.url());
+        let jwks = get_jwks(Client::new(), &url).await.unwrap();
+
+        assert_eq!(jwks.keys.len(), 1);
+        assert_eq!(jwks.keys[0].kid.as_deref(), Some("key-1"));
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_jwks_returns_error_for_non_ok_status() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/.well-known/jwks.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let url = format!("{}/.well-known/jwks.json", server.url());
+        let result = get_jwks(Client::new(), &url).await;
+
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "JWKS request failed: 404 Not Found"
+        );
+
+        mock.assert_async().await;
+    }
 }
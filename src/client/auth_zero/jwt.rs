@@ -0,0 +1,446 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::signature::{
+    RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512,
+    RsaParameters, RsaPublicKeyComponents,
+};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Jwk {
+    pub kid: Option<String>,
+    pub kty: String,
+    pub n: Option<String>,
+    pub e: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    alg: String,
+    kid: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Claims {
+    iss: Option<String>,
+    aud: Option<Audience>,
+    exp: Option<i64>,
+    nbf: Option<i64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Audience {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Audience {
+    fn contains(&self, audience: &str) -> bool {
+        match self {
+            Audience::One(a) => a == audience,
+            Audience::Many(a) => a.iter().any(|a| a == audience),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Audience::One(a) => a.clone(),
+            Audience::Many(a) => a.join(", "),
+        }
+    }
+}
+
+/// The issuer and audience a token must carry to be accepted downstream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expectations {
+    pub issuer: String,
+    pub audience: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: true,
+            detail: detail.into(),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationReport {
+    pub checks: Vec<Check>,
+}
+
+impl VerificationReport {
+    pub fn is_valid(&self) -> bool {
+        !self.checks.is_empty() && self.checks.iter().all(|c| c.passed)
+    }
+
+    pub fn rejections(&self) -> Vec<&Check> {
+        self.checks.iter().filter(|c| !c.passed).collect()
+    }
+}
+
+/// Verifies the signature, issuer, audience and validity window of `token`.
+///
+/// Every check is run even after one fails so the report explains all of the
+/// reasons a downstream API would reject the token, not just the first.
+pub fn verify(token: &str, jwks: &Jwks, expected: &Expectations, now: i64) -> VerificationReport {
+    let mut checks = Vec::new();
+
+    let segments: Vec<&str> = token.trim().split('.').collect();
+    if segments.len() != 3 {
+        checks.push(Check::fail(
+            "Format",
+            format!(
+                "Expected 3 dot-separated segments, found {}",
+                segments.len()
+            ),
+        ));
+        return VerificationReport { checks };
+    }
+
+    let header: Header = match decode_segment(segments[0]) {
+        Ok(h) => h,
+        Err(e) => {
+            checks.push(Check::fail("Format", format!("Header is unreadable: {e}")));
+            return VerificationReport { checks };
+        }
+    };
+    let claims: Claims = match decode_segment(segments[1]) {
+        Ok(c) => c,
+        Err(e) => {
+            checks.push(Check::fail("Format", format!("Payload is unreadable: {e}")));
+            return VerificationReport { checks };
+        }
+    };
+    let signature = match URL_SAFE_NO_PAD.decode(segments[2]) {
+        Ok(s) => s,
+        Err(e) => {
+            checks.push(Check::fail(
+                "Format",
+                format!("Signature is not base64url: {e}"),
+            ));
+            return VerificationReport { checks };
+        }
+    };
+    checks.push(Check::pass(
+        "Format",
+        format!("Well-formed JWT ({})", header.alg),
+    ));
+
+    checks.push(check_signature(
+        &header,
+        jwks,
+        format!("{}.{}", segments[0], segments[1]).as_bytes(),
+        &signature,
+    ));
+    checks.push(check_issuer(&claims, &expected.issuer));
+    checks.push(check_audience(&claims, &expected.audience));
+    checks.push(check_expiry(&claims, now));
+    if let Some(nbf) = claims.nbf
+        && nbf > now
+    {
+        checks.push(Check::fail(
+            "Not before",
+            format!("Not valid for another {}", describe_duration(nbf - now)),
+        ));
+    }
+
+    VerificationReport { checks }
+}
+
+fn decode_segment<T: DeserializeOwned>(segment: &str) -> Result<T, String> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("not base64url ({e})"))?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("not JSON ({e})"))
+}
+
+fn check_signature(header: &Header, jwks: &Jwks, message: &[u8], signature: &[u8]) -> Check {
+    const NAME: &str = "Signature";
+
+    let params: &RsaParameters = match header.alg.as_str() {
+        "RS256" => &RSA_PKCS1_2048_8192_SHA256,
+        "RS384" => &RSA_PKCS1_2048_8192_SHA384,
+        "RS512" => &RSA_PKCS1_2048_8192_SHA512,
+        alg => {
+            return Check::fail(
+                NAME,
+                format!("Unsupported algorithm {alg} — only RS256/RS384/RS512 can be verified"),
+            );
+        }
+    };
+
+    let rsa_keys: Vec<&Jwk> = jwks.keys.iter().filter(|k| k.kty == "RSA").collect();
+    let key = match &header.kid {
+        Some(kid) => rsa_keys.iter().find(|k| k.kid.as_deref() == Some(kid)),
+        None if rsa_keys.len() == 1 => rsa_keys.first(),
+        None => None,
+    };
+    let Some(key) = key else {
+        return Check::fail(
+            NAME,
+            format!(
+                "No signing key with kid '{}' in JWKS ({} keys) — token may come from another tenant or the keys were rotated",
+                header.kid.as_deref().unwrap_or("none"),
+                rsa_keys.len()
+            ),
+        );
+    };
+
+    let components = match (key.n.as_deref(), key.e.as_deref()) {
+        (Some(n), Some(e)) => match (URL_SAFE_NO_PAD.decode(n), URL_SAFE_NO_PAD.decode(e)) {
+            (Ok(n), Ok(e)) => RsaPublicKeyComponents { n, e },
+            _ => return Check::fail(NAME, "Signing key in JWKS is not valid base64url"),
+        },
+        _ => return Check::fail(NAME, "Signing key in JWKS has no modulus/exponent"),
+    };
+
+    match components.verify(params, message, signature) {
+        Ok(()) => Check::pass(
+            NAME,
+            format!("Valid for key {}", key.kid.as_deref().unwrap_or("(no kid)")),
+        ),
+        Err(_) => Check::fail(NAME, "Signature does not match the signing key"),
+    }
+}
+
+fn check_issuer(claims: &Claims, expected: &str) -> Check {
+    const NAME: &str = "Issuer";
+    match &claims.iss {
+        Some(iss) if iss == expected => Check::pass(NAME, iss.clone()),
+        Some(iss) => Check::fail(NAME, format!("Expected {expected}, found {iss}")),
+        None => Check::fail(NAME, "Token has no iss claim"),
+    }
+}
+
+fn check_audience(claims: &Claims, expected: &str) -> Check {
+    const NAME: &str = "Audience";
+    match &claims.aud {
+        Some(aud) if aud.contains(expected) => Check::pass(NAME, expected.to_string()),
+        Some(aud) => Check::fail(
+            NAME,
+            format!("Expected {expected}, found {}", aud.describe()),
+        ),
+        None => Check::fail(NAME, "Token has no aud claim"),
+    }
+}
+
+fn check_expiry(claims: &Claims, now: i64) -> Check {
+    const NAME: &str = "Expiry";
+    match claims.exp {
+        Some(exp) if exp > now => {
+            Check::pass(NAME, format!("Expires in {}", describe_duration(exp - now)))
+        }
+        Some(exp) => Check::fail(
+            NAME,
+            format!("Expired {} ago", describe_duration(now - exp)),
+        ),
+        None => Check::fail(NAME, "Token has no exp claim"),
+    }
+}
+
+fn describe_duration(seconds: i64) -> String {
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    match (h, m) {
+        (0, 0) => format!("{s}s"),
+        (0, m) => format!("{m}m {s}s"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{RSA_PKCS1_SHA256, RsaKeyPair};
+    use test_case::test_case;
+
+    const NOW: i64 = 1_700_000_000;
+    const ISSUER: &str = "https://tenant.auth0.com/";
+    const AUDIENCE: &str = "https://api.example.com";
+
+    fn key_pair() -> RsaKeyPair {
+        RsaKeyPair::from_pkcs8(include_bytes!("fixtures/rsa_test_key.pk8")).unwrap()
+    }
+
+    fn jwks(kid: &str) -> Jwks {
+        let public = RsaPublicKeyComponents::<Vec<u8>>::from(key_pair().public());
+        Jwks {
+            keys: vec![Jwk {
+                kid: Some(kid.to_string()),
+                kty: "RSA".to_string(),
+                n: Some(URL_SAFE_NO_PAD.encode(public.n)),
+                e: Some(URL_SAFE_NO_PAD.encode(public.e)),
+            }],
+        }
+    }
+
+    fn sign(header: serde_json::Value, claims: serde_json::Value) -> String {
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        let key_pair = key_pair();
+        let mut signature = vec![0; key_pair.public().modulus_len()];
+        key_pair
+            .sign(
+                &RSA_PKCS1_SHA256,
+                &SystemRandom::new(),
+                message.as_bytes(),
+                &mut signature,
+            )
+            .unwrap();
+        format!("{message}.{}", URL_SAFE_NO_PAD.encode(signature))
+    }
+
+    fn valid_claims() -> serde_json::Value {
+        serde_json::json!({ "iss": ISSUER, "aud": AUDIENCE, "exp": NOW + 3600 })
+    }
+
+    fn expectations() -> Expectations {
+        Expectations {
+            issuer: ISSUER.to_string(),
+            audience: AUDIENCE.to_string(),
+        }
+    }
+
+    fn failed(report: &VerificationReport) -> Vec<&'static str> {
+        report.rejections().iter().map(|c| c.name).collect()
+    }
+
+    #[test]
+    fn verify_accepts_valid_token() {
+        let token = sign(
+            serde_json::json!({ "alg": "RS256", "kid": "key-1" }),
+            valid_claims(),
+        );
+
+        let report = verify(&token, &jwks("key-1"), &expectations(), NOW);
+
+        assert!(report.is_valid(), "{:?}", report);
+        assert_eq!(report.checks.len(), 5);
+    }
+
+    #[test]
+    fn verify_accepts_audience_list() {
+        let token = sign(
+            serde_json::json!({ "alg": "RS256", "kid": "key-1" }),
+            serde_json::json!({ "iss": ISSUER, "aud": ["other", AUDIENCE], "exp": NOW + 60 }),
+        );
+
+        let report = verify(&token, &jwks("key-1"), &expectations(), NOW);
+
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn verify_uses_only_key_when_token_has_no_kid() {
+        let token = sign(serde_json::json!({ "alg": "RS256" }), valid_claims());
+
+        let report = verify(&token, &jwks("key-1"), &expectations(), NOW);
+
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn verify_rejects_unknown_kid() {
+        let token = sign(
+            serde_json::json!({ "alg": "RS256", "kid": "rotated" }),
+            valid_claims(),
+        );
+
+        let report = verify(&token, &jwks("key-1"), &expectations(), NOW);
+
+        assert_eq!(failed(&report), vec!["Signature"]);
+        assert!(report.checks[1].detail.contains("'rotated'"));
+    }
+
+    #[test]
+    fn verify_rejects_tampered_payload() {
+        let token = sign(
+            serde_json::json!({ "alg": "RS256", "kid": "key-1" }),
+            valid_claims(),
+        );
+        let segments: Vec<&str> = token.split('.').collect();
+        let forged = URL_SAFE_NO_PAD.encode(
+            serde_json::json!({ "iss": ISSUER, "aud": AUDIENCE, "exp": NOW + 99_999 }).to_string(),
+        );
+        let tampered = format!("{}.{}.{}", segments[0], forged, segments[2]);
+
+        let report = verify(&tampered, &jwks("key-1"), &expectations(), NOW);
+
+        assert_eq!(failed(&report), vec!["Signature"]);
+    }
+
+    #[test]
+    fn verify_reports_every_failing_claim() {
+        let token = sign(
+            serde_json::json!({ "alg": "RS256", "kid": "key-1" }),
+            serde_json::json!({ "iss": "https://other/", "aud": "wrong", "exp": NOW - 120, "nbf": NOW + 60 }),
+        );
+
+        let report = verify(&token, &jwks("key-1"), &expectations(), NOW);
+
+        assert_eq!(
+            failed(&report),
+            vec!["Issuer", "Audience", "Expiry", "Not before"]
+        );
+        assert_eq!(report.checks[4].detail, "Expired 2m 0s ago");
+    }
+
+    #[test]
+    fn verify_rejects_unsupported_algorithm() {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(valid_claims().to_string());
+        let token = format!("{header}.{payload}.c2ln");
+
+        let report = verify(&token, &jwks("key-1"), &expectations(), NOW);
+
+        assert_eq!(failed(&report), vec!["Signature"]);
+        assert!(report.checks[1].detail.contains("HS256"));
+    }
+
+    #[test_case("not-a-token"; "single segment")]
+    #[test_case("a.b"; "two segments")]
+    #[test_case("!!.e30.c2ln"; "header not base64")]
+    #[test_case("eyJhbGciOiJSUzI1NiJ9.bm9wZQ.c2ln"; "payload not json")]
+    fn verify_rejects_malformed_tokens(token: &str) {
+        let report = verify(token, &jwks("key-1"), &expectations(), NOW);
+
+        assert_eq!(failed(&report), vec!["Format"]);
+        assert!(!report.is_valid());
+    }
+
+    #[test_case(45, "45s")]
+    #[test_case(125, "2m 5s")]
+    #[test_case(7_260, "2h 1m")]
+    fn describe_duration_formats(seconds: i64, expected: &str) {
+        assert_eq!(describe_duration(seconds), expected);
+    }
+}
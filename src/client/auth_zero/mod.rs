@@ -1,3 +1,4 @@
 pub(crate) mod api;
 pub(crate) mod auth_zero_client;
+pub(crate) mod jwt;
 mod models;
@@ -10,6 +10,7 @@ pub trait HealthcheckApi {
     fn get_commit_ref(
         &self,
         service_idx: usize,
+        generation: u64,
         env: Environment,
         config: Vec<ServiceStatusConfig>,
         sender: EventSender,
@@ -38,6 +39,7 @@ impl HealthcheckApi for ImmediateHealthcheckApi {
     fn get_commit_ref(
         &self,
         service_idx: usize,
+        generation: u64,
         env: Environment,
         config: Vec<ServiceStatusConfig>,
         sender: EventSender,
@@ -46,13 +48,19 @@ impl HealthcheckApi for ImmediateHealthcheckApi {
         tokio::spawn(async move {
             match get_commit_ref(client, service_idx, &env, config).await {
                 Ok(commit) => {
-                    sender.send_service_status_event(GetCommitRefOk(commit, service_idx, env));
+                    sender.send_service_status_event(GetCommitRefOk(
+                        commit,
+                        service_idx,
+                        env,
+                        generation,
+                    ));
                 }
                 Err(err) => {
                     sender.send_service_status_event(GetCommitRefErrored(
                         err.to_string(),
                         service_idx,
                         env,
+                        generation,
                     ));
                 }
             }
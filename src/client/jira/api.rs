@@ -1,15 +1,99 @@
 use crate::client::jira::jira_client;
-use crate::client::jira::models::TicketResponse;
+use crate::client::jira::models::{AssignTo, NewIssue, NewWorklog, Transition};
 use crate::config::model::JiraConfig;
-use crate::error::model::ClientError;
 use crate::event::events::AppEvent::AppLog;
-use crate::event::events::JiraEvent::TicketRetrieved;
+use crate::event::events::JiraEvent::{
+    AssigneesFound, BoardFailed, BoardRetrieved, CommentAdded, CommentFailed, IssueCreateFailed,
+    IssueCreated, QueryFailed, QueryResults, TicketAssigned, TicketDetailsFailed,
+    TicketDetailsRetrieved, TicketRetrieved, TicketScanFailed, TicketScanned, TicketTransitioned,
+    TicketsRetrieved, TransitionsRetrieved, WatchingSet, WorklogAdded, WorklogFailed,
+};
 use crate::event::sender::EventSender;
 use crate::state::log::{LogEntry, LogLevel, log_source};
 use reqwest::Client;
 
 pub trait JiraApi {
-    fn fetch_ticket(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender);
+    /// Fetches a ticket to add from `site`, which `jira_config` points at.
+    fn fetch_ticket(
+        &self,
+        ticket_id: String,
+        site: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    /// Fetches several tickets for a bulk import in as few requests as possible.
+    fn fetch_tickets(
+        &self,
+        ticket_ids: Vec<String>,
+        site: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    /// Refreshes a watchlist ticket as part of a scan, reporting failure per ticket.
+    fn scan_ticket(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender);
+
+    fn fetch_details(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender);
+
+    fn search(&self, query_idx: usize, jql: String, jira_config: JiraConfig, sender: EventSender);
+
+    fn fetch_transitions(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender);
+
+    fn transition_ticket(
+        &self,
+        ticket_id: String,
+        transition: Transition,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    /// Fetches the columns of the configured agile board for the board view.
+    fn fetch_board(&self, board_id: u64, jira_config: JiraConfig, sender: EventSender);
+
+    fn create_issue(&self, issue: NewIssue, jira_config: JiraConfig, sender: EventSender);
+
+    fn add_comment(
+        &self,
+        ticket_id: String,
+        text: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    fn add_worklog(
+        &self,
+        ticket_id: String,
+        worklog: NewWorklog,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    /// Looks up who `ticket_id` can be assigned to, by name or email.
+    fn search_assignees(
+        &self,
+        ticket_id: String,
+        query: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    fn assign_ticket(
+        &self,
+        ticket_id: String,
+        to: AssignTo,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
+
+    /// Starts or stops watching `ticket_id` in Jira as the configured user.
+    fn set_watching(
+        &self,
+        ticket_id: String,
+        watch: bool,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    );
 }
 
 pub struct ImmediateJiraApi {
@@ -31,12 +115,18 @@ impl Default for ImmediateJiraApi {
 }
 
 impl JiraApi for ImmediateJiraApi {
-    fn fetch_ticket(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender) {
+    fn fetch_ticket(
+        &self,
+        ticket_id: String,
+        site: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
         let client = self.client.clone();
         tokio::spawn(async move {
-            match get_ticket(client, &ticket_id, &jira_config).await {
+            match jira_client::get(client, &jira_config, &ticket_id).await {
                 Ok(ticket) => {
-                    sender.send_jira_event(TicketRetrieved(ticket));
+                    sender.send_jira_event(TicketRetrieved(site, ticket));
                 }
                 Err(err) => {
                     sender.send_app_event(AppLog(
@@ -47,12 +137,272 @@ impl JiraApi for ImmediateJiraApi {
             }
         });
     }
-}
 
-async fn get_ticket(
-    client: Client,
-    ticket_id: &str,
-    config: &JiraConfig,
-) -> Result<TicketResponse, ClientError> {
-    jira_client::get(client, &config.url, ticket_id, &config.email, &config.token).await
+    fn fetch_tickets(
+        &self,
+        ticket_ids: Vec<String>,
+        site: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::get_many(client, &jira_config, &ticket_ids).await {
+                Ok(tickets) => {
+                    sender.send_jira_event(TicketsRetrieved(site, ticket_ids, tickets));
+                }
+                Err(err) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::JIRA,
+                            format!("Failed to import {} tickets", ticket_ids.len()),
+                        )
+                        .with_detail(err.to_string()),
+                    ));
+                }
+            }
+        });
+    }
+
+    fn scan_ticket(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::get(client, &jira_config, &ticket_id).await {
+                Ok(ticket) => {
+                    sender.send_jira_event(TicketScanned(ticket));
+                }
+                Err(err) => {
+                    sender.send_jira_event(TicketScanFailed(ticket_id, err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn fetch_details(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::get_details(client, &jira_config, &ticket_id).await {
+                Ok(details) => {
+                    sender.send_jira_event(TicketDetailsRetrieved(Box::new(details)));
+                }
+                Err(err) => {
+                    sender.send_jira_event(TicketDetailsFailed(ticket_id, err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn search(&self, query_idx: usize, jql: String, jira_config: JiraConfig, sender: EventSender) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::search(client, &jira_config, &jql).await {
+                Ok(tickets) => {
+                    sender.send_jira_event(QueryResults(query_idx, tickets));
+                }
+                Err(err) => {
+                    sender.send_jira_event(QueryFailed(query_idx, err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn fetch_transitions(&self, ticket_id: String, jira_config: JiraConfig, sender: EventSender) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::get_transitions(client, &jira_config, &ticket_id).await {
+                Ok(transitions) => {
+                    sender.send_jira_event(TransitionsRetrieved(ticket_id, transitions));
+                }
+                Err(err) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::JIRA,
+                            format!("Failed to get transitions for {}", ticket_id),
+                        )
+                        .with_detail(err.to_string()),
+                    ));
+                }
+            }
+        });
+    }
+
+    fn transition_ticket(
+        &self,
+        ticket_id: String,
+        transition: Transition,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::transition(client, &jira_config, &ticket_id, &transition.id).await {
+                Ok(()) => {
+                    sender.send_jira_event(TicketTransitioned(ticket_id, transition));
+                }
+                Err(err) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::JIRA,
+                            format!("Failed to transition {}", ticket_id),
+                        )
+                        .with_detail(err.to_string()),
+                    ));
+                }
+            }
+        });
+    }
+
+    fn fetch_board(&self, board_id: u64, jira_config: JiraConfig, sender: EventSender) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::get_board_configuration(client, &jira_config, board_id).await {
+                Ok(board) => {
+                    sender.send_jira_event(BoardRetrieved(board_id, board));
+                }
+                Err(err) => {
+                    sender.send_jira_event(BoardFailed(board_id, err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn create_issue(&self, issue: NewIssue, jira_config: JiraConfig, sender: EventSender) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::create_issue(client, &jira_config, &issue).await {
+                Ok(key) => {
+                    sender.send_jira_event(IssueCreated(key));
+                }
+                Err(err) => {
+                    sender.send_jira_event(IssueCreateFailed(err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn add_comment(
+        &self,
+        ticket_id: String,
+        text: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::add_comment(client, &jira_config, &ticket_id, &text).await {
+                Ok(()) => {
+                    sender.send_jira_event(CommentAdded(ticket_id));
+                }
+                Err(err) => {
+                    sender.send_jira_event(CommentFailed(ticket_id, err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn add_worklog(
+        &self,
+        ticket_id: String,
+        worklog: NewWorklog,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            let seconds = worklog.time_spent_seconds;
+            match jira_client::add_worklog(client, &jira_config, &ticket_id, &worklog).await {
+                Ok(()) => {
+                    sender.send_jira_event(WorklogAdded(ticket_id, seconds));
+                }
+                Err(err) => {
+                    sender.send_jira_event(WorklogFailed(ticket_id, seconds, err.to_string()));
+                }
+            }
+        });
+    }
+
+    fn search_assignees(
+        &self,
+        ticket_id: String,
+        query: String,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::search_assignees(client, &jira_config, &ticket_id, &query).await {
+                Ok(users) => {
+                    sender.send_jira_event(AssigneesFound(ticket_id, query, users));
+                }
+                Err(err) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::JIRA,
+                            format!("Failed to search people for {}", ticket_id),
+                        )
+                        .with_detail(err.to_string()),
+                    ));
+                }
+            }
+        });
+    }
+
+    fn assign_ticket(
+        &self,
+        ticket_id: String,
+        to: AssignTo,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::assign(client, &jira_config, &ticket_id, &to).await {
+                Ok(assignee) => {
+                    sender.send_jira_event(TicketAssigned(ticket_id, assignee));
+                }
+                Err(err) => {
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::JIRA,
+                            format!("Failed to assign {}", ticket_id),
+                        )
+                        .with_detail(err.to_string()),
+                    ));
+                }
+            }
+        });
+    }
+
+    fn set_watching(
+        &self,
+        ticket_id: String,
+        watch: bool,
+        jira_config: JiraConfig,
+        sender: EventSender,
+    ) {
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match jira_client::set_watching(client, &jira_config, &ticket_id, watch).await {
+                Ok(()) => {
+                    sender.send_jira_event(WatchingSet(ticket_id, watch));
+                }
+                Err(err) => {
+                    let action = if watch { "watch" } else { "stop watching" };
+                    sender.send_app_event(AppLog(
+                        LogEntry::new(
+                            LogLevel::Error,
+                            log_source::JIRA,
+                            format!("Failed to {} {}", action, ticket_id),
+                        )
+                        .with_detail(err.to_string()),
+                    ));
+                }
+            }
+        });
+    }
 }
@@ -1,16 +1,74 @@
-use serde::Deserialize;
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub struct TicketResponse {
     pub key: String,
     pub fields: Fields,
+    /// Only present when requested with `expand=changelog`.
+    #[serde(default)]
+    pub changelog: Option<Changelog>,
 }
 
-#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
 pub struct Fields {
     pub assignee: Option<Assignee>,
     pub status: Status,
     pub summary: String,
+    #[serde(default)]
+    pub issuetype: Option<Named>,
+    #[serde(default)]
+    pub updated: Option<String>,
+    #[serde(default)]
+    pub priority: Option<Named>,
+    /// Whether the authenticated user watches the issue.
+    #[serde(default)]
+    pub watches: Option<Watches>,
+    /// The issue above this one: an epic for a story or task, a story for a subtask.
+    /// Boxed, as few issues have one and it would otherwise double the response.
+    #[serde(default)]
+    pub parent: Option<Box<Parent>>,
+}
+
+impl Fields {
+    /// The epic the issue belongs to, as `KEY Summary`.
+    pub fn epic(&self) -> Option<String> {
+        self.parent
+            .as_ref()
+            .filter(|p| {
+                p.fields
+                    .issuetype
+                    .as_ref()
+                    .is_some_and(|t| t.name.eq_ignore_ascii_case("epic"))
+            })
+            .map(|p| format!("{} {}", p.key, p.fields.summary))
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Watches {
+    pub is_watching: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Parent {
+    pub key: String,
+    pub fields: ParentFields,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct ParentFields {
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub issuetype: Option<Named>,
+}
+
+/// Jira timestamps look like `2024-01-31T09:15:00.000+0000`.
+pub fn parse_time(raw: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f%z").ok()
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq)]
@@ -19,16 +77,52 @@ pub struct Assignee {
     pub display_name: String,
 }
 
-#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Status {
+    /// Board columns list their statuses by ID, as names can repeat across workflows.
+    #[serde(default)]
+    pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub status_category: Option<StatusCategoryRef>,
+}
+
+impl Status {
+    pub fn category(&self) -> StatusCategory {
+        self.status_category
+            .as_ref()
+            .map(|c| c.key)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct StatusCategoryRef {
+    pub key: StatusCategory,
+}
+
+/// The fixed set of buckets every Jira workflow status belongs to, whatever it is called.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusCategory {
+    #[serde(rename = "new")]
+    ToDo,
+    #[serde(rename = "indeterminate")]
+    InProgress,
+    Done,
+    #[default]
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorResponse {
     pub error_messages: Vec<String>,
+    /// Per-field validation errors, e.g. `summary` → "You must specify a summary".
+    #[serde(default)]
+    pub errors: BTreeMap<String, String>,
 }
 
 #[derive(Deserialize, Debug, PartialEq)]
@@ -38,6 +132,346 @@ pub enum JiraResponse {
     ErrorResponse(ErrorResponse),
 }
 
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct SearchResults {
+    pub issues: Vec<TicketResponse>,
+    /// How many tickets match across every page.
+    #[serde(default)]
+    pub total: usize,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum SearchResponse {
+    SearchResults(SearchResults),
+    ErrorResponse(ErrorResponse),
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct TransitionsResults {
+    pub transitions: Vec<Transition>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Transition {
+    pub id: String,
+    pub name: String,
+    pub to: Status,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum TransitionsResponse {
+    TransitionsResults(TransitionsResults),
+    ErrorResponse(ErrorResponse),
+}
+
+/// An agile board's setup; only its columns are used.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardConfiguration {
+    pub name: String,
+    pub column_config: ColumnConfig,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnConfig {
+    pub columns: Vec<BoardColumn>,
+}
+
+/// A board column and the workflow statuses that put a card in it.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct BoardColumn {
+    pub name: String,
+    #[serde(default)]
+    pub statuses: Vec<StatusRef>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct StatusRef {
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum BoardConfigurationResponse {
+    BoardConfiguration(BoardConfiguration),
+    ErrorResponse(ErrorResponse),
+}
+
+/// An issue's edit history.
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct Changelog {
+    #[serde(default)]
+    pub histories: Vec<History>,
+}
+
+/// One edit: every field `author` changed at `created`.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct History {
+    pub author: Option<Assignee>,
+    pub created: String,
+    #[serde(default)]
+    pub items: Vec<ChangeItem>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct ChangeItem {
+    pub field: String,
+    #[serde(default, rename = "fromString")]
+    pub from: Option<String>,
+    #[serde(default, rename = "toString")]
+    pub to: Option<String>,
+}
+
+/// A single field change, flattened out of the changelog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: String,
+    pub to: String,
+    pub author: String,
+    pub at: DateTime<FixedOffset>,
+}
+
+impl History {
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        parse_time(&self.created)
+    }
+}
+
+impl Changelog {
+    /// When the most recent edit was made.
+    pub fn latest(&self) -> Option<DateTime<FixedOffset>> {
+        self.histories.iter().filter_map(History::created_at).max()
+    }
+
+    /// When the status was last changed, if the changelog holds the change.
+    pub fn status_changed_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.histories
+            .iter()
+            .filter(|history| history.items.iter().any(|item| item.field == "status"))
+            .filter_map(History::created_at)
+            .max()
+    }
+
+    /// Every field change made after `since` (all of them for `None`), oldest
+    /// first. Jira doesn't promise an order, so they are sorted here.
+    pub fn changes_since(&self, since: Option<DateTime<FixedOffset>>) -> Vec<FieldChange> {
+        let mut changes = self
+            .histories
+            .iter()
+            .filter_map(|history| Some((history, history.created_at()?)))
+            .filter(|(_, at)| since.is_none_or(|since| *at > since))
+            .flat_map(|(history, at)| {
+                let author = history
+                    .author
+                    .as_ref()
+                    .map_or("Unknown", |a| a.display_name.as_str());
+                history.items.iter().map(move |item| FieldChange {
+                    field: item.field.clone(),
+                    from: item.from.clone().unwrap_or_default(),
+                    to: item.to.clone().unwrap_or_default(),
+                    author: author.to_string(),
+                    at,
+                })
+            })
+            .collect::<Vec<_>>();
+        changes.sort_by_key(|change| change.at);
+        changes
+    }
+}
+
+impl FieldChange {
+    /// e.g. "status: To Do → In Progress (Alice, 2024-03-01 14:02)".
+    pub fn describe(&self) -> String {
+        let value = |v: &str| {
+            if v.is_empty() {
+                "None".to_string()
+            } else {
+                v.to_string()
+            }
+        };
+        format!(
+            "{}: {} → {} ({}, {})",
+            self.field,
+            value(&self.from),
+            value(&self.to),
+            self.author,
+            self.at.format("%Y-%m-%d %H:%M")
+        )
+    }
+}
+
+/// A single issue with the fields shown in the ticket detail pane. `description`
+/// and comment bodies are kept as raw JSON: Atlassian Document Format on Cloud.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct IssueDetails {
+    pub key: String,
+    pub fields: DetailFields,
+    #[serde(default)]
+    pub changelog: Option<Changelog>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailFields {
+    pub summary: String,
+    pub status: Status,
+    pub assignee: Option<Assignee>,
+    #[serde(default)]
+    pub description: Option<serde_json::Value>,
+    #[serde(default)]
+    pub comment: Option<Comments>,
+    #[serde(default)]
+    pub subtasks: Vec<LinkedIssue>,
+    #[serde(default)]
+    pub issuelinks: Vec<IssueLink>,
+    #[serde(default)]
+    pub priority: Option<Named>,
+    #[serde(default)]
+    pub issuetype: Option<Named>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub fix_versions: Vec<Named>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Named {
+    pub name: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Comments {
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Comment {
+    pub author: Option<Assignee>,
+    pub body: serde_json::Value,
+    pub created: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct LinkedIssue {
+    pub key: String,
+    pub fields: LinkedFields,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct LinkedFields {
+    pub summary: String,
+    pub status: Status,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLink {
+    #[serde(rename = "type")]
+    pub link_type: LinkType,
+    pub inward_issue: Option<LinkedIssue>,
+    pub outward_issue: Option<LinkedIssue>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct LinkType {
+    pub inward: String,
+    pub outward: String,
+}
+
+impl IssueLink {
+    /// The relationship as read from this issue, e.g. ("blocks", ABC-2).
+    pub fn describe(&self) -> Option<(&str, &LinkedIssue)> {
+        match (&self.outward_issue, &self.inward_issue) {
+            (Some(issue), _) => Some((&self.link_type.outward, issue)),
+            (None, Some(issue)) => Some((&self.link_type.inward, issue)),
+            (None, None) => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum DetailsResponse {
+    IssueDetails(Box<IssueDetails>),
+    ErrorResponse(ErrorResponse),
+}
+
+/// A Jira user: the authenticated one, or a match from an assignee search. Cloud
+/// identifies users by `accountId`, Server by `name`.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    #[serde(default)]
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub display_name: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum MyselfResponse {
+    Myself(User),
+    ErrorResponse(ErrorResponse),
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum UsersResponse {
+    Users(Vec<User>),
+    ErrorResponse(ErrorResponse),
+}
+
+/// Who to assign an existing ticket to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssignTo {
+    Me,
+    User(User),
+    Nobody,
+}
+
+/// A ticket to create, as entered in the new issue form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewIssue {
+    pub project: String,
+    pub issue_type: String,
+    pub summary: String,
+    pub description: String,
+    pub assignee: Assignment,
+    pub labels: Vec<String>,
+}
+
+/// Who a new ticket is assigned to; `User` holds an account ID (Cloud) or username (Server).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Assignment {
+    Me,
+    Unassigned,
+    User(String),
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct CreatedIssue {
+    pub key: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum CreateResponse {
+    CreatedIssue(CreatedIssue),
+    ErrorResponse(ErrorResponse),
+}
+
+/// Time spent on a ticket, from the worklog form or a stopped timer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewWorklog {
+    pub time_spent_seconds: u64,
+    pub started: DateTime<Local>,
+    pub comment: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +493,50 @@ mod tests {
         assert_eq!(ticket.fields.assignee.unwrap().display_name, "Alice");
     }
 
+    #[test]
+    fn ticket_epic_is_an_epic_parent() {
+        let json = r#"{
+            "key": "PROJ-7",
+            "fields": {
+                "assignee": null,
+                "status": { "name": "Open" },
+                "summary": "Story",
+                "updated": "2024-01-31T09:15:00.000+0000",
+                "priority": { "name": "High" },
+                "parent": {
+                    "key": "PROJ-1",
+                    "fields": { "summary": "Login revamp", "issuetype": { "name": "Epic" } }
+                }
+            }
+        }"#;
+        let ticket: TicketResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(ticket.fields.epic().as_deref(), Some("PROJ-1 Login revamp"));
+        assert_eq!(ticket.fields.priority.unwrap().name, "High");
+        assert!(parse_time(&ticket.fields.updated.unwrap()).is_some());
+
+        let subtask = Fields {
+            parent: Some(Box::new(Parent {
+                key: "PROJ-7".to_string(),
+                fields: ParentFields {
+                    summary: "Story".to_string(),
+                    issuetype: Some(Named {
+                        name: "Story".to_string(),
+                    }),
+                },
+            })),
+            ..Default::default()
+        };
+        assert_eq!(subtask.epic(), None);
+    }
+
+    #[test]
+    fn ticket_reads_whether_i_am_watching() {
+        let json = r#"{"key": "PROJ-1", "fields": {"assignee": null, "status": {"name": "Open"}, "summary": "S",
+            "watches": {"watchCount": 2, "isWatching": true}}}"#;
+        let ticket: TicketResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(ticket.fields.watches, Some(Watches { is_watching: true }));
+    }
+
     #[test]
     fn deserialize_ticket_with_null_assignee() {
         let json = r#"{
@@ -79,4 +557,203 @@ mod tests {
         let assignee: Assignee = serde_json::from_str(json).unwrap();
         assert_eq!(assignee.display_name, "Bob");
     }
+
+    #[test]
+    fn deserialize_issue_details_with_missing_optional_fields() {
+        let json = r#"{
+            "key": "PROJ-1",
+            "fields": {
+                "summary": "Bare",
+                "status": { "name": "Open" },
+                "assignee": null
+            }
+        }"#;
+        let details: IssueDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(details.fields.description, None);
+        assert!(details.fields.subtasks.is_empty());
+        assert!(details.fields.fix_versions.is_empty());
+    }
+
+    #[test]
+    fn deserialize_issue_details_fields() {
+        let json = r#"{
+            "key": "PROJ-1",
+            "fields": {
+                "summary": "Full",
+                "status": { "name": "Open" },
+                "assignee": null,
+                "priority": { "name": "High" },
+                "issuetype": { "name": "Bug" },
+                "labels": ["backend"],
+                "fixVersions": [{ "name": "1.2.0" }],
+                "subtasks": [{ "key": "PROJ-2", "fields": { "summary": "Sub", "status": { "name": "Done" } } }],
+                "comment": { "comments": [{ "author": { "displayName": "Alice" }, "body": "hi", "created": "2024-01-01T10:00:00.000+0000" }] }
+            }
+        }"#;
+        let details: IssueDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(details.fields.priority.unwrap().name, "High");
+        assert_eq!(details.fields.issuetype.unwrap().name, "Bug");
+        assert_eq!(details.fields.labels, vec!["backend"]);
+        assert_eq!(details.fields.fix_versions[0].name, "1.2.0");
+        assert_eq!(details.fields.subtasks[0].fields.status.name, "Done");
+        assert_eq!(details.fields.comment.unwrap().comments.len(), 1);
+    }
+
+    #[test]
+    fn issue_link_describes_relation_from_this_issue() {
+        let json = r#"[
+            { "type": { "inward": "is blocked by", "outward": "blocks" },
+              "outwardIssue": { "key": "PROJ-3", "fields": { "summary": "Later", "status": { "name": "Open" } } } },
+            { "type": { "inward": "is blocked by", "outward": "blocks" },
+              "inwardIssue": { "key": "PROJ-0", "fields": { "summary": "Earlier", "status": { "name": "Open" } } } }
+        ]"#;
+        let links: Vec<IssueLink> = serde_json::from_str(json).unwrap();
+
+        let (relation, issue) = links[0].describe().unwrap();
+        assert_eq!((relation, issue.key.as_str()), ("blocks", "PROJ-3"));
+        let (relation, issue) = links[1].describe().unwrap();
+        assert_eq!((relation, issue.key.as_str()), ("is blocked by", "PROJ-0"));
+    }
+
+    #[test]
+    fn status_category_read_from_key() {
+        let json = r#"{"name": "Ready for QA", "statusCategory": {"key": "indeterminate", "colorName": "yellow"}}"#;
+        let status: Status = serde_json::from_str(json).unwrap();
+        assert_eq!(status.category(), StatusCategory::InProgress);
+    }
+
+    #[test]
+    fn status_category_unknown_when_missing_or_unrecognised() {
+        let missing: Status = serde_json::from_str(r#"{"name": "Open"}"#).unwrap();
+        assert_eq!(missing.category(), StatusCategory::Unknown);
+
+        let unrecognised: Status =
+            serde_json::from_str(r#"{"name": "Open", "statusCategory": {"key": "undefined"}}"#)
+                .unwrap();
+        assert_eq!(unrecognised.category(), StatusCategory::Unknown);
+    }
+
+    #[test]
+    fn create_response_reads_field_errors() {
+        let json = r#"{"errorMessages": [], "errors": {"summary": "You must specify a summary of the issue."}}"#;
+        let response: CreateResponse = serde_json::from_str(json).unwrap();
+        let CreateResponse::ErrorResponse(error) = response else {
+            panic!("expected an error response");
+        };
+        assert_eq!(
+            error.errors.get("summary").map(String::as_str),
+            Some("You must specify a summary of the issue.")
+        );
+    }
+
+    #[test]
+    fn user_reads_cloud_and_server_identifiers() {
+        let cloud: User =
+            serde_json::from_str(r#"{"accountId": "5b10ac", "displayName": "Alice"}"#).unwrap();
+        assert_eq!(cloud.account_id.as_deref(), Some("5b10ac"));
+        assert_eq!(cloud.name, None);
+
+        let server: User =
+            serde_json::from_str(r#"{"name": "alice", "displayName": "Alice"}"#).unwrap();
+        assert_eq!(server.name.as_deref(), Some("alice"));
+    }
+
+    fn changelog() -> Changelog {
+        serde_json::from_str(
+            r#"{"histories": [
+                {
+                    "author": { "displayName": "Bob" },
+                    "created": "2024-03-01T16:30:00.000+0000",
+                    "items": [{ "field": "assignee", "fromString": null, "toString": "Bob" }]
+                },
+                {
+                    "author": { "displayName": "Alice" },
+                    "created": "2024-03-01T09:15:00.000+0000",
+                    "items": [
+                        { "field": "status", "fromString": "To Do", "toString": "In Progress" },
+                        { "field": "labels", "fromString": "", "toString": "ui" }
+                    ]
+                },
+                { "created": "not a date", "items": [{ "field": "summary" }] }
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn changelog_latest_is_newest_edit() {
+        assert_eq!(
+            changelog().latest(),
+            DateTime::parse_from_rfc3339("2024-03-01T16:30:00+00:00").ok()
+        );
+        assert_eq!(Changelog::default().latest(), None);
+    }
+
+    #[test]
+    fn changelog_status_changed_at_ignores_other_fields() {
+        assert_eq!(
+            changelog().status_changed_at(),
+            DateTime::parse_from_rfc3339("2024-03-01T09:15:00+00:00").ok()
+        );
+        assert_eq!(Changelog::default().status_changed_at(), None);
+    }
+
+    #[test]
+    fn changes_since_flattens_oldest_first() {
+        let changes = changelog().changes_since(None);
+        let described = changes
+            .iter()
+            .map(FieldChange::describe)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            described,
+            vec![
+                "status: To Do → In Progress (Alice, 2024-03-01 09:15)",
+                "labels: None → ui (Alice, 2024-03-01 09:15)",
+                "assignee: None → Bob (Bob, 2024-03-01 16:30)",
+            ]
+        );
+    }
+
+    #[test]
+    fn changes_since_skips_edits_already_seen() {
+        let seen = DateTime::parse_from_rfc3339("2024-03-01T09:15:00+00:00").ok();
+        let changes = changelog().changes_since(seen);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "assignee");
+    }
+
+    #[test]
+    fn ticket_response_reads_optional_changelog() {
+        let json = r#"{"key": "PROJ-1", "fields": {"assignee": null, "status": {"name": "Open"}, "summary": "S"},
+            "changelog": {"histories": []}}"#;
+        let response: TicketResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.changelog, Some(Changelog::default()));
+    }
+
+    #[test]
+    fn board_configuration_reads_columns_and_status_ids() {
+        let json = r#"{"id": 7, "name": "Team board", "type": "scrum",
+            "columnConfig": {"columns": [
+                {"name": "Backlog"},
+                {"name": "In Progress", "statuses": [{"id": "3", "self": "https://x/status/3"}, {"id": "10001"}]}
+            ], "constraintType": "none"}}"#;
+        let response: BoardConfigurationResponse = serde_json::from_str(json).unwrap();
+        let BoardConfigurationResponse::BoardConfiguration(board) = response else {
+            panic!("expected a board configuration");
+        };
+        assert_eq!(board.name, "Team board");
+        assert_eq!(board.column_config.columns[0].statuses, vec![]);
+        assert_eq!(
+            board.column_config.columns[1].statuses,
+            vec![
+                StatusRef {
+                    id: "3".to_string()
+                },
+                StatusRef {
+                    id: "10001".to_string()
+                },
+            ]
+        );
+    }
 }
@@ -1,10 +1,43 @@
 use crate::config::model::Config;
+use crate::config::validate::{positions, validate};
 use crate::error::model::ConfigError;
+use serde_yaml::Value;
 use std::fs;
+use std::io::{ErrorKind, Write};
 use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// The config schema version this build reads and writes. Bump it alongside a
+/// new entry in [`MIGRATIONS`].
+pub const CONFIG_VERSION: u64 = 1;
+
+/// Each step upgrades a config from the version of its index to the next one.
+/// Files from before versioning have no `version` key and count as version 0.
+/// Steps edit the text line by line rather than re-serialising it, so the
+/// user's comments and layout survive.
+const MIGRATIONS: [fn(&str) -> String; CONFIG_VERSION as usize] = [rename_short_env_keys];
 
 pub struct ConfigLoader {
     file_path: PathBuf,
+    /// When the file was last read or written by us, to tell outside edits apart.
+    modified: Option<SystemTime>,
+    /// Whether the file on disk has problems. It's left alone until they're fixed.
+    broken: bool,
+    /// The upgrade made on the last read, until it has been logged.
+    migrated: Option<Migrated>,
+}
+
+/// An upgrade of the config file to [`CONFIG_VERSION`]: the file before and after.
+pub struct Migration {
+    pub from: u64,
+    pub before: String,
+    pub after: String,
+}
+
+/// An upgrade that has been written, and where the original was kept.
+pub struct Migrated {
+    pub from: u64,
+    pub backup: PathBuf,
 }
 
 impl ConfigLoader {
@@ -12,42 +45,215 @@ impl ConfigLoader {
         let home_dir = dirs::home_dir().expect("Could not find home directory");
         ConfigLoader {
             file_path: home_dir.join(folder).join(config_file),
+            modified: None,
+            broken: false,
+            migrated: None,
         }
     }
 
     #[cfg(test)]
     pub(crate) fn from_path(file_path: PathBuf) -> ConfigLoader {
-        ConfigLoader { file_path }
+        ConfigLoader {
+            file_path,
+            modified: None,
+            broken: false,
+            migrated: None,
+        }
     }
 
-    pub fn read_or_create_config(&self) -> Result<Config, ConfigError> {
-        match fs::read_to_string(&self.file_path) {
-            Ok(content) => Ok(serde_yaml::from_str::<Config>(content.as_str())?.normalize()),
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+    pub fn read_or_create_config(&mut self) -> Result<Config, ConfigError> {
+        self.modified = self.modified_on_disk();
+        match self.read_config() {
+            Err(ConfigError::Read(e)) if e.kind() == std::io::ErrorKind::NotFound => {
                 let config = Config::default();
                 if let Some(parent) = self.file_path.parent() {
                     fs::create_dir_all(parent)?;
                 }
-                let yaml = serde_yaml::to_string(&config)?;
-                fs::write(&self.file_path, yaml)?;
+                self.write_config(&config)?;
                 Ok(config)
             }
-            Err(e) => Err(ConfigError::Read(e)),
+            result => result,
+        }
+    }
+
+    /// Re-reads the config when the file has been changed by something other than
+    /// us since we last read or wrote it. `None` means it hasn't changed.
+    pub fn reload_if_changed(&mut self) -> Option<Result<Config, ConfigError>> {
+        let modified = self.modified_on_disk()?;
+        if self.modified == Some(modified) {
+            return None;
         }
+        // Remember the change even if it doesn't parse, so it's reported once.
+        self.modified = Some(modified);
+        Some(self.read_config())
     }
 
-    pub fn write_config(&self, config: &Config) -> Result<(), ConfigError> {
+    pub fn write_config(&mut self, config: &Config) -> Result<(), ConfigError> {
+        if self.broken {
+            return Err(ConfigError::Broken);
+        }
         let yaml = serde_yaml::to_string(config)?;
         fs::write(&self.file_path, yaml)?;
+        self.modified = self.modified_on_disk();
         Ok(())
     }
+
+    /// What upgrading the file to [`CONFIG_VERSION`] would change, without writing
+    /// anything. `None` means it is up to date.
+    pub fn plan_migration(&self) -> Result<Option<Migration>, ConfigError> {
+        let content = fs::read_to_string(&self.file_path)?;
+        Ok(migrate(&content))
+    }
+
+    /// Writes the upgraded file, keeping the original alongside it as
+    /// `config.yaml.v<from>.bak`, or `config.yaml.v<from>.<n>.bak` when an earlier
+    /// backup is already there. Nothing is written if the upgraded file has
+    /// problems; they're reported against the file as it is on disk.
+    pub fn apply_migration(&mut self, migration: &Migration) -> Result<PathBuf, ConfigError> {
+        if !validate(&migration.after).is_empty() {
+            let mut problems = validate(&migration.before);
+            if problems.is_empty() {
+                problems = validate(&migration.after);
+            }
+            return Err(ConfigError::Invalid(problems));
+        }
+        let backup = self.write_backup(migration)?;
+        fs::write(&self.file_path, &migration.after)?;
+        self.modified = self.modified_on_disk();
+        Ok(backup)
+    }
+
+    /// The upgrade made by the last read, if it hasn't been taken already.
+    pub fn take_migrated(&mut self) -> Option<Migrated> {
+        self.migrated.take()
+    }
+
+    fn read_config(&mut self) -> Result<Config, ConfigError> {
+        let mut content = fs::read_to_string(&self.file_path)?;
+        if let Some(migration) = migrate(&content) {
+            let backup = match self.apply_migration(&migration) {
+                Ok(backup) => backup,
+                Err(ConfigError::Invalid(problems)) => {
+                    self.broken = true;
+                    return Err(ConfigError::Invalid(problems));
+                }
+                Err(e) => return Err(e),
+            };
+            self.migrated = Some(Migrated {
+                from: migration.from,
+                backup,
+            });
+            content = migration.after;
+        }
+        let problems = validate(&content);
+        self.broken = !problems.is_empty();
+        if self.broken {
+            return Err(ConfigError::Invalid(problems));
+        }
+        Ok(serde_yaml::from_str::<Config>(content.as_str())?.normalize())
+    }
+
+    /// Writes `migration.before` to the first backup name not already taken.
+    fn write_backup(&self, migration: &Migration) -> Result<PathBuf, ConfigError> {
+        for n in 0.. {
+            let extension = match n {
+                0 => format!("yaml.v{}.bak", migration.from),
+                n => format!("yaml.v{}.{}.bak", migration.from, n),
+            };
+            let backup = self.file_path.with_extension(extension);
+            match fs::File::create_new(&backup) {
+                Ok(mut file) => {
+                    file.write_all(migration.before.as_bytes())?;
+                    return Ok(backup);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        unreachable!("ran out of backup names")
+    }
+
+    fn modified_on_disk(&self) -> Option<SystemTime> {
+        fs::metadata(&self.file_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
+
+/// Upgrades `content` to [`CONFIG_VERSION`]. Files that are current, newer, or
+/// not a YAML mapping are left for validation to judge.
+fn migrate(content: &str) -> Option<Migration> {
+    let Ok(Value::Mapping(root)) = serde_yaml::from_str::<Value>(content) else {
+        return None;
+    };
+    let from = match root.get("version") {
+        None => 0,
+        Some(version) => version.as_u64()?,
+    };
+    if from >= CONFIG_VERSION {
+        return None;
+    }
+    let upgraded = MIGRATIONS[from as usize..]
+        .iter()
+        .fold(content.to_string(), |source, migration| migration(&source));
+    Some(Migration {
+        from,
+        before: content.to_string(),
+        after: set_version(&upgraded),
+    })
+}
+
+/// Points the top-level `version` key at [`CONFIG_VERSION`], adding it at the top,
+/// where a reader looks for it, when there isn't one.
+fn set_version(source: &str) -> String {
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+    let version = format!("version: {}", CONFIG_VERSION);
+    match positions(source).get("version") {
+        Some((line, _)) => lines[line - 1] = version,
+        None => {
+            // After a leading `---`, so the file stays a single document.
+            let start = lines
+                .iter()
+                .position(|line| !line.trim().is_empty() && !line.starts_with('#'))
+                .filter(|&i| lines[i].starts_with("---"))
+                .map_or(0, |i| i + 1);
+            lines.insert(start, version);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// 0 → 1: early example configs named Service Status URLs `preprod` and `prod`.
+fn rename_short_env_keys(source: &str) -> String {
+    let positions = positions(source);
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+    for service in (0..).map(|i| format!("servicestatus[{}]", i)) {
+        if !positions.contains_key(&service) {
+            break;
+        }
+        for (old, new) in [("preprod", "preproduction"), ("prod", "production")] {
+            if positions.contains_key(&format!("{}.{}", service, new)) {
+                continue;
+            }
+            if let Some((line, column)) = positions.get(&format!("{}.{}", service, old)) {
+                // The key starts at its column, after any opening quote.
+                let (before, key) = lines[line - 1].split_at(column - 1);
+                let renamed = format!("{}{}", before, key.replacen(old, new, 1));
+                lines[line - 1] = renamed;
+            }
+        }
+    }
+    lines.join("\n") + "\n"
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::model::{Auth0Config, ServiceStatusConfig};
+    use crate::config::model::{
+        Auth0Config, Deployment, JiraConfig, ServiceStatusConfig, StatusColour,
+    };
     use crate::environment::Environment;
+    use std::collections::BTreeMap;
     use tempfile::TempDir;
 
     #[test]
@@ -58,6 +264,7 @@ mod tests {
             preproduction: "http://preproduction.test.com".to_string(),
             production: "http://production.test.com".to_string(),
             repo: "http://repo.test.com".to_string(),
+            clone: None,
         };
         assert_eq!(
             status.get_from_env(&Environment::Staging),
@@ -95,6 +302,155 @@ mod tests {
         assert_eq!(config.get_from_env(&Environment::Production), "production");
     }
 
+    #[test]
+    fn jira_deployment_defaults_to_cloud() {
+        let jira: JiraConfig =
+            serde_yaml::from_str("url: https://x.atlassian.net\nemail: a@b.c\ntoken: t\n").unwrap();
+        assert_eq!(jira.deployment, Deployment::Cloud);
+        assert_eq!(jira.deployment.api_version(), 3);
+        assert!(!serde_yaml::to_string(&jira).unwrap().contains("deployment"));
+    }
+
+    #[test]
+    fn jira_server_deployment_uses_api_v2() {
+        let jira: JiraConfig = serde_yaml::from_str(
+            "url: https://jira.internal\nemail: ''\ntoken: pat\ndeployment: server\n",
+        )
+        .unwrap();
+        assert_eq!(jira.deployment, Deployment::Server);
+        assert_eq!(jira.deployment.api_version(), 2);
+        assert!(
+            serde_yaml::to_string(&jira)
+                .unwrap()
+                .contains("deployment: server")
+        );
+    }
+
+    fn jira_with_status_colours(yaml: &str) -> JiraConfig {
+        serde_yaml::from_str(&format!(
+            "url: https://x.atlassian.net\nemail: a@b.c\ntoken: t\nstatus_colours:\n{}",
+            yaml
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn status_colour_matches_status_name_case_insensitively() {
+        let jira = jira_with_status_colours("  Ready for QA: magenta\n");
+        assert_eq!(jira.status_colour("ABC", "ready for qa"), Some("magenta"));
+        assert_eq!(jira.status_colour("ABC", "In Progress"), None);
+    }
+
+    #[test]
+    fn status_colour_prefers_project_workflow() {
+        let jira = jira_with_status_colours(
+            "  Deployed to UAT: cyan\n  OPS:\n    Deployed to UAT: green\n",
+        );
+        assert_eq!(jira.status_colour("OPS", "Deployed to UAT"), Some("green"));
+        assert_eq!(jira.status_colour("ABC", "Deployed to UAT"), Some("cyan"));
+    }
+
+    #[test]
+    fn status_colours_round_trip() {
+        let jira = jira_with_status_colours("  Done: green\n  OPS:\n    Done: blue\n");
+        let yaml = serde_yaml::to_string(&jira).unwrap();
+        let reparsed: JiraConfig = serde_yaml::from_str(&yaml).unwrap();
+        assert!(reparsed == jira);
+        assert_eq!(
+            reparsed.status_colours.get("OPS"),
+            Some(&StatusColour::Workflow(BTreeMap::from([(
+                "Done".to_string(),
+                "blue".to_string()
+            )])))
+        );
+    }
+
+    #[test]
+    fn jira_git_defaults_and_is_omitted_when_unset() {
+        let jira = jira_with_status_colours("  Done: green\n");
+        assert_eq!(jira.git.branch_template, "{type}/{id}-{slug(title)}");
+        assert_eq!(jira.git.commit_template, "{type}({id}): ");
+        assert!(!serde_yaml::to_string(&jira).unwrap().contains("git"));
+    }
+
+    #[test]
+    fn jira_git_commit_type_uses_mapping_then_defaults() {
+        let jira: JiraConfig = serde_yaml::from_str(
+            "url: u\nemail: e\ntoken: t\ngit:\n  repository: ~/code/app\n  types:\n    Task: chore\n",
+        )
+        .unwrap();
+        assert_eq!(jira.git.repository.as_deref(), Some("~/code/app"));
+        assert_eq!(jira.git.commit_type("task"), "chore");
+        assert_eq!(jira.git.commit_type("Bug"), "fix");
+        assert_eq!(jira.git.commit_type("Story"), "feat");
+    }
+
+    #[test]
+    fn jira_git_scan_repositories_puts_repository_first_without_duplicates() {
+        let jira: JiraConfig = serde_yaml::from_str(
+            "url: u\nemail: e\ntoken: t\ngit:\n  repository: ~/code/app\n  repositories:\n    - ~/code/api\n    - ~/code/app\n",
+        )
+        .unwrap();
+        assert_eq!(
+            jira.git.scan_repositories(),
+            vec!["~/code/app", "~/code/api"]
+        );
+    }
+
+    #[test]
+    fn jira_board_is_optional() {
+        let jira: JiraConfig =
+            serde_yaml::from_str("url: u\nemail: e\ntoken: t\nboard: 42\n").unwrap();
+        assert_eq!(jira.board, Some(42));
+        let jira = jira_with_status_colours("  Done: green\n");
+        assert_eq!(jira.board, None);
+        assert!(!serde_yaml::to_string(&jira).unwrap().contains("board"));
+    }
+
+    #[test]
+    fn jira_archive_defaults_to_done_and_reads_status_days() {
+        let jira = jira_with_status_colours("  Done: green\n");
+        assert!(jira.archive.done);
+        assert!(!serde_yaml::to_string(&jira).unwrap().contains("archive"));
+
+        let jira: JiraConfig = serde_yaml::from_str(
+            "url: u\nemail: e\ntoken: t\narchive:\n  done: false\n  after_days:\n    Ready for release: 14\n",
+        )
+        .unwrap();
+        assert!(!jira.archive.done);
+        assert_eq!(jira.archive.days_in("ready for release"), Some(14));
+        assert_eq!(jira.archive.days_in("In Review"), None);
+    }
+
+    fn jira_with_sites() -> JiraConfig {
+        serde_yaml::from_str(
+            "url: https://us.atlassian.net\nemail: me@us.com\ntoken: t\nsites:\n  - name: acme\n    url: https://jira.acme.internal\n    email: ''\n    token: pat\n    deployment: server\n    projects: [ACME, OPS]\n    board: 7\nboard: 42\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn jira_route_uses_the_site_listing_the_project() {
+        let jira = jira_with_sites();
+        assert_eq!(jira.route("ACME-12"), "acme");
+        assert_eq!(jira.route("ops-3"), "acme");
+        assert_eq!(jira.route("ABC-1"), "");
+        assert_eq!(jira.site_names(), vec!["", "acme"]);
+    }
+
+    #[test]
+    fn jira_for_site_swaps_url_and_credentials() {
+        let jira = jira_with_sites();
+        let acme = jira.for_site("acme");
+        assert_eq!(acme.url, "https://jira.acme.internal");
+        assert_eq!(acme.token, "pat");
+        assert_eq!(acme.deployment, Deployment::Server);
+        assert_eq!(acme.board, Some(7));
+        assert_eq!(jira.for_site("").board, Some(42));
+        assert!(jira.for_site("") == jira);
+        assert!(jira.for_site("gone") == jira);
+    }
+
     fn temp_loader_path(dir: &TempDir) -> PathBuf {
         dir.path().join("config.yaml")
     }
@@ -105,7 +461,7 @@ mod tests {
         let file_path = temp_loader_path(&dir);
         assert!(!file_path.exists());
 
-        let config_loader = ConfigLoader::from_path(file_path.clone());
+        let mut config_loader = ConfigLoader::from_path(file_path.clone());
         let config = config_loader.read_or_create_config().unwrap();
 
         // Returns default config with all features disabled
@@ -140,10 +496,251 @@ tokengenerator:
         let file_path = temp_loader_path(&dir);
         fs::write(&file_path, yaml).expect("Unable to write temp config file");
 
-        let config_loader = ConfigLoader::from_path(file_path);
+        let mut config_loader = ConfigLoader::from_path(file_path);
         let config = config_loader.read_or_create_config().unwrap();
 
         assert_eq!(config.servicestatus[0].name, "My Api");
         assert_eq!(config.tokengenerator.auth0.local, "local_auth0");
     }
+
+    #[test]
+    fn read_or_create_config_reads_jwks_files() {
+        let yaml = "servicestatus: []
+tokengenerator:
+  auth0:
+    local: local_auth0
+    staging: staging_auth0
+    preproduction: preproduction_auth0
+    production: production_auth0
+  services: []
+  jwks:
+    files:
+      Local: ~/.devtool/jwks/local.json";
+
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        fs::write(&file_path, yaml).expect("Unable to write temp config file");
+
+        let config = ConfigLoader::from_path(file_path)
+            .read_or_create_config()
+            .unwrap();
+
+        assert_eq!(
+            config.tokengenerator.jwks.files.get(&Environment::Local),
+            Some(&"~/.devtool/jwks/local.json".to_string())
+        );
+        assert_eq!(config.tokengenerator.jwks.cache_minutes, 60);
+    }
+
+    fn touch(file_path: &PathBuf, contents: &str, modified: SystemTime) {
+        fs::write(file_path, contents).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(file_path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn reload_if_changed_ignores_our_own_writes() {
+        let dir = TempDir::new().unwrap();
+        let mut config_loader = ConfigLoader::from_path(temp_loader_path(&dir));
+        let mut config = config_loader.read_or_create_config().unwrap();
+        assert!(config_loader.reload_if_changed().is_none());
+
+        config.features.jira = false;
+        config_loader.write_config(&config).unwrap();
+        assert!(config_loader.reload_if_changed().is_none());
+    }
+
+    #[test]
+    fn reload_if_changed_reads_outside_edits_once() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        let mut config_loader = ConfigLoader::from_path(file_path.clone());
+        config_loader.read_or_create_config().unwrap();
+        let later = SystemTime::now() + std::time::Duration::from_secs(60);
+
+        touch(
+            &file_path,
+            "servicestatus: []\ntokengenerator:\n  auth0:\n    local: edited\n    staging: ''\n    preproduction: ''\n    production: ''\n  services: []\n",
+            later,
+        );
+        let config = config_loader.reload_if_changed().unwrap().unwrap();
+        assert_eq!(config.tokengenerator.auth0.local, "edited");
+        assert!(config_loader.reload_if_changed().is_none());
+
+        touch(
+            &file_path,
+            "servicestatus: [",
+            later + std::time::Duration::from_secs(1),
+        );
+        assert!(matches!(
+            config_loader.reload_if_changed(),
+            Some(Err(ConfigError::Invalid(_)))
+        ));
+        assert!(config_loader.reload_if_changed().is_none());
+    }
+
+    #[test]
+    fn invalid_config_reports_every_problem_and_is_not_overwritten() {
+        let yaml = "version: 1
+servicestatus:
+  - name: My Api
+    staging: https://myapi.staging.com
+    preprod: https://myapi.preprod.com
+    prod: https://myapi.prod.com
+    repo: https://github.com/myapi
+tokengenerator:
+  auth0:
+    local: ''
+    staging: ''
+    preproduction: ''
+    production: ''
+  services: []";
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        fs::write(&file_path, yaml).unwrap();
+
+        let mut config_loader = ConfigLoader::from_path(file_path.clone());
+        let Err(ConfigError::Invalid(problems)) = config_loader.read_or_create_config() else {
+            panic!("expected the config to be invalid");
+        };
+        assert_eq!(problems.len(), 4);
+        assert_eq!((problems[2].line, problems[2].column), (5, 5));
+
+        assert!(matches!(
+            config_loader.write_config(&Config::default()),
+            Err(ConfigError::Broken)
+        ));
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), yaml);
+    }
+
+    const UNVERSIONED: &str = "servicestatus:
+  - name: My Api
+    staging: https://myapi.staging.com
+    preprod: https://myapi.preprod.com
+    prod: https://myapi.prod.com
+    repo: https://github.com/myapi
+tokengenerator:
+  auth0:
+    local: ''
+    staging: ''
+    preproduction: ''
+    production: ''
+  services: []
+";
+
+    #[test]
+    fn migrate_upgrades_unversioned_files() {
+        let migration = migrate(UNVERSIONED).unwrap();
+        assert_eq!(migration.from, 0);
+        assert_eq!(migration.before, UNVERSIONED);
+        assert!(migration.after.starts_with("version: 1\n"));
+
+        let config: Config = serde_yaml::from_str(&migration.after).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(
+            config.servicestatus[0].preproduction,
+            "https://myapi.preprod.com"
+        );
+        assert_eq!(config.servicestatus[0].production, "https://myapi.prod.com");
+    }
+
+    #[test]
+    fn migrate_leaves_current_and_unreadable_files_alone() {
+        assert!(migrate(&serde_yaml::to_string(&Config::default()).unwrap()).is_none());
+        assert!(migrate("version: 99\n").is_none());
+        assert!(migrate("servicestatus: [").is_none());
+    }
+
+    #[test]
+    fn read_upgrades_the_file_and_keeps_a_backup() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        fs::write(&file_path, UNVERSIONED).unwrap();
+
+        let mut config_loader = ConfigLoader::from_path(file_path.clone());
+        let config = config_loader.read_or_create_config().unwrap();
+        assert_eq!(config.servicestatus[0].production, "https://myapi.prod.com");
+
+        let migrated = config_loader.take_migrated().unwrap();
+        assert_eq!(migrated.from, 0);
+        assert_eq!(migrated.backup, dir.path().join("config.yaml.v0.bak"));
+        assert_eq!(fs::read_to_string(&migrated.backup).unwrap(), UNVERSIONED);
+        assert!(config_loader.take_migrated().is_none());
+
+        assert!(config_loader.plan_migration().unwrap().is_none());
+        assert!(config_loader.reload_if_changed().is_none());
+    }
+
+    #[test]
+    fn shipped_example_is_not_upgraded() {
+        let example = include_str!("../../config/config.yaml.dist");
+        assert!(migrate(example).is_none());
+
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        fs::write(&file_path, example).unwrap();
+
+        let mut config_loader = ConfigLoader::from_path(file_path.clone());
+        let _ = config_loader.read_or_create_config();
+        assert!(config_loader.take_migrated().is_none());
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), example);
+        assert!(!dir.path().join("config.yaml.v0.bak").exists());
+    }
+
+    #[test]
+    fn migrate_keeps_comments_and_layout() {
+        let commented = format!(
+            "# my services\n{}",
+            UNVERSIONED.replace(
+                "    prod: https://myapi.prod.com\n",
+                "    prod: https://myapi.prod.com # live\n",
+            )
+        );
+        let migration = migrate(&commented).unwrap();
+        assert_eq!(
+            migration.after,
+            format!("version: 1\n{}", commented)
+                .replace("    preprod:", "    preproduction:")
+                .replace("    prod:", "    production:")
+        );
+    }
+
+    #[test]
+    fn invalid_old_file_is_not_upgraded_or_backed_up() {
+        let yaml = UNVERSIONED.replace("repo: https://github.com/myapi", "repo: not a url");
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        fs::write(&file_path, &yaml).unwrap();
+
+        let mut config_loader = ConfigLoader::from_path(file_path.clone());
+        assert!(matches!(
+            config_loader.read_or_create_config(),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(config_loader.take_migrated().is_none());
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), yaml);
+        assert!(!dir.path().join("config.yaml.v0.bak").exists());
+    }
+
+    #[test]
+    fn upgrade_never_overwrites_an_earlier_backup() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_loader_path(&dir);
+        fs::write(dir.path().join("config.yaml.v0.bak"), "earlier").unwrap();
+        fs::write(&file_path, UNVERSIONED).unwrap();
+
+        let mut config_loader = ConfigLoader::from_path(file_path);
+        config_loader.read_or_create_config().unwrap();
+        let migrated = config_loader.take_migrated().unwrap();
+        assert_eq!(migrated.backup, dir.path().join("config.yaml.v0.1.bak"));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.yaml.v0.bak")).unwrap(),
+            "earlier"
+        );
+        assert_eq!(fs::read_to_string(&migrated.backup).unwrap(), UNVERSIONED);
+    }
 }
@@ -1,2 +1,3 @@
 pub(crate) mod loader;
 pub(crate) mod model;
+pub(crate) mod validate;
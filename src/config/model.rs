@@ -1,12 +1,30 @@
+use crate::config::loader::CONFIG_VERSION;
 use crate::environment::Environment;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 
 fn default_true() -> bool {
     true
 }
 
+fn default_jwks_cache_minutes() -> u64 {
+    60
+}
+
+fn default_branch_template() -> String {
+    "{type}/{id}-{slug(title)}".to_string()
+}
+
+fn default_commit_template() -> String {
+    "{type}({id}): ".to_string()
+}
+
 #[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub(crate) struct Config {
+    /// The schema version the file was written for; see `config::loader` for the upgrades.
+    #[serde(default)]
+    pub version: u64,
     pub servicestatus: Vec<ServiceStatusConfig>,
     pub tokengenerator: TokenGenerator,
     pub jira: Option<JiraConfig>,
@@ -37,6 +55,7 @@ impl Default for Features {
 impl Default for Config {
     fn default() -> Self {
         Self {
+            version: CONFIG_VERSION,
             servicestatus: Vec::new(),
             tokengenerator: TokenGenerator::default(),
             jira: None,
@@ -75,6 +94,9 @@ impl Config {
 
         if let Some(ref mut jira) = self.jira {
             jira.url = Self::strip_trailing_slash(&jira.url);
+            for site in &mut jira.sites {
+                site.url = Self::strip_trailing_slash(&site.url);
+            }
         }
     }
 
@@ -102,6 +124,9 @@ pub(crate) struct ServiceStatusConfig {
     pub preproduction: String,
     pub production: String,
     pub repo: String,
+    /// A local clone of `repo`, read for the tickets waiting to go to production.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clone: Option<String>,
 }
 
 impl ServiceStatusConfig {
@@ -119,6 +144,16 @@ impl ServiceStatusConfig {
 pub(crate) struct TokenGenerator {
     pub auth0: Auth0Config,
     pub services: Vec<ServiceConfig>,
+    #[serde(default, skip_serializing_if = "JwksConfig::is_default")]
+    pub jwks: JwksConfig,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<TokenServerConfig>,
+}
+
+/// Serves the latest generated tokens on `127.0.0.1:<port>` while the TUI runs.
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct TokenServerConfig {
+    pub port: u16,
 }
 
 #[derive(Deserialize, Serialize, Clone, PartialEq, Default)]
@@ -140,6 +175,32 @@ impl Auth0Config {
     }
 }
 
+/// Where the token verifier gets signing keys from. Environments with a file
+/// configured are verified offline; the rest fetch the tenant's JWKS and keep
+/// it for `cache_minutes`.
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct JwksConfig {
+    #[serde(default)]
+    pub files: BTreeMap<Environment, String>,
+    #[serde(default = "default_jwks_cache_minutes")]
+    pub cache_minutes: u64,
+}
+
+impl Default for JwksConfig {
+    fn default() -> Self {
+        Self {
+            files: BTreeMap::new(),
+            cache_minutes: default_jwks_cache_minutes(),
+        }
+    }
+}
+
+impl JwksConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct ServiceConfig {
     pub name: String,
@@ -152,11 +213,259 @@ pub struct Credentials {
     pub env: Environment,
     pub client_id: String,
     pub client_secret: String,
+    /// When set, every token generated for this environment is also written here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_file: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Default)]
 pub struct JiraConfig {
+    pub url: String,
+    /// Only used on Cloud, where the token is an API token tied to this account.
+    pub email: String,
+    /// An API token on Cloud; a personal access token on Server / Data Center.
+    pub token: String,
+    #[serde(default, skip_serializing_if = "Deployment::is_cloud")]
+    pub deployment: Deployment,
+    /// Overrides the status-category colour, keyed by status name or by project key
+    /// for a map scoped to that project's workflow.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub status_colours: BTreeMap<String, StatusColour>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub queries: Vec<SavedQuery>,
+    #[serde(default, skip_serializing_if = "JiraGitConfig::is_default")]
+    pub git: JiraGitConfig,
+    /// The agile board whose columns the board view lays tickets out in, by its ID
+    /// (the `rapidView` / `boards/…` number in the board's URL).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board: Option<u64>,
+    /// More Jira sites besides the one above, which is the main site.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sites: Vec<JiraSite>,
+    #[serde(default, skip_serializing_if = "JiraArchiveConfig::is_default")]
+    pub archive: JiraArchiveConfig,
+}
+
+impl JiraConfig {
+    /// The site a new ticket goes to: the one listing its project, otherwise the
+    /// main site, which is named by an empty string.
+    pub fn route(&self, ticket_id: &str) -> &str {
+        let project = ticket_id.split_once('-').map_or(ticket_id, |(p, _)| p);
+        self.sites
+            .iter()
+            .find(|site| {
+                site.projects
+                    .iter()
+                    .any(|p| p.eq_ignore_ascii_case(project))
+            })
+            .map_or("", |site| site.name.as_str())
+    }
+
+    /// This config pointed at `site`'s URL, credentials and board. The main site, or
+    /// a site no longer configured, leaves it as it is.
+    pub fn for_site(&self, site: &str) -> JiraConfig {
+        let mut config = self.clone();
+        if let Some(site) = self.sites.iter().find(|s| s.name == site) {
+            config.url = site.url.clone();
+            config.email = site.email.clone();
+            config.token = site.token.clone();
+            config.deployment = site.deployment;
+            config.board = site.board;
+        }
+        config
+    }
+
+    /// Every site a ticket can be added to, the main site first.
+    pub fn site_names(&self) -> Vec<String> {
+        std::iter::once(String::new())
+            .chain(self.sites.iter().map(|site| site.name.clone()))
+            .collect()
+    }
+
+    /// The configured colour for `status` in `project`. A project-scoped entry wins
+    /// over a global one; status names match case-insensitively.
+    pub fn status_colour(&self, project: &str, status: &str) -> Option<&str> {
+        let scoped = match self.status_colours.get(project) {
+            Some(StatusColour::Workflow(colours)) => colours
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(status))
+                .map(|(_, colour)| colour.as_str()),
+            _ => None,
+        };
+        scoped.or_else(|| {
+            self.status_colours
+                .iter()
+                .find_map(|(name, colour)| match colour {
+                    StatusColour::Colour(colour) if name.eq_ignore_ascii_case(status) => {
+                        Some(colour.as_str())
+                    }
+                    _ => None,
+                })
+        })
+    }
+}
+
+/// Another Jira site with its own URL and credentials, such as a client's. Tickets
+/// in `projects` go to it; any other ticket can be sent to it when it is added.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct JiraSite {
+    pub name: String,
     pub url: String,
     pub email: String,
     pub token: String,
+    #[serde(default, skip_serializing_if = "Deployment::is_cloud")]
+    pub deployment: Deployment,
+    /// Project keys whose tickets live on this site, e.g. `ACME`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<String>,
+    /// This site's agile board for the board view, like the main site's `board`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board: Option<u64>,
+}
+
+/// How tickets turn into git branches and commit messages. Templates take `{id}`,
+/// `{project}`, `{title}`, `{type}` (the conventional commit type) and `{issue_type}`,
+/// and `{slug(name)}` for a lowercase, dash-separated form of any of them.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct JiraGitConfig {
+    /// The local clone branches are created and checked out in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// More clones to look in for branches of watched tickets, besides `repository`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories: Vec<String>,
+    #[serde(default = "default_branch_template")]
+    pub branch_template: String,
+    #[serde(default = "default_commit_template")]
+    pub commit_template: String,
+    /// Conventional commit type per Jira issue type, e.g. `Task: chore`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub types: BTreeMap<String, String>,
+}
+
+impl Default for JiraGitConfig {
+    fn default() -> Self {
+        Self {
+            repository: None,
+            repositories: Vec::new(),
+            branch_template: default_branch_template(),
+            commit_template: default_commit_template(),
+            types: BTreeMap::new(),
+        }
+    }
+}
+
+impl JiraGitConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Every clone to scan for ticket branches, `repository` first.
+    pub fn scan_repositories(&self) -> Vec<&str> {
+        let mut repositories = Vec::new();
+        for repository in self.repository.iter().chain(&self.repositories) {
+            if !repositories.contains(&repository.as_str()) {
+                repositories.push(repository.as_str());
+            }
+        }
+        repositories
+    }
+
+    /// The conventional commit type for a Jira issue type: a configured mapping,
+    /// otherwise `fix` for bugs and `feat` for everything else.
+    pub fn commit_type(&self, issue_type: &str) -> &str {
+        self.types
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(issue_type))
+            .map(|(_, commit_type)| commit_type.as_str())
+            .unwrap_or(if issue_type.eq_ignore_ascii_case("bug") {
+                "fix"
+            } else {
+                "feat"
+            })
+    }
+}
+
+/// When watchlist tickets move to the archive, checked after every scan.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct JiraArchiveConfig {
+    /// Archive tickets once their status is in the done category.
+    #[serde(default = "default_true")]
+    pub done: bool,
+    /// Days a ticket may stay in a status before it is archived, by status name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub after_days: BTreeMap<String, u32>,
+}
+
+impl Default for JiraArchiveConfig {
+    fn default() -> Self {
+        Self {
+            done: true,
+            after_days: BTreeMap::new(),
+        }
+    }
+}
+
+impl JiraArchiveConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The days a ticket may stay in `status`, matched case-insensitively.
+    pub fn days_in(&self, status: &str) -> Option<u32> {
+        self.after_days
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(status))
+            .map(|(_, days)| *days)
+    }
+}
+
+/// A `status_colours` entry: a colour name (`magenta`, `light-cyan`, `#ff8800`) for
+/// one status, or a map of those for every status in one project's workflow.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum StatusColour {
+    Colour(String),
+    Workflow(BTreeMap<String, String>),
+}
+
+/// Where Jira is hosted, which decides the REST API version and auth scheme.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Deployment {
+    #[default]
+    Cloud,
+    Server,
+}
+
+impl Deployment {
+    pub fn api_version(self) -> u8 {
+        match self {
+            Deployment::Cloud => 3,
+            Deployment::Server => 2,
+        }
+    }
+
+    fn is_cloud(&self) -> bool {
+        *self == Deployment::Cloud
+    }
+}
+
+impl Display for Deployment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Deployment::Cloud => write!(f, "Cloud"),
+            Deployment::Server => write!(f, "Server / Data Center"),
+        }
+    }
+}
+
+/// A named JQL search shown as its own tab next to the watchlist.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct SavedQuery {
+    pub name: String,
+    pub jql: String,
+    /// The site to search, by name; the main site when empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub site: String,
 }
@@ -0,0 +1,784 @@
+use crate::config::loader::CONFIG_VERSION;
+use crate::config::model::{
+    Auth0Config, Config, Credentials, Deployment, Features, JiraArchiveConfig, JiraConfig,
+    JiraGitConfig, JiraSite, JwksConfig, SavedQuery, ServiceConfig, ServiceStatusConfig,
+    StatusColour, TokenGenerator, TokenServerConfig,
+};
+use crate::environment::Environment;
+use reqwest::Url;
+use serde_yaml::{Mapping, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+const ENVIRONMENTS: [&str; 4] = ["Local", "Staging", "Preproduction", "Production"];
+
+/// Something wrong in config.yaml, and where it is.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Problem {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Problem {
+    fn from_yaml_error(e: &serde_yaml::Error) -> Self {
+        let (line, column) = e
+            .location()
+            .map_or((1, 1), |location| (location.line(), location.column()));
+        // The location is reported separately, so drop serde_yaml's " at line …".
+        let message = e.to_string();
+        let message = message.split(" at line ").next().unwrap_or_default();
+        Self {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// Every problem in `source`, in file order. An empty list means it loads.
+pub fn validate(source: &str) -> Vec<Problem> {
+    let root = match serde_yaml::from_str::<Value>(source) {
+        Ok(root) => root,
+        Err(e) => return vec![Problem::from_yaml_error(&e)],
+    };
+    let mut checker = Checker {
+        positions: positions(source),
+        model: serde_yaml::to_value(populated()).expect("the config model serializes"),
+        problems: Vec::new(),
+    };
+    checker.root(&root);
+
+    // The loader stops at its first error, which is usually one of the above. When
+    // nothing was found, it's something the checks don't cover, such as a number
+    // where text should be.
+    if checker.problems.is_empty()
+        && let Err(e) = serde_yaml::from_str::<Config>(source)
+    {
+        checker.problems.push(Problem::from_yaml_error(&e));
+    }
+
+    let mut problems = checker.problems;
+    problems.sort_by_key(|p| (p.line, p.column));
+    problems.dedup();
+    problems
+}
+
+// ── Known keys ────────────────────────────────────────────────────────────────
+
+/// A config with every optional part filled in, so that serialized it has every key
+/// the model reads. It's written out in full, without `..Default::default()`, so a
+/// new field doesn't compile until it's added here.
+fn populated() -> Config {
+    let url = || "https://example.com".to_string();
+    let text = || "x".to_string();
+    let environments = [
+        Environment::Local,
+        Environment::Staging,
+        Environment::Preproduction,
+        Environment::Production,
+    ];
+    Config {
+        version: CONFIG_VERSION,
+        servicestatus: vec![ServiceStatusConfig {
+            name: text(),
+            staging: url(),
+            preproduction: url(),
+            production: url(),
+            repo: url(),
+            clone: Some(text()),
+        }],
+        tokengenerator: TokenGenerator {
+            auth0: Auth0Config {
+                local: url(),
+                staging: url(),
+                preproduction: url(),
+                production: url(),
+            },
+            services: vec![ServiceConfig {
+                name: text(),
+                audience: text(),
+                credentials: vec![Credentials {
+                    env: Environment::Local,
+                    client_id: text(),
+                    client_secret: text(),
+                    token_file: Some(text()),
+                }],
+            }],
+            jwks: JwksConfig {
+                files: environments.into_iter().map(|env| (env, text())).collect(),
+                cache_minutes: 1,
+            },
+            server: Some(TokenServerConfig { port: 1 }),
+        },
+        jira: Some(JiraConfig {
+            url: url(),
+            email: text(),
+            token: text(),
+            deployment: Deployment::Server,
+            status_colours: BTreeMap::from([(text(), StatusColour::Colour(text()))]),
+            queries: vec![SavedQuery {
+                name: text(),
+                jql: text(),
+                site: text(),
+            }],
+            git: JiraGitConfig {
+                repository: Some(text()),
+                repositories: vec![text()],
+                branch_template: text(),
+                commit_template: text(),
+                types: BTreeMap::from([(text(), text())]),
+            },
+            board: Some(1),
+            sites: vec![JiraSite {
+                name: text(),
+                url: url(),
+                email: text(),
+                token: text(),
+                deployment: Deployment::Server,
+                projects: vec![text()],
+                board: Some(1),
+            }],
+            archive: JiraArchiveConfig {
+                done: false,
+                after_days: BTreeMap::from([(text(), 1)]),
+            },
+        }),
+        features: Features {
+            service_status: true,
+            token_generator: true,
+            jira: true,
+        },
+    }
+}
+
+// ── Locating ──────────────────────────────────────────────────────────────────
+
+/// Where each key and sequence item in `source` starts, by path such as
+/// `servicestatus[0].staging`. Block-style YAML only; anything in a flow
+/// collection is found at its key.
+pub(crate) fn positions(source: &str) -> HashMap<String, (usize, usize)> {
+    let mut positions = HashMap::new();
+    // The keys and items enclosing the current line: their column, path and,
+    // for items, index.
+    let mut open: Vec<(usize, String, Option<usize>)> = Vec::new();
+    let mut block_scalar = None;
+
+    for (number, line) in source.lines().enumerate() {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let mut rest = line[indent..].trim_end();
+        if rest.is_empty() || rest.starts_with('#') || rest.starts_with("---") {
+            continue;
+        }
+        match block_scalar {
+            Some(column) if indent > column => continue,
+            _ => block_scalar = None,
+        }
+
+        let mut column = indent;
+        while rest == "-" || rest.starts_with("- ") {
+            let mut index = 0;
+            while let Some((open_column, _, open_index)) = open.last() {
+                if *open_column < column || (*open_column == column && open_index.is_none()) {
+                    break;
+                }
+                if *open_column == column {
+                    index = open_index.map_or(0, |i| i + 1);
+                }
+                open.pop();
+            }
+            let path = format!("{}[{}]", open.last().map_or("", |(_, p, _)| p), index);
+            positions
+                .entry(path.clone())
+                .or_insert((number + 1, column + 1));
+            open.push((column, path, Some(index)));
+
+            let item = &rest[1..];
+            let item_start = item.trim_start();
+            column += 1 + item.len() - item_start.len();
+            rest = item_start;
+        }
+
+        if let Some((key, value)) = split_key(rest) {
+            while open
+                .last()
+                .is_some_and(|(open_column, _, _)| *open_column >= column)
+            {
+                open.pop();
+            }
+            let path = match open.last() {
+                Some((_, parent, _)) => format!("{}.{}", parent, key),
+                None => key.to_string(),
+            };
+            positions
+                .entry(path.clone())
+                .or_insert((number + 1, column + 1));
+            if value.starts_with('|') || value.starts_with('>') {
+                block_scalar = Some(column);
+            }
+            open.push((column, path, None));
+        }
+    }
+    positions
+}
+
+/// The key and value of a `key: value` line, with any quotes around the key removed.
+fn split_key(line: &str) -> Option<(&str, &str)> {
+    let (key, after) = match line.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let end = line[1..].find(quote)? + 1;
+            (&line[1..end], &line[end + 1..])
+        }
+        '{' | '[' => return None,
+        _ => {
+            let end = match line.find(": ") {
+                Some(end) => end,
+                None => line.strip_suffix(':')?.len(),
+            };
+            (&line[..end], &line[end..])
+        }
+    };
+    let value = after.strip_prefix(':')?;
+    (value.is_empty() || value.starts_with(' ')).then(|| (key.trim_end(), value.trim()))
+}
+
+// ── Checking ──────────────────────────────────────────────────────────────────
+
+struct Checker {
+    positions: HashMap<String, (usize, usize)>,
+    /// [`populated`] serialized, where the keys known at each path are looked up.
+    model: Value,
+    problems: Vec<Problem>,
+}
+
+impl Checker {
+    fn root(&mut self, root: &Value) {
+        let Some(root) = self.mapping("", root, &["servicestatus", "tokengenerator"]) else {
+            return;
+        };
+        if let Some(version) = root.get("version").and_then(Value::as_u64)
+            && version > CONFIG_VERSION
+        {
+            self.report(
+                "version",
+                format!(
+                    "version {} is newer than this dev-tool understands ({}) — update dev-tool",
+                    version, CONFIG_VERSION
+                ),
+            );
+        }
+        if let Some(features) = root.get("features") {
+            self.mapping("features", features, &[]);
+        }
+        if let Some(services) = root.get("servicestatus") {
+            self.service_status(services);
+        }
+        if let Some(token_generator) = root.get("tokengenerator") {
+            self.token_generator(token_generator);
+        }
+        if let Some(jira) = root.get("jira") {
+            self.jira(jira);
+        }
+    }
+
+    fn service_status(&mut self, services: &Value) {
+        let path = "servicestatus";
+        for (i, service) in self.sequence(path, services).iter().enumerate() {
+            let path = format!("{}[{}]", path, i);
+            let Some(service) = self.mapping(
+                &path,
+                service,
+                &["name", "staging", "preproduction", "production", "repo"],
+            ) else {
+                continue;
+            };
+            for key in ["staging", "preproduction", "production", "repo"] {
+                self.url(&path, service, key);
+            }
+        }
+        self.unique_names(path, services, "service");
+    }
+
+    fn token_generator(&mut self, token_generator: &Value) {
+        let path = "tokengenerator";
+        let Some(token_generator) = self.mapping(path, token_generator, &["auth0", "services"])
+        else {
+            return;
+        };
+
+        let mut used_envs = HashSet::new();
+        if let Some(services) = token_generator.get("services") {
+            let services_path = format!("{}.services", path);
+            for (i, service) in self.sequence(&services_path, services).iter().enumerate() {
+                let service_path = format!("{}[{}]", services_path, i);
+                let Some(service) =
+                    self.mapping(&service_path, service, &["name", "audience", "credentials"])
+                else {
+                    continue;
+                };
+                if let Some(credentials) = service.get("credentials") {
+                    used_envs.extend(self.credentials(&service_path, credentials));
+                }
+            }
+            self.unique_names(&services_path, services, "service");
+        }
+
+        if let Some(auth0) = token_generator.get("auth0") {
+            let auth0_path = format!("{}.auth0", path);
+            let keys = ["local", "staging", "preproduction", "production"];
+            if let Some(auth0) = self.mapping(&auth0_path, auth0, &keys) {
+                // An environment without credentials never asks Auth0 for a token.
+                for (key, env) in keys.into_iter().zip(ENVIRONMENTS) {
+                    if used_envs.contains(env) {
+                        self.url(&auth0_path, auth0, key);
+                    }
+                }
+            }
+        }
+        if let Some(jwks) = token_generator.get("jwks") {
+            let jwks_path = format!("{}.jwks", path);
+            if let Some(jwks) = self.mapping(&jwks_path, jwks, &[])
+                && let Some(files) = jwks.get("files")
+            {
+                // Files are keyed by environment, so an unknown one is an unknown key.
+                self.mapping(&format!("{}.files", jwks_path), files, &[]);
+            }
+        }
+        if let Some(server) = token_generator.get("server") {
+            self.mapping(&format!("{}.server", path), server, &["port"]);
+        }
+    }
+
+    /// Checks a service's credentials, returning the environments they cover.
+    fn credentials(&mut self, service_path: &str, credentials: &Value) -> Vec<&'static str> {
+        let path = format!("{}.credentials", service_path);
+        let mut envs = Vec::new();
+        for (i, entry) in self.sequence(&path, credentials).iter().enumerate() {
+            let entry_path = format!("{}[{}]", path, i);
+            let Some(entry) = self.mapping(&entry_path, entry, &["env"]) else {
+                continue;
+            };
+            if let Some(env) = entry.get("env") {
+                match ENVIRONMENTS
+                    .iter()
+                    .find(|known| env.as_str() == Some(**known))
+                {
+                    Some(known) => envs.push(*known),
+                    None => self.report(
+                        &format!("{}.env", entry_path),
+                        format!(
+                            "credentials for unknown environment {} — expected one of {}",
+                            describe(env),
+                            ENVIRONMENTS.join(", ")
+                        ),
+                    ),
+                }
+            }
+            self.secret(&entry_path, entry, "client_id");
+            self.secret(&entry_path, entry, "client_secret");
+        }
+        envs
+    }
+
+    fn jira(&mut self, jira: &Value) {
+        let path = "jira";
+        let Some(jira) = self.mapping(path, jira, &["url", "email"]) else {
+            return;
+        };
+        self.url(path, jira, "url");
+        self.secret(path, jira, "token");
+
+        if let Some(queries) = jira.get("queries") {
+            let queries_path = format!("{}.queries", path);
+            for (i, query) in self.sequence(&queries_path, queries).iter().enumerate() {
+                self.mapping(&format!("{}[{}]", queries_path, i), query, &["name", "jql"]);
+            }
+        }
+        if let Some(git) = jira.get("git") {
+            self.mapping(&format!("{}.git", path), git, &[]);
+        }
+        if let Some(archive) = jira.get("archive") {
+            self.mapping(&format!("{}.archive", path), archive, &[]);
+        }
+        if let Some(sites) = jira.get("sites") {
+            let sites_path = format!("{}.sites", path);
+            for (i, site) in self.sequence(&sites_path, sites).iter().enumerate() {
+                let site_path = format!("{}[{}]", sites_path, i);
+                let Some(site) = self.mapping(&site_path, site, &["name", "url", "email"]) else {
+                    continue;
+                };
+                self.url(&site_path, site, "url");
+                self.secret(&site_path, site, "token");
+            }
+            self.unique_names(&sites_path, sites, "site");
+        }
+    }
+
+    // ── Checks ────────────────────────────────────────────────────────────────
+
+    /// `value` as a mapping, reporting keys the model doesn't have at `path` and
+    /// missing `required` ones. Empty values are left to the loader.
+    fn mapping<'a>(
+        &mut self,
+        path: &str,
+        value: &'a Value,
+        required: &[&str],
+    ) -> Option<&'a Mapping> {
+        let mapping = match value {
+            Value::Mapping(mapping) => mapping,
+            Value::Null => return None,
+            value => {
+                self.report(
+                    path,
+                    format!("expected a mapping, found {}", describe(value)),
+                );
+                return None;
+            }
+        };
+        let known = self.known_keys(path);
+        for key in mapping.keys() {
+            let Some(key) = key.as_str() else {
+                self.report(path, format!("unexpected key {}", describe(key)));
+                continue;
+            };
+            if !known.iter().any(|known| known == key) {
+                let message = match suggestion(key, &known) {
+                    Some(known) => format!("unknown key `{}` — did you mean `{}`?", key, known),
+                    None => format!("unknown key `{}`", key),
+                };
+                self.report(&child(path, key), message);
+            }
+        }
+        for key in required {
+            if !mapping.contains_key(*key) {
+                self.report(path, format!("missing key `{}`", key));
+            }
+        }
+        Some(mapping)
+    }
+
+    /// The keys at `path` in the model, where every item of a list is like its first.
+    fn known_keys(&self, path: &str) -> Vec<String> {
+        let mut value = &self.model;
+        for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+            value = match segment.split_once('[') {
+                Some((key, _)) => &value[key][0],
+                None => &value[segment],
+            };
+        }
+        value
+            .as_mapping()
+            .into_iter()
+            .flat_map(Mapping::keys)
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// `value`'s items, reporting it if it isn't a sequence.
+    fn sequence<'a>(&mut self, path: &str, value: &'a Value) -> &'a [Value] {
+        match value {
+            Value::Sequence(items) => items,
+            Value::Null => &[],
+            value => {
+                self.report(path, format!("expected a list, found {}", describe(value)));
+                &[]
+            }
+        }
+    }
+
+    /// Reports `key` unless it is an http(s) URL. A missing key is reported elsewhere.
+    fn url(&mut self, path: &str, mapping: &Mapping, key: &str) {
+        let Some(value) = mapping.get(key) else {
+            return;
+        };
+        let valid = value.as_str().is_some_and(|url| {
+            Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+        });
+        if !valid {
+            let message = if is_empty(value) {
+                format!("`{}` is empty — expected a URL", key)
+            } else {
+                format!("`{}` is not a valid URL: {}", key, describe(value))
+            };
+            self.report(&child(path, key), message);
+        }
+    }
+
+    /// Reports `key` if it is missing or empty.
+    fn secret(&mut self, path: &str, mapping: &Mapping, key: &str) {
+        match mapping.get(key) {
+            None => self.report(path, format!("missing secret `{}`", key)),
+            Some(value) if is_empty(value) => {
+                self.report(&child(path, key), format!("`{}` is empty", key))
+            }
+            Some(_) => {}
+        }
+    }
+
+    /// Reports every item of `items` whose name repeats an earlier one.
+    fn unique_names(&mut self, path: &str, items: &Value, what: &str) {
+        let Some(items) = items.as_sequence() else {
+            return;
+        };
+        let mut seen = HashSet::new();
+        for (i, item) in items.iter().enumerate() {
+            if let Some(name) = item.get("name").and_then(Value::as_str)
+                && !name.is_empty()
+                && !seen.insert(name)
+            {
+                self.report(
+                    &format!("{}[{}].name", path, i),
+                    format!("duplicate {} name `{}`", what, name),
+                );
+            }
+        }
+    }
+
+    fn report(&mut self, path: &str, message: String) {
+        let (line, column) = self.position(path);
+        self.problems.push(Problem {
+            line,
+            column,
+            message,
+        });
+    }
+
+    /// Where `path` is, or the nearest enclosing key or item that could be found.
+    fn position(&self, path: &str) -> (usize, usize) {
+        let mut path = path;
+        loop {
+            if let Some(position) = self.positions.get(path) {
+                return *position;
+            }
+            match path.rfind(['.', '[']) {
+                Some(end) => path = &path[..end],
+                None => return (1, 1),
+            }
+        }
+    }
+}
+
+fn child(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// A known key `key` looks like a shortened or mistyped form of.
+fn suggestion<'a>(key: &str, known: &'a [String]) -> Option<&'a str> {
+    let key = key.to_lowercase();
+    known
+        .iter()
+        .find(|known| known.starts_with(&key) || key.starts_with(known.as_str()))
+        .map(String::as_str)
+}
+
+fn is_empty(value: &Value) -> bool {
+    value.is_null() || value.as_str() == Some("")
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => "nothing".to_string(),
+        Value::Bool(b) => format!("`{}`", b),
+        Value::Number(n) => format!("`{}`", n),
+        Value::String(s) => format!("`{}`", s),
+        Value::Sequence(_) => "a list".to_string(),
+        Value::Mapping(_) => "a mapping".to_string(),
+        Value::Tagged(tagged) => format!("`{}`", tagged.tag),
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "servicestatus:
+- name: My Api
+  staging: https://myapi.staging.com
+  preproduction: https://myapi.preprod.com
+  production: https://myapi.prod.com
+  repo: https://github.com/myapi
+tokengenerator:
+  auth0:
+    local: ''
+    staging: https://staging.auth0.com/oauth/token
+    preproduction: ''
+    production: ''
+  services:
+    - name: My Api
+      audience: https://myapi
+      credentials:
+        - env: Staging
+          client_id: id
+          client_secret: secret
+";
+
+    fn messages(source: &str) -> Vec<String> {
+        validate(source).iter().map(Problem::to_string).collect()
+    }
+
+    #[test]
+    fn valid_config_has_no_problems() {
+        assert_eq!(messages(VALID), Vec::<String>::new());
+    }
+
+    #[test]
+    fn unknown_keys_are_reported_with_a_suggestion() {
+        let source = VALID
+            .replace(
+                "  preproduction: https://myapi.preprod",
+                "  preprod: https://myapi.preprod",
+            )
+            .replace(
+                "  production: https://myapi.prod",
+                "  prod: https://myapi.prod",
+            );
+        assert_eq!(
+            messages(&source),
+            [
+                "line 2, column 1: missing key `preproduction`",
+                "line 2, column 1: missing key `production`",
+                "line 4, column 3: unknown key `preprod` — did you mean `preproduction`?",
+                "line 5, column 3: unknown key `prod` — did you mean `production`?",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_urls_are_reported() {
+        let source = VALID
+            .replace("https://myapi.staging.com", "myapi.staging.com")
+            .replace("https://github.com/myapi", "''");
+        assert_eq!(
+            messages(&source),
+            [
+                "line 3, column 3: `staging` is not a valid URL: `myapi.staging.com`",
+                "line 6, column 3: `repo` is empty — expected a URL",
+            ]
+        );
+    }
+
+    #[test]
+    fn auth0_urls_are_only_needed_for_environments_with_credentials() {
+        let source = VALID.replace("https://staging.auth0.com/oauth/token", "''");
+        assert_eq!(
+            messages(&source),
+            ["line 10, column 5: `staging` is empty — expected a URL"]
+        );
+    }
+
+    #[test]
+    fn duplicate_names_are_reported() {
+        let source = format!(
+            "{}{}",
+            VALID,
+            "    - name: My Api
+      audience: https://other
+      credentials: []
+"
+        );
+        assert_eq!(
+            messages(&source),
+            ["line 20, column 7: duplicate service name `My Api`"]
+        );
+    }
+
+    #[test]
+    fn credentials_need_a_known_environment_and_secrets() {
+        let source = VALID
+            .replace("env: Staging", "env: Dev")
+            .replace("client_secret: secret", "client_secret:");
+        assert_eq!(
+            messages(&source),
+            [
+                "line 17, column 11: credentials for unknown environment `Dev` — expected one of Local, Staging, Preproduction, Production",
+                "line 19, column 11: `client_secret` is empty",
+            ]
+        );
+    }
+
+    #[test]
+    fn jira_needs_a_token_and_url() {
+        let source = format!(
+            "{}jira:\n  url: jira.example.com\n  email: me@example.com\n",
+            VALID
+        );
+        assert_eq!(
+            messages(&source),
+            [
+                "line 20, column 1: missing secret `token`",
+                "line 21, column 3: `url` is not a valid URL: `jira.example.com`",
+            ]
+        );
+    }
+
+    #[test]
+    fn newer_versions_are_reported() {
+        let source = format!("version: {}\n{}", CONFIG_VERSION + 1, VALID);
+        assert_eq!(validate(&source)[0].line, 1);
+        assert!(validate(&source)[0].message.contains("newer"));
+    }
+
+    #[test]
+    fn syntax_errors_stop_at_the_first() {
+        let problems = validate("servicestatus: []\ntokengenerator: a: b\njira: [");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, 2);
+    }
+
+    #[test]
+    fn wrong_types_fall_back_to_the_loader_error() {
+        let source = format!("{}  server:\n    port: high\n", VALID);
+        let problems = validate(&source);
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, 21);
+        assert!(problems[0].message.contains("invalid"));
+    }
+
+    #[test]
+    fn positions_follow_nested_and_unindented_sequences() {
+        let positions =
+            positions("a:\n- x: 1\n  y:\n    - 'q': 2\n    - |\n      - z: 3\n- x: 4\nb: 5\n");
+        assert_eq!(positions["a[0].x"], (2, 3));
+        assert_eq!(positions["a[0].y"], (3, 3));
+        assert_eq!(positions["a[0].y[0].q"], (4, 7));
+        assert_eq!(positions["a[0].y[1]"], (5, 5));
+        assert_eq!(positions["a[1].x"], (7, 3));
+        assert_eq!(positions["b"], (8, 1));
+        assert!(!positions.contains_key("a[0].y[1].z"));
+    }
+
+    #[test]
+    fn every_key_of_a_populated_config_is_accepted() {
+        let yaml = serde_yaml::to_string(&populated()).unwrap();
+        assert_eq!(validate(&yaml), vec![]);
+    }
+
+    #[test]
+    fn shipped_example_only_lacks_values() {
+        let example = include_str!("../../config/config.yaml.dist");
+        let unknown = validate(example)
+            .into_iter()
+            .filter(|p| {
+                p.message.starts_with("unknown key") || p.message.starts_with("missing key")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(unknown, vec![]);
+    }
+}
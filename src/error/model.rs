@@ -1,3 +1,5 @@
+use crate::config::validate::Problem;
+
 #[derive(thiserror::Error, Debug)]
 pub enum ClientError {
     #[error("Failed to parse response: {0}")]
@@ -15,6 +17,18 @@ pub enum ConfigError {
     Parse(#[from] serde_yaml::Error),
     #[error("Failed to read config: {0}")]
     Read(#[from] std::io::Error),
+    #[error("config.yaml has {} problem(s):\n{}", .0.len(), list_problems(.0))]
+    Invalid(Vec<Problem>),
+    #[error("config.yaml has problems, so it wasn't overwritten")]
+    Broken,
+}
+
+fn list_problems(problems: &[Problem]) -> String {
+    problems
+        .iter()
+        .map(|problem| format!("  {}", problem))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// Errors from reading or writing the persistence file.
@@ -1,8 +1,14 @@
-use crate::client::jira::models::TicketResponse;
+use crate::client::auth_zero::jwt::VerificationReport;
+use crate::client::jira::models::{
+    AssignTo, BoardConfiguration, IssueDetails, TicketResponse, Transition, User,
+};
 use crate::environment::Environment;
-use crate::state::app::{AppFocus, Tool};
+use crate::state::app::{AppFocus, ToolId};
+use crate::state::jira_board::BoardStep;
 use crate::state::log::LogEntry;
+use crate::state::service_status::CommitRange;
 use crate::state::token_generator::Focus;
+use crate::utils::git::{Checkout, TicketBranch};
 use ratatui::crossterm::event::Event as CrosstermEvent;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -34,7 +40,7 @@ pub enum Event {
 pub enum AppEvent {
     // List event
     ListMove(Direction), // Move up and down tool List
-    ListSelect(Tool),    // Select item on tool list
+    ListSelect(ToolId),  // Select item on tool list
     DismissPopup,
 
     // Log event
@@ -46,8 +52,9 @@ pub enum AppEvent {
     // Config event
     ConfigListMove(Direction),
     ToggleFeature,
-    OpenToolConfig(Tool),
+    OpenToolConfig(ToolId),
     CloseToolConfig,
+    ReloadConfig, // Re-read config.yaml if it has been edited outside the app
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -62,10 +69,14 @@ pub enum GenericEvent {
 #[derive(Clone, Debug, PartialEq)]
 pub enum ServiceStatusEvent {
     ListMove(Direction),
-    GetCommitRefOk(String, usize, Environment),
-    GetCommitRefErrored(String, usize, Environment),
+    // Results carry the reload generation the request started in; see `ServiceStatus`.
+    GetCommitRefOk(String, usize, Environment, u64),
+    GetCommitRefErrored(String, usize, Environment, u64),
     Scan, // Scan all services
     ScanServiceEnv(usize, Environment),
+    PendingTicketsOk(Vec<String>, usize, CommitRange, u64),
+    PendingTicketsErrored(String, usize, CommitRange, u64),
+    WatchPendingTickets, // Add the selected service's pending tickets to the Jira watchlist
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -92,8 +103,17 @@ pub enum TokenGeneratorEvent {
     EnvListMove(Direction),
     SetFocus(Focus),
     GenerateToken,
-    TokenGenerated(String, usize, usize),
-    TokenFailed(String, usize, usize),
+    // Results carry the reload generation the request started in; see `TokenGenerator`.
+    TokenGenerated(String, usize, usize, u64),
+    TokenFailed(String, usize, usize, u64),
+    VerifyToken,
+    OpenPasteToken,
+    CancelPasteToken,
+    PasteTokenChar(char),
+    PasteTokenBackspace,
+    SubmitPastedToken,
+    TokenVerified(VerificationReport),
+    TokenVerifyFailed(String),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -119,6 +139,8 @@ pub enum TokenGeneratorConfigEvent {
 pub enum JiraEvent {
     ListMove(Direction), // Move down ticket list
     NewTicket,
+    ImportFromFile,
+    ImportFromClipboard,
     CancelNewTicket,
     AddTicketIdChar(char),
     RemoveTicketIdChar,
@@ -128,11 +150,104 @@ pub enum JiraEvent {
     TicketIdEnd,
     TicketIdDelete,
     SubmitTicketId,
+    CycleTicketSite, // Choose the site the tickets being added go to
+    OpenFilter,      // Narrow the watchlist down by text
+    CycleSort,
+    CycleGroup,
     RemoveTicket,
+    RestoreTicket,         // Put the selected archived ticket back on the watchlist
     TicketMove(Direction), // Move selected ticket up and down list
-    TicketRetrieved(TicketResponse),
+    TicketRetrieved(String, TicketResponse), // The site it was fetched from, and the ticket
+    TicketsRetrieved(String, Vec<String>, Vec<TicketResponse>), // Site, requested IDs and those found
+    TicketScanned(TicketResponse),
+    TicketScanFailed(String, String),
     TicketListUpdate,
     ScanTickets,
+    NextTab,
+    PrevTab,
+    QueryResults(usize, Vec<TicketResponse>),
+    QueryFailed(usize, String),
+    OpenTransitions,
+    TransitionsRetrieved(String, Vec<Transition>),
+    TransitionTicket(String, Transition),
+    TicketTransitioned(String, Transition),
+    OpenDetail,
+    CloseDetail,
+    ScrollDetail(Direction, u16),
+    TicketDetailsRetrieved(Box<IssueDetails>),
+    TicketDetailsFailed(String, String),
+    OpenCreateIssue,
+    CancelCreateIssue,
+    CreateFormNextField,
+    CreateFormPrevField,
+    CreateFormChar(char),
+    CreateFormBackspace,
+    CreateFormLeft,
+    CreateFormRight,
+    CreateFormHome,
+    CreateFormEnd,
+    CreateFormDelete,
+    SubmitCreateIssue,
+    IssueCreated(String),
+    IssueCreateFailed(String),
+    CopyBranchName,
+    CheckOutBranch,
+    BranchCheckedOut(String, String, Checkout), // Ticket ID and branch
+    BranchCheckOutFailed(String, String),       // Ticket ID and git's error
+    CopyCommitPrefix,
+    BranchesScanned(Vec<(String, TicketBranch)>), // Ticket ID and a branch naming it
+    OpenComment,
+    CancelComment,
+    CommentChar(char), // Enter sends '\n'
+    CommentBackspace,
+    CommentLeft,
+    CommentRight,
+    CommentHome,
+    CommentEnd,
+    CommentDelete,
+    SubmitComment,
+    CommentAdded(String),
+    CommentFailed(String, String),
+    OpenWorklog,
+    CancelWorklog,
+    WorklogNextField,
+    WorklogPrevField,
+    WorklogChar(char),
+    WorklogBackspace,
+    WorklogLeft,
+    WorklogRight,
+    WorklogHome,
+    WorklogEnd,
+    WorklogDelete,
+    SubmitWorklog,
+    WorklogAdded(String, u64), // Ticket ID and seconds logged
+    WorklogFailed(String, u64, String),
+    ToggleTimer,
+    OpenNotes,
+    CancelNotes,
+    NotesNextField,
+    NotesChar(char),
+    NotesBackspace,
+    NotesLeft,
+    NotesRight,
+    NotesHome,
+    NotesEnd,
+    NotesDelete,
+    SubmitNotes,
+    OpenBoard,
+    CloseBoard,
+    BoardRetrieved(u64, BoardConfiguration), // Board ID and its configuration
+    BoardFailed(u64, String),
+    BoardSelect(BoardStep),
+    MoveCard(BoardStep),               // Only sideways steps change column
+    WatchTickets(Vec<String>, String), // IDs to add and where they came from
+    OpenPeople,                        // Assign the selected ticket, or watch it in Jira
+    OpenAssigneeSearch,
+    AssigneesFound(String, String, Vec<User>), // Ticket ID, the search and who matched
+    AssignTicket(String, AssignTo),
+    TicketAssigned(String, Option<String>), // Ticket ID and the new assignee's name
+    SetWatching(String, bool),
+    WatchingSet(String, bool),
 }
 
 #[derive(Clone, Debug, PartialEq)]
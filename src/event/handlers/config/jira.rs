@@ -62,10 +62,13 @@ pub fn handle_event(app: &mut App, event: JiraConfigEvent) {
                 if form.is_empty() {
                     app.config.jira = None;
                 } else {
+                    // Settings without a form field (e.g. saved queries) carry over.
+                    let existing = app.config.jira.take().unwrap_or_default();
                     app.config.jira = Some(crate::config::model::JiraConfig {
                         url: form.url.value().trim().to_string(),
                         email: form.email.value().trim().to_string(),
                         token: form.token.value().trim().to_string(),
+                        ..existing
                     });
                 }
                 let has_jira_config = app.config.jira.is_some();
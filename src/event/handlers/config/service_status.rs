@@ -91,12 +91,18 @@ pub fn handle_event(app: &mut App, event: ServiceStatusConfigEvent) {
             if let Some(form) = app.state.service_status_config_editor.form.take()
                 && form.is_valid()
             {
+                // The local clone has no form field, so an edit keeps it.
+                let clone = form
+                    .edit_index
+                    .and_then(|idx| app.config.servicestatus.get(idx))
+                    .and_then(|existing| existing.clone.clone());
                 let service = crate::config::model::ServiceStatusConfig {
                     name: form.name.value().trim().to_string(),
                     staging: form.staging.value().trim().to_string(),
                     preproduction: form.preprod.value().trim().to_string(),
                     production: form.prod.value().trim().to_string(),
                     repo: form.repo.value().trim().to_string(),
+                    clone,
                 };
                 if let Some(idx) = form.edit_index {
                     // Edit existing
@@ -106,7 +106,7 @@ pub fn handle_event(app: &mut App, event: TokenGeneratorConfigEvent) {
                         let _ = app.config_loader.write_config(&app.config);
                     }
                     ActiveEdit::Service(p) if p.is_valid() => {
-                        let svc = crate::config::model::ServiceConfig {
+                        let mut svc = crate::config::model::ServiceConfig {
                             name: p.name.value().trim().to_string(),
                             audience: p.audience.value().trim().to_string(),
                             credentials: p.to_credentials(),
@@ -114,6 +114,14 @@ pub fn handle_event(app: &mut App, event: TokenGeneratorConfigEvent) {
                         if let Some(idx) = p.edit_index {
                             if let Some(existing) = app.config.tokengenerator.services.get_mut(idx)
                             {
+                                // Token file paths are only set in config.yaml; keep them on edit.
+                                for cred in &mut svc.credentials {
+                                    cred.token_file = existing
+                                        .credentials
+                                        .iter()
+                                        .find(|c| c.env == cred.env)
+                                        .and_then(|c| c.token_file.clone());
+                                }
                                 *existing = svc;
                             }
                         } else {
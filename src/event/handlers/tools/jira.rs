@@ -1,35 +1,616 @@
 use crate::app::App;
+use crate::client::jira::models::{AssignTo, Transition, User};
+use crate::config::model::JiraConfig;
 use crate::event::events::AppEvent::{ActivityEvent, AppLog};
 use crate::event::events::GenericEvent::OpenInBrowser;
 use crate::event::events::JiraEvent::{
-    AddTicketIdChar, CancelNewTicket, ListMove, NewTicket, RemoveTicket, RemoveTicketIdChar,
-    ScanTickets, SubmitTicketId, TicketIdDelete, TicketIdEnd, TicketIdHome, TicketIdLeft,
-    TicketIdRight, TicketListUpdate, TicketMove, TicketRetrieved,
+    AddTicketIdChar, AssignTicket, AssigneesFound, BoardFailed, BoardRetrieved, BoardSelect,
+    BranchCheckOutFailed, BranchCheckedOut, BranchesScanned, CancelComment, CancelCreateIssue,
+    CancelNewTicket, CancelNotes, CancelWorklog, CheckOutBranch, CloseBoard, CloseDetail,
+    CommentAdded, CommentBackspace, CommentChar, CommentDelete, CommentEnd, CommentFailed,
+    CommentHome, CommentLeft, CommentRight, CopyBranchName, CopyCommitPrefix, CreateFormBackspace,
+    CreateFormChar, CreateFormDelete, CreateFormEnd, CreateFormHome, CreateFormLeft,
+    CreateFormNextField, CreateFormPrevField, CreateFormRight, CycleGroup, CycleSort,
+    CycleTicketSite, ImportFromClipboard, ImportFromFile, IssueCreateFailed, IssueCreated,
+    ListMove, MoveCard, NewTicket, NextTab, NotesBackspace, NotesChar, NotesDelete, NotesEnd,
+    NotesHome, NotesLeft, NotesNextField, NotesRight, OpenAssigneeSearch, OpenBoard, OpenComment,
+    OpenCreateIssue, OpenDetail, OpenFilter, OpenNotes, OpenPeople, OpenTransitions, OpenWorklog,
+    PrevTab, QueryFailed, QueryResults, RemoveTicket, RemoveTicketIdChar, RestoreTicket,
+    ScanTickets, ScrollDetail, SetWatching, SubmitComment, SubmitCreateIssue, SubmitNotes,
+    SubmitTicketId, SubmitWorklog, TicketAssigned, TicketDetailsFailed, TicketDetailsRetrieved,
+    TicketIdDelete, TicketIdEnd, TicketIdHome, TicketIdLeft, TicketIdRight, TicketListUpdate,
+    TicketMove, TicketRetrieved, TicketScanFailed, TicketScanned, TicketTransitioned,
+    TicketsRetrieved, ToggleTimer, TransitionTicket, TransitionsRetrieved, WatchTickets,
+    WatchingSet, WorklogAdded, WorklogBackspace, WorklogChar, WorklogDelete, WorklogEnd,
+    WorklogFailed, WorklogHome, WorklogLeft, WorklogNextField, WorklogPrevField, WorklogRight,
 };
 use crate::event::events::{Direction, GenericEvent, JiraEvent};
+use crate::popup::model::Popup;
 use crate::state::app::AppFocus;
+use crate::state::jira::{ScanSummary, TicketInput, TimerToggle};
+use crate::state::jira_board::{BoardStep, Column};
 use crate::state::log::{LogEntry, LogLevel, log_source};
+use crate::ui::widgets::popup::{Part, Type};
 use crate::utils::browser::open_link_in_browser;
-use crate::utils::update_list_state;
+use crate::utils::git::{self, Checkout};
+use crate::utils::path::expand_tilde;
+use crate::utils::string_copy::{copy_to_clipboard, read_from_clipboard};
+use crate::utils::{duration, ticket_ids, update_list_state};
+use chrono::Local;
+use std::collections::HashSet;
+use std::fs;
+use std::time::Instant;
+use tui_text_field::TextField;
 
 const SERVICE_NAME: &str = log_source::JIRA;
 
 pub fn handle_event(app: &mut App, event: JiraEvent) {
     match event {
-        ListMove(direction) => {
-            let list_len = app.state.jira.tickets.len();
-            update_list_state::update_noneable_list(
-                &mut app.state.jira.list_state,
-                direction,
-                list_len,
+        ListMove(direction) => app.state.jira.move_selection(direction),
+        NextTab => {
+            app.state.jira.close_detail();
+            app.state.jira.next_tab();
+        }
+        PrevTab => {
+            app.state.jira.close_detail();
+            app.state.jira.prev_tab();
+        }
+        OpenDetail => {
+            if app.config.jira.is_some()
+                && let Some(ticket_id) = app.state.jira.open_detail()
+                && let Some(config) = site_config(app, &ticket_id)
+            {
+                app.jira_api
+                    .fetch_details(ticket_id, config, app.event_sender.clone());
+            }
+        }
+        CloseDetail => app.state.jira.close_detail(),
+        OpenBoard => {
+            let Some(config) = tab_site_config(app) else {
+                return;
+            };
+            let Some(board_id) = config.board else {
+                app.event_sender.send_app_event(AppLog(LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    "No board configured for this tab's site — set its board to a board ID",
+                )));
+                return;
+            };
+            app.state.jira.open_board(board_id);
+            app.jira_api
+                .fetch_board(board_id, config, app.event_sender.clone());
+        }
+        CloseBoard => app.state.jira.close_board(),
+        BoardRetrieved(board_id, configuration) => {
+            app.state.jira.set_board(board_id, configuration);
+            if app.state.jira.selected_ticket().is_none() {
+                app.state.jira.board_select(BoardStep::Down);
+            }
+        }
+        BoardFailed(board_id, error) => {
+            app.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("Failed to load board {}", board_id),
+                )
+                .with_detail(error.clone()),
+            ));
+            app.state.jira.set_board_failed(board_id, error);
+        }
+        WatchTickets(ids, source) => import_tickets(app, ids, &source, None),
+        BoardSelect(step) => app.state.jira.board_select(step),
+        MoveCard(step) => {
+            if app.config.jira.is_some()
+                && let Some(ticket_id) = app.state.jira.start_card_move(step)
+                && let Some(config) = site_config(app, &ticket_id)
+            {
+                app.jira_api
+                    .fetch_transitions(ticket_id, config, app.event_sender.clone());
+            }
+        }
+        ScrollDetail(direction, amount) => app.state.jira.scroll_detail(direction, amount),
+        TicketDetailsRetrieved(details) => app.state.jira.set_details(*details),
+        TicketDetailsFailed(ticket_id, error) => {
+            app.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("Failed to load details for {}", ticket_id),
+                )
+                .with_detail(error.clone()),
+            ));
+            app.state.jira.set_details_failed(&ticket_id, error);
+        }
+        OpenCreateIssue => app.state.jira.open_create(),
+        CancelCreateIssue => app.state.jira.close_create(),
+        CreateFormNextField => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field = form.active_field.next();
+            }
+        }
+        CreateFormPrevField => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field = form.active_field.prev();
+            }
+        }
+        CreateFormChar(c) => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().insert(c);
+            }
+        }
+        CreateFormBackspace => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().backspace();
+            }
+        }
+        CreateFormLeft => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().move_left();
+            }
+        }
+        CreateFormRight => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().move_right();
+            }
+        }
+        CreateFormHome => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().home();
+            }
+        }
+        CreateFormEnd => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().end();
+            }
+        }
+        CreateFormDelete => {
+            if let Some(form) = &mut app.state.jira.create {
+                form.active_field_mut().delete_forward();
+            }
+        }
+        SubmitCreateIssue => {
+            let Some(config) = app.state.jira.create.as_ref().and_then(|form| {
+                let jira = app.config.jira.as_ref()?;
+                Some(jira.for_site(jira.route(form.project.value().trim())))
+            }) else {
+                return;
+            };
+            let Some(form) = app.state.jira.create.as_mut().filter(|f| !f.submitting) else {
+                return;
+            };
+            match form.to_new_issue() {
+                Ok(issue) => {
+                    form.submitting = true;
+                    form.error = None;
+                    app.jira_api
+                        .create_issue(issue, config, app.event_sender.clone());
+                }
+                Err(e) => form.error = Some(e),
+            }
+        }
+        IssueCreated(ticket_id) => {
+            app.state.jira.close_create();
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id.clone(), "Created".to_string()));
+            // Fetching it back runs the usual add path, which watches it.
+            if let Some(config) = app.config.jira.as_ref() {
+                let site = config.route(&ticket_id).to_string();
+                app.jira_api.fetch_ticket(
+                    ticket_id,
+                    site.clone(),
+                    config.for_site(&site),
+                    app.event_sender.clone(),
+                );
+            }
+        }
+        IssueCreateFailed(error) => {
+            app.event_sender.send_app_event(AppLog(
+                LogEntry::new(LogLevel::Warning, SERVICE_NAME, "Failed to create ticket")
+                    .with_detail(error.clone()),
+            ));
+            if let Some(form) = &mut app.state.jira.create {
+                form.submitting = false;
+                form.error = Some(error);
+            }
+        }
+        CopyBranchName | CopyCommitPrefix => {
+            let Some(ticket) = app.state.jira.selected_ticket() else {
+                return;
+            };
+            let git_config = app.config.jira.clone().unwrap_or_default().git;
+            let (what, rendered) = match event {
+                CopyBranchName => ("branch name", ticket.branch_name(&git_config)),
+                _ => ("commit prefix", ticket.commit_prefix(&git_config)),
+            };
+            let result = rendered.and_then(|text| copy_to_clipboard(&text).map(|_| text));
+            let entry = match result {
+                Ok(text) => LogEntry::new(
+                    LogLevel::Info,
+                    SERVICE_NAME,
+                    format!("Copied {}: {}", what, text),
+                ),
+                Err(e) => LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("Copy {} failed: {}", what, e),
+                ),
+            };
+            app.event_sender.send_app_event(AppLog(entry));
+        }
+        CheckOutBranch => {
+            let Some(ticket) = app.state.jira.selected_ticket() else {
+                return;
+            };
+            let git_config = app.config.jira.clone().unwrap_or_default().git;
+            let Some(repository) = git_config.repository.as_deref() else {
+                app.event_sender.send_app_event(AppLog(LogEntry::new(
+                    LogLevel::Error,
+                    SERVICE_NAME,
+                    "No repository configured — set jira.git.repository in config.yaml",
+                )));
+                return;
+            };
+            let ticket_id = ticket.id.clone();
+            let branch = match ticket.branch_name(&git_config) {
+                Ok(branch) => branch,
+                Err(e) => {
+                    app.event_sender
+                        .send_jira_event(BranchCheckOutFailed(ticket_id, e));
+                    return;
+                }
+            };
+            let repository = expand_tilde(repository);
+            let sender = app.event_sender.clone();
+            tokio::task::spawn_blocking(move || {
+                sender.send_jira_event(match git::check_out_branch(&repository, &branch) {
+                    Ok(checkout) => BranchCheckedOut(ticket_id, branch, checkout),
+                    Err(e) => BranchCheckOutFailed(ticket_id, e),
+                });
+            });
+        }
+        BranchCheckedOut(ticket_id, branch, checkout) => {
+            let message = match checkout {
+                Checkout::Created => format!("Created branch {}", branch),
+                Checkout::Existing => format!("Checked out branch {}", branch),
+            };
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id, message));
+            scan_branches(app);
+        }
+        BranchCheckOutFailed(ticket_id, e) => app.event_sender.send_app_event(AppLog(
+            LogEntry::new(
+                LogLevel::Error,
+                SERVICE_NAME,
+                format!("Unable to check out a branch for {}", ticket_id),
+            )
+            .with_detail(e),
+        )),
+        OpenComment => app.state.jira.open_comment(),
+        CancelComment => app.state.jira.close_comment(),
+        CommentChar(c) => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.insert(c);
+            }
+        }
+        CommentBackspace => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.backspace();
+            }
+        }
+        CommentLeft => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.move_left();
+            }
+        }
+        CommentRight => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.move_right();
+            }
+        }
+        CommentHome => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.home();
+            }
+        }
+        CommentEnd => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.end();
+            }
+        }
+        CommentDelete => {
+            if let Some(form) = &mut app.state.jira.comment {
+                form.body.delete_forward();
+            }
+        }
+        SubmitComment => {
+            let Some(config) = (app.state.jira.comment.as_ref())
+                .and_then(|form| site_config(app, &form.ticket_id))
+            else {
+                return;
+            };
+            let Some(form) = app.state.jira.comment.as_mut().filter(|f| !f.submitting) else {
+                return;
+            };
+            match form.to_text() {
+                Ok(text) => {
+                    form.submitting = true;
+                    form.error = None;
+                    app.jira_api.add_comment(
+                        form.ticket_id.clone(),
+                        text,
+                        config,
+                        app.event_sender.clone(),
+                    );
+                }
+                Err(e) => form.error = Some(e),
+            }
+        }
+        CommentAdded(ticket_id) => {
+            app.state.jira.close_comment();
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id, "Commented".to_string()));
+        }
+        CommentFailed(ticket_id, error) => {
+            app.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("Failed to comment on {}", ticket_id),
+                )
+                .with_detail(error.clone()),
+            ));
+            if let Some(form) = &mut app.state.jira.comment {
+                form.submitting = false;
+                form.error = Some(error);
+            }
+        }
+        OpenWorklog => app.state.jira.open_worklog(Local::now().date_naive()),
+        CancelWorklog => app.state.jira.close_worklog(),
+        WorklogNextField => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field = form.active_field.next();
+            }
+        }
+        WorklogPrevField => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field = form.active_field.prev();
+            }
+        }
+        WorklogChar(c) => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().insert(c);
+            }
+        }
+        WorklogBackspace => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().backspace();
+            }
+        }
+        WorklogLeft => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().move_left();
+            }
+        }
+        WorklogRight => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().move_right();
+            }
+        }
+        WorklogHome => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().home();
+            }
+        }
+        WorklogEnd => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().end();
+            }
+        }
+        WorklogDelete => {
+            if let Some(form) = &mut app.state.jira.worklog {
+                form.active_field_mut().delete_forward();
+            }
+        }
+        SubmitWorklog => {
+            let Some(config) = (app.state.jira.worklog.as_ref())
+                .and_then(|form| site_config(app, &form.ticket_id))
+            else {
+                return;
+            };
+            let Some(form) = app.state.jira.worklog.as_mut().filter(|f| !f.submitting) else {
+                return;
+            };
+            match form.to_worklog(Local::now()) {
+                Ok(worklog) => {
+                    form.submitting = true;
+                    form.error = None;
+                    app.jira_api.add_worklog(
+                        form.ticket_id.clone(),
+                        worklog,
+                        config,
+                        app.event_sender.clone(),
+                    );
+                }
+                Err(e) => form.error = Some(e),
+            }
+        }
+        WorklogAdded(ticket_id, seconds) => {
+            if app
+                .state
+                .jira
+                .worklog
+                .as_ref()
+                .is_some_and(|f| f.ticket_id == ticket_id)
+            {
+                app.state.jira.close_worklog();
+            } else {
+                app.state.jira.finish_timer(&ticket_id);
+            }
+            app.event_sender.send_app_event(ActivityEvent(
+                ticket_id,
+                format!("Logged {}", duration::format(seconds)),
+            ));
+        }
+        WorklogFailed(ticket_id, seconds, error) => {
+            let message = format!(
+                "Failed to log {} on {}",
+                duration::format(seconds),
+                ticket_id
             );
+            match app
+                .state
+                .jira
+                .worklog
+                .as_mut()
+                .filter(|f| f.submitting && f.ticket_id == ticket_id)
+            {
+                Some(form) => {
+                    form.submitting = false;
+                    form.error = Some(error.clone());
+                    app.event_sender.send_app_event(AppLog(
+                        LogEntry::new(LogLevel::Warning, SERVICE_NAME, message).with_detail(error),
+                    ));
+                }
+                // A stopped timer has nowhere to show the error, so this one pops
+                // up, and the timer runs again so its time can still be logged.
+                None => {
+                    let message = if app.state.jira.restore_timer(&ticket_id) {
+                        app.event_sender.send_jira_event(TicketListUpdate);
+                        format!("{} — its timer is running again", message)
+                    } else {
+                        message
+                    };
+                    app.event_sender.send_app_event(AppLog(
+                        LogEntry::new(LogLevel::Error, SERVICE_NAME, message).with_detail(error),
+                    ));
+                }
+            }
         }
-        NewTicket => {
-            app.state.jira.new_ticket_id.clear();
-            app.state.jira.adding_ticket = true;
-            app.state.focus = AppFocus::JiraInput
+        ToggleTimer => {
+            if app.config.jira.is_none() {
+                return;
+            }
+            match app.state.jira.toggle_timer(Local::now()) {
+                Some(TimerToggle::Started(ticket_id)) => {
+                    app.event_sender
+                        .send_app_event(ActivityEvent(ticket_id, "Timer started".to_string()));
+                }
+                Some(TimerToggle::Stopped(ticket_id, worklog)) => {
+                    match site_config(app, &ticket_id) {
+                        Some(config) => app.jira_api.add_worklog(
+                            ticket_id,
+                            worklog,
+                            config,
+                            app.event_sender.clone(),
+                        ),
+                        None => {
+                            app.state.jira.restore_timer(&ticket_id);
+                            app.event_sender.send_app_event(AppLog(LogEntry::new(
+                                LogLevel::Error,
+                                SERVICE_NAME,
+                                format!(
+                                    "No Jira site to log {} on {} — its timer is running again",
+                                    duration::format(worklog.time_spent_seconds),
+                                    ticket_id
+                                ),
+                            )));
+                        }
+                    }
+                }
+                None => return,
+            }
+            app.event_sender.send_jira_event(TicketListUpdate);
+        }
+        OpenNotes => app.state.jira.open_notes(),
+        CancelNotes => app.state.jira.close_notes(),
+        NotesNextField => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field = form.active_field.next();
+            }
+        }
+        NotesChar(c) => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().insert(c);
+            }
+        }
+        NotesBackspace => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().backspace();
+            }
         }
+        NotesLeft => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().move_left();
+            }
+        }
+        NotesRight => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().move_right();
+            }
+        }
+        NotesHome => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().home();
+            }
+        }
+        NotesEnd => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().end();
+            }
+        }
+        NotesDelete => {
+            if let Some(form) = &mut app.state.jira.notes {
+                form.active_field_mut().delete_forward();
+            }
+        }
+        SubmitNotes => {
+            let Some(form) = app.state.jira.notes.as_mut() else {
+                return;
+            };
+            match form.to_notes(Local::now()) {
+                Ok((notes, remind_at)) => {
+                    let ticket_id = form.ticket_id.clone();
+                    app.state.jira.set_notes(&ticket_id, notes, remind_at);
+                    app.state.jira.close_notes();
+                    app.event_sender.send_jira_event(TicketListUpdate);
+                }
+                Err(e) => form.error = Some(e),
+            }
+        }
+        NewTicket => open_ticket_input(app, TicketInput::Ids),
+        ImportFromFile => open_ticket_input(app, TicketInput::File),
+        OpenFilter if !app.state.jira.is_watchlist_tab() && !app.state.jira.is_archive_tab() => {}
+        OpenFilter => {
+            let filter = app.state.jira.saved_filter().to_string();
+            open_ticket_input(app, TicketInput::Filter);
+            app.state.jira.new_ticket_id = TextField::new(filter);
+        }
+        CycleSort | CycleGroup if !app.state.jira.is_watchlist_tab() => {}
+        CycleSort => {
+            app.state.jira.cycle_sort();
+            save_layout(app);
+        }
+        CycleGroup => {
+            app.state.jira.cycle_group();
+            save_layout(app);
+        }
+        ImportFromClipboard => match read_from_clipboard() {
+            Ok(text) => import_tickets(app, ticket_ids::extract(&text), "clipboard", None),
+            Err(e) => app.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Error,
+                    SERVICE_NAME,
+                    "Unable to read the clipboard",
+                )
+                .with_detail(e),
+            )),
+        },
         CancelNewTicket => {
+            if app.state.jira.ticket_input == TicketInput::Filter {
+                app.state.jira.clear_filter();
+            }
             app.state.jira.new_ticket_id.clear();
             app.state.jira.adding_ticket = false;
             app.state.focus = AppFocus::Tool;
@@ -43,42 +624,134 @@ pub fn handle_event(app: &mut App, event: JiraEvent) {
         TicketIdHome => app.state.jira.new_ticket_id.home(),
         TicketIdEnd => app.state.jira.new_ticket_id.end(),
         TicketIdDelete => app.state.jira.new_ticket_id.delete_forward(),
+        SubmitTicketId if app.state.jira.ticket_input == TicketInput::Filter => {
+            app.state.jira.apply_filter();
+            app.state.focus = AppFocus::Tool;
+        }
+        SubmitTicketId if app.state.jira.ticket_input == TicketInput::Assignee => {
+            let query = app.state.jira.new_ticket_id.value().trim().to_string();
+            let Some(ticket_id) = app.state.jira.selected_ticket().map(|t| t.id.clone()) else {
+                return;
+            };
+            let Some(config) = site_config(app, &ticket_id).filter(|_| !query.is_empty()) else {
+                return;
+            };
+            app.state.jira.adding_ticket = false;
+            app.state.jira.new_ticket_id.clear();
+            app.state.focus = AppFocus::Tool;
+            app.jira_api
+                .search_assignees(ticket_id, query, config, app.event_sender.clone());
+        }
         SubmitTicketId => {
-            let ticket_id = app.state.jira.new_ticket_id.value().to_string();
-            if let Some(config) = app.config.jira.clone()
-                && !ticket_id.is_empty()
-            {
-                app.state.jira.adding_ticket = false;
-                app.state.jira.new_ticket_id.clear();
-                app.state.focus = AppFocus::Tool;
+            let input = app.state.jira.new_ticket_id.value().trim().to_string();
+            if app.config.jira.is_none() || input.is_empty() {
+                return;
+            }
 
-                let sender = app.event_sender.clone();
+            // A bad entry keeps the input open so it can be corrected.
+            let (parsed, source) = match app.state.jira.ticket_input {
+                TicketInput::Ids => (ticket_ids::parse(&input), "input"),
+                TicketInput::File => (
+                    fs::read_to_string(expand_tilde(&input))
+                        .map(|text| ticket_ids::extract(&text))
+                        .map_err(|e| format!("{}: {}", input, e)),
+                    "file",
+                ),
+                // Handled by the arms above.
+                TicketInput::Filter | TicketInput::Assignee => return,
+            };
+            let ids = match parsed {
+                Ok(ids) => ids,
+                Err(e) => {
+                    app.event_sender.send_app_event(AppLog(
+                        LogEntry::new(LogLevel::Error, SERVICE_NAME, "Unable to add tickets")
+                            .with_detail(e),
+                    ));
+                    return;
+                }
+            };
+
+            app.state.jira.adding_ticket = false;
+            app.state.jira.new_ticket_id.clear();
+            app.state.focus = AppFocus::Tool;
 
-                app.jira_api.fetch_ticket(ticket_id, config, sender);
+            let site = app.state.jira.new_ticket_site.take();
+            import_tickets(app, ids, source, site);
+        }
+        CycleTicketSite => {
+            if let Some(config) = app.config.jira.as_ref()
+                && !config.sites.is_empty()
+            {
+                app.state.jira.cycle_new_ticket_site(&config.site_names());
             }
         }
-        TicketRetrieved(ticket_response) => {
-            if app.state.jira.tickets_pending_scan > 0 {
-                let changes = app.state.jira.update_ticket_with_changes(ticket_response);
-                app.state.jira.tickets_pending_scan =
-                    app.state.jira.tickets_pending_scan.saturating_sub(1);
-                if let Some((id, change_msg)) = changes {
+        TicketRetrieved(site, ticket_response) => {
+            let ticket_id = ticket_response.key.clone();
+            app.state.jira.add_ticket(&site, ticket_response);
+            app.state.jira.new_ticket_id.clear();
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id, "Added to watchlist".to_string()));
+            app.event_sender.send_jira_event(TicketListUpdate);
+            scan_branches(app);
+        }
+        TicketsRetrieved(site, requested, ticket_responses) => {
+            let summary = app
+                .state
+                .jira
+                .import_tickets(&site, &requested, ticket_responses);
+            for ticket_id in &summary.added {
+                app.event_sender.send_app_event(ActivityEvent(
+                    ticket_id.clone(),
+                    "Added to watchlist".to_string(),
+                ));
+            }
+
+            let message = format!("Imported {} tickets", summary.added.len());
+            let entry = if summary.missing.is_empty() {
+                LogEntry::new(LogLevel::Info, SERVICE_NAME, message)
+            } else {
+                LogEntry::new(LogLevel::Warning, SERVICE_NAME, message)
+                    .with_detail(format!("Not found: {}", summary.missing.join(", ")))
+            };
+            app.event_sender.send_app_event(AppLog(entry));
+            app.event_sender.send_jira_event(TicketListUpdate);
+            scan_branches(app);
+        }
+        BranchesScanned(found) => app.state.jira.set_branches(found),
+        TicketScanned(ticket_response) => {
+            let ticket_id = ticket_response.key.clone();
+            if let Some((id, changes)) = app.state.jira.update_ticket_with_changes(ticket_response)
+            {
+                for change in changes {
                     app.event_sender
-                        .send_app_event(ActivityEvent(id, change_msg));
-                }
-                if app.state.jira.tickets_pending_scan == 0 {
-                    app.event_sender.send_jira_event(TicketListUpdate)
+                        .send_app_event(ActivityEvent(id.clone(), change));
                 }
-            } else {
-                let ticket_id = ticket_response.key.clone();
-                app.state.jira.add_ticket(ticket_response);
-                app.state.jira.new_ticket_id.clear();
+            }
+            let summary = app.state.jira.finish_scan_ticket(&ticket_id, Ok(()));
+            finish_scan(app, summary);
+        }
+        TicketScanFailed(ticket_id, error) => {
+            let summary = app.state.jira.finish_scan_ticket(&ticket_id, Err(error));
+            finish_scan(app, summary);
+        }
+        RemoveTicket if app.state.jira.is_archive_tab() => {
+            if let Some(id) = app.state.jira.remove_archived() {
                 app.event_sender
-                    .send_app_event(ActivityEvent(ticket_id, "Added to watchlist".to_string()));
+                    .send_app_event(ActivityEvent(id, "Removed from archive".to_string()));
                 app.event_sender.send_jira_event(TicketListUpdate);
             }
         }
+        RemoveTicket if !app.state.jira.is_watchlist_tab() => {}
+        TicketMove(_) if !app.state.jira.is_watchlist_tab() => {}
+        TicketMove(_) if !app.state.jira.is_manual_order() => {
+            app.event_sender.send_app_event(AppLog(LogEntry::new(
+                LogLevel::Info,
+                SERVICE_NAME,
+                "Tickets can only be moved in manual order, unfiltered — press [z] to change the sort",
+            )));
+        }
         RemoveTicket => {
+            app.state.jira.close_detail();
             if let Some(idx) = app.state.jira.list_state.selected()
                 && let Some(ticket) = app.state.jira.tickets.get(idx)
             {
@@ -101,12 +774,23 @@ pub fn handle_event(app: &mut App, event: JiraEvent) {
             }
             app.event_sender.send_jira_event(TicketListUpdate);
         }
+        RestoreTicket => {
+            if let Some(id) = app.state.jira.restore_selected() {
+                app.event_sender
+                    .send_app_event(ActivityEvent(id, "Restored from archive".to_string()));
+                app.event_sender.send_jira_event(TicketListUpdate);
+            }
+        }
         TicketMove(direction) => {
             app.state.jira.swap_tickets(direction);
             app.event_sender.send_jira_event(TicketListUpdate);
         }
         TicketListUpdate => {
-            if let Err(e) = app.state.jira.jira_file.write_jira(&app.state.jira.tickets) {
+            let jira = &app.state.jira;
+            if let Err(e) = jira
+                .jira_file
+                .write_tickets(&jira.tickets, &jira.archive.tickets)
+            {
                 let sender = app.event_sender.clone();
                 sender.send_app_event(AppLog(
                     LogEntry::new(
@@ -118,47 +802,515 @@ pub fn handle_event(app: &mut App, event: JiraEvent) {
                 ));
             }
         }
-        ScanTickets => {
-            // If there are no tickets or a previous scan is still running
-            if app.state.jira.tickets.is_empty() || app.state.jira.tickets_pending_scan > 0 {
-                if app.state.jira.tickets_pending_scan > 0 {
-                    app.event_sender.send_app_event(AppLog(LogEntry::new(
-                        LogLevel::Warning,
-                        SERVICE_NAME,
-                        "Ticket scan skipped — previous scan still running",
-                    )));
-                }
+        QueryResults(query_idx, tickets) => {
+            let site = app
+                .config
+                .jira
+                .as_ref()
+                .and_then(|config| config.queries.get(query_idx))
+                .map(|query| query.site.clone())
+                .unwrap_or_default();
+            app.state.jira.set_query_results(query_idx, &site, tickets);
+        }
+        QueryFailed(query_idx, error) => {
+            let name = app
+                .state
+                .jira
+                .queries
+                .get(query_idx)
+                .map(|q| q.name.clone())
+                .unwrap_or_default();
+            app.state.jira.set_query_failed(query_idx, error.clone());
+            app.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("Saved query failed: {}", name),
+                )
+                .with_detail(error),
+            ));
+        }
+        OpenTransitions => {
+            if let Some(ticket) = app.state.jira.selected_ticket()
+                && let Some(config) = site_config(app, &ticket.id)
+            {
+                app.jira_api
+                    .fetch_transitions(ticket.id.clone(), config, app.event_sender.clone());
+            }
+        }
+        TransitionsRetrieved(ticket_id, transitions) => {
+            if let Some(column) = app.state.jira.take_card_move(&ticket_id) {
+                move_card(app, ticket_id, column, transitions);
                 return;
             }
-            if let Some(config) = app.config.jira.clone() {
-                let count = app.state.jira.tickets.len();
+            if transitions.is_empty() {
+                app.event_sender.send_app_event(AppLog(LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("No transitions available for {}", ticket_id),
+                )));
+                return;
+            }
+
+            app.state.popup = Some(transition_popup(ticket_id, transitions));
+        }
+        TransitionTicket(ticket_id, transition) => {
+            if let Some(config) = site_config(app, &ticket_id) {
+                app.jira_api.transition_ticket(
+                    ticket_id,
+                    transition,
+                    config,
+                    app.event_sender.clone(),
+                );
+            }
+        }
+        TicketTransitioned(ticket_id, transition) => {
+            let new_status = &transition.to.name;
+            let message = match app.state.jira.set_ticket_status(&ticket_id, &transition.to) {
+                Some(old_status) => format!("Transitioned: {} → {}", old_status, new_status),
+                None => format!("Transitioned to {}", new_status),
+            };
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id, message));
+            app.event_sender.send_jira_event(TicketListUpdate);
+        }
+        OpenPeople if app.state.jira.is_archive_tab() || app.config.jira.is_none() => {}
+        OpenPeople => {
+            if let Some(ticket) = app.state.jira.selected_ticket() {
+                app.state.popup = Some(people_popup(ticket.id.clone(), ticket.watching));
+            }
+        }
+        OpenAssigneeSearch => {
+            if app.state.jira.selected_ticket().is_some() {
+                open_ticket_input(app, TicketInput::Assignee);
+            }
+        }
+        AssigneesFound(ticket_id, query, users) => {
+            if users.is_empty() {
                 app.event_sender.send_app_event(AppLog(LogEntry::new(
                     LogLevel::Info,
                     SERVICE_NAME,
-                    format!("Ticket scan started — {} tickets", count),
+                    format!(
+                        "No one matching \"{}\" can be assigned {}",
+                        query, ticket_id
+                    ),
                 )));
-                app.state.jira.tickets_pending_scan = app.state.jira.tickets.len();
-                for t in app.state.jira.tickets.iter() {
-                    app.jira_api.fetch_ticket(
-                        t.id.clone(),
-                        config.clone(),
+                return;
+            }
+            app.state.popup = Some(assignee_popup(ticket_id, users));
+        }
+        AssignTicket(ticket_id, to) => {
+            if let Some(config) = site_config(app, &ticket_id) {
+                app.jira_api
+                    .assign_ticket(ticket_id, to, config, app.event_sender.clone());
+            }
+        }
+        TicketAssigned(ticket_id, assignee) => {
+            app.state
+                .jira
+                .set_ticket_assignee(&ticket_id, assignee.as_deref());
+            let message = match assignee {
+                Some(name) => format!("Assigned to {}", name),
+                None => "Unassigned".to_string(),
+            };
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id, message));
+            app.event_sender.send_jira_event(TicketListUpdate);
+        }
+        SetWatching(ticket_id, watch) => {
+            if let Some(config) = site_config(app, &ticket_id) {
+                app.jira_api
+                    .set_watching(ticket_id, watch, config, app.event_sender.clone());
+            }
+        }
+        WatchingSet(ticket_id, watching) => {
+            app.state.jira.set_watching(&ticket_id, watching);
+            let message = if watching {
+                "Watching in Jira"
+            } else {
+                "Stopped watching in Jira"
+            };
+            app.event_sender
+                .send_app_event(ActivityEvent(ticket_id, message.to_string()));
+            app.event_sender.send_jira_event(TicketListUpdate);
+        }
+        ScanTickets => {
+            // Saved queries refresh on every scan, independent of the watchlist
+            if let Some(config) = app.config.jira.clone() {
+                for (idx, query) in config.queries.iter().enumerate() {
+                    app.jira_api.search(
+                        idx,
+                        query.jql.clone(),
+                        config.for_site(&query.site),
                         app.event_sender.clone(),
                     );
                 }
             }
+
+            scan_branches(app);
+
+            if app.config.jira.is_none() {
+                return;
+            }
+            if app.state.jira.is_scanning() {
+                app.event_sender.send_app_event(AppLog(LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    "Ticket scan skipped — previous scan still running",
+                )));
+                return;
+            }
+            let Some(ticket_ids) = app.state.jira.start_scan(Instant::now()) else {
+                return;
+            };
+            app.event_sender.send_app_event(AppLog(LogEntry::new(
+                LogLevel::Info,
+                SERVICE_NAME,
+                format!("Ticket scan started — {} tickets", ticket_ids.len()),
+            )));
+            for ticket_id in ticket_ids {
+                match site_config(app, &ticket_id) {
+                    Some(config) => {
+                        app.jira_api
+                            .scan_ticket(ticket_id, config, app.event_sender.clone())
+                    }
+                    None => {
+                        // Fail it now rather than leave it pending until the timeout.
+                        let project = ticket_id.split_once('-').map_or(&*ticket_id, |(p, _)| p);
+                        let error = format!("No site configured for {}", project);
+                        let summary = app.state.jira.finish_scan_ticket(&ticket_id, Err(error));
+                        finish_scan(app, summary);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A popup to pick one of `transitions` for the ticket.
+fn transition_popup(ticket_id: String, transitions: Vec<Transition>) -> Popup {
+    transitions.into_iter().enumerate().fold(
+        Popup::new(
+            Type::Select,
+            format!("Transition {}", ticket_id),
+            vec![Part::Text("any other key to cancel")],
+        ),
+        |popup, (idx, transition)| match Popup::select_key(idx) {
+            Some(key) => {
+                let label = if transition.name == transition.to.name {
+                    transition.name.clone()
+                } else {
+                    format!("{} → {}", transition.name, transition.to.name)
+                };
+                popup.with_action(
+                    key,
+                    &label,
+                    TransitionTicket(ticket_id.clone(), transition).into(),
+                )
+            }
+            None => popup,
+        },
+    )
+}
+
+/// A popup to change who the ticket is assigned to, or whether it is watched in Jira.
+fn people_popup(ticket_id: String, watching: bool) -> Popup {
+    let (watch_label, watch) = if watching {
+        ("Stop watching", false)
+    } else {
+        ("Watch", true)
+    };
+    Popup::new(
+        Type::Select,
+        format!("People on {}", ticket_id),
+        vec![Part::Text("any other key to cancel")],
+    )
+    .with_action(
+        '1',
+        "Assign to me",
+        AssignTicket(ticket_id.clone(), AssignTo::Me).into(),
+    )
+    .with_action('2', "Assign to someone…", OpenAssigneeSearch.into())
+    .with_action(
+        '3',
+        "Unassign",
+        AssignTicket(ticket_id.clone(), AssignTo::Nobody).into(),
+    )
+    .with_action('4', watch_label, SetWatching(ticket_id, watch).into())
+}
+
+/// A popup to pick who to assign the ticket to from an assignee search.
+fn assignee_popup(ticket_id: String, users: Vec<User>) -> Popup {
+    users.into_iter().enumerate().fold(
+        Popup::new(
+            Type::Select,
+            format!("Assign {}", ticket_id),
+            vec![Part::Text("any other key to cancel")],
+        ),
+        |popup, (idx, user)| match Popup::select_key(idx) {
+            Some(key) => {
+                let label = user.display_name.clone();
+                popup.with_action(
+                    key,
+                    &label,
+                    AssignTicket(ticket_id.clone(), AssignTo::User(user)).into(),
+                )
+            }
+            None => popup,
+        },
+    )
+}
+
+/// Finishes a card move with the transition into one of the column's statuses,
+/// asking which when the workflow offers more than one.
+fn move_card(app: &mut App, ticket_id: String, column: Column, transitions: Vec<Transition>) {
+    let mut transitions = transitions
+        .into_iter()
+        .filter(|t| column.status_ids.contains(&t.to.id))
+        .collect::<Vec<_>>();
+    match transitions.len() {
+        0 => app.event_sender.send_app_event(AppLog(LogEntry::new(
+            LogLevel::Warning,
+            SERVICE_NAME,
+            format!("No transition moves {} to {}", ticket_id, column.name),
+        ))),
+        1 => app
+            .event_sender
+            .send_jira_event(TransitionTicket(ticket_id, transitions.remove(0))),
+        _ => app.state.popup = Some(transition_popup(ticket_id, transitions)),
+    }
+}
+
+fn save_layout(app: &App) {
+    if let Err(e) = app.state.jira.jira_file.write_layout(app.state.jira.layout) {
+        app.event_sender.send_app_event(AppLog(
+            LogEntry::new(
+                LogLevel::Error,
+                SERVICE_NAME,
+                "Unable to save the watchlist layout",
+            )
+            .with_detail(e.to_string()),
+        ));
+    }
+}
+
+fn open_ticket_input(app: &mut App, input: TicketInput) {
+    app.state.jira.new_ticket_id.clear();
+    app.state.jira.new_ticket_site = None;
+    app.state.jira.ticket_input = input;
+    app.state.jira.adding_ticket = true;
+    app.state.focus = AppFocus::JiraInput
+}
+
+/// Fetches the IDs not already on the watchlist from `site`, or each from the site
+/// its project routes to: per site, a lone ticket on its own, so a typo gets Jira's
+/// error, anything more as one batched search.
+fn import_tickets(app: &mut App, ids: Vec<String>, source: &str, site: Option<String>) {
+    let Some(config) = app.config.jira.clone() else {
+        return;
+    };
+    if ids.is_empty() {
+        app.event_sender.send_app_event(AppLog(LogEntry::new(
+            LogLevel::Warning,
+            SERVICE_NAME,
+            format!("No ticket IDs found in {}", source),
+        )));
+        return;
+    }
+
+    let ids = app.state.jira.unwatched(ids);
+    if ids.is_empty() {
+        app.event_sender.send_app_event(AppLog(LogEntry::new(
+            LogLevel::Info,
+            SERVICE_NAME,
+            format!("Nothing to add from {} — already on the watchlist", source),
+        )));
+        return;
+    }
+
+    let mut by_site: Vec<(String, Vec<String>)> = Vec::new();
+    for id in ids {
+        let site = site
+            .clone()
+            .unwrap_or_else(|| config.route(&id).to_string());
+        match by_site.iter_mut().find(|(s, _)| *s == site) {
+            Some((_, site_ids)) => site_ids.push(id),
+            None => by_site.push((site, vec![id])),
+        }
+    }
+    for (site, mut site_ids) in by_site {
+        let sender = app.event_sender.clone();
+        let site_config = config.for_site(&site);
+        match site_ids.len() {
+            1 => app
+                .jira_api
+                .fetch_ticket(site_ids.remove(0), site, site_config, sender),
+            _ => app
+                .jira_api
+                .fetch_tickets(site_ids, site, site_config, sender),
+        }
+    }
+}
+
+/// The config for the site ticket `id` is on: the one it is listed with, or the one
+/// its project routes to.
+fn site_config(app: &App, id: &str) -> Option<JiraConfig> {
+    let config = app.config.jira.as_ref()?;
+    let site = app
+        .state
+        .jira
+        .site_of(id)
+        .unwrap_or_else(|| config.route(id));
+    Some(config.for_site(site))
+}
+
+/// The config for the site the current tab shows: a saved query's own site, or
+/// else the highlighted ticket's, falling back to the main site.
+fn tab_site_config(app: &App) -> Option<JiraConfig> {
+    let config = app.config.jira.as_ref()?;
+    if app.state.jira.current_query().is_some()
+        && let Some(query) = config.queries.get(app.state.jira.tab - 1)
+    {
+        return Some(config.for_site(&query.site));
+    }
+    match app.state.jira.selected_ticket() {
+        Some(ticket) => site_config(app, &ticket.id),
+        None => Some(config.clone()),
+    }
+}
+
+/// Looks through the configured clones for branches naming a watched ticket. Git
+/// runs on a blocking thread; the results replace the previous scan's.
+fn scan_branches(app: &App) {
+    let Some(config) = app.config.jira.as_ref() else {
+        return;
+    };
+    let repositories = config
+        .git
+        .scan_repositories()
+        .into_iter()
+        .map(expand_tilde)
+        .collect::<Vec<_>>();
+    if repositories.is_empty() {
+        return;
+    }
+    let watched = app
+        .state
+        .jira
+        .tickets
+        .iter()
+        .map(|t| t.id.clone())
+        .collect::<HashSet<_>>();
+    let sender = app.event_sender.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut found = Vec::new();
+        for repository in repositories {
+            match git::ticket_branches(&repository, &watched) {
+                Ok(branches) => found.extend(branches),
+                Err(e) => sender.send_app_event(AppLog(
+                    LogEntry::new(
+                        LogLevel::Warning,
+                        SERVICE_NAME,
+                        format!("Branch scan failed for {}", repository.display()),
+                    )
+                    .with_detail(e),
+                )),
+            }
         }
+        sender.send_jira_event(BranchesScanned(found));
+    });
+}
+
+/// Fails any tickets still outstanding once a scan has run past its timeout, and
+/// sets off any reminders that have come due.
+pub fn handle_tick(app: &mut App) {
+    let summary = app.state.jira.expire_scan(Instant::now());
+    finish_scan(app, summary);
+    remind(app);
+}
+
+/// Records each due reminder in Activity and pops up the latest one. While another
+/// popup is open, reminders stay due, so the user's choice or input in it isn't
+/// thrown away; they go off on the first tick after it closes.
+fn remind(app: &mut App) {
+    if app.state.has_popup() {
+        return;
+    }
+    let due = app.state.jira.take_due_reminders(Local::now());
+    let title = match due.as_slice() {
+        [] => return,
+        [(ticket_id, notes)] => format!("Reminder — {}: {}", ticket_id, notes),
+        _ => format!("{} reminders due — see Activity", due.len()),
+    };
+    for (ticket_id, notes) in due {
+        app.event_sender
+            .send_app_event(ActivityEvent(ticket_id, format!("Reminder: {}", notes)));
+    }
+    app.state.popup = Some(Popup::new(
+        Type::Reminder,
+        title,
+        vec![Part::Key("d"), Part::Text(" dismiss  ")],
+    ));
+    app.event_sender.send_jira_event(TicketListUpdate);
+}
+
+/// Logs the outcome of a completed scan and persists the refreshed watchlist.
+fn finish_scan(app: &mut App, summary: Option<ScanSummary>) {
+    let Some(summary) = summary else {
+        return;
+    };
+
+    let entry = if summary.failed.is_empty() {
+        LogEntry::new(
+            LogLevel::Info,
+            SERVICE_NAME,
+            format!("Ticket scan finished — {} tickets refreshed", summary.total),
+        )
+    } else {
+        let failures = summary
+            .failed
+            .iter()
+            .map(|(id, error)| format!("{}: {}", id, error))
+            .collect::<Vec<_>>()
+            .join("; ");
+        LogEntry::new(
+            LogLevel::Warning,
+            SERVICE_NAME,
+            format!(
+                "Ticket scan finished — {} of {} tickets failed",
+                summary.failed.len(),
+                summary.total
+            ),
+        )
+        .with_detail(failures)
+    };
+    app.event_sender.send_app_event(AppLog(entry));
+    archive_finished(app);
+    app.event_sender.send_jira_event(TicketListUpdate);
+}
+
+/// Moves the tickets the archive settings say are finished off the watchlist,
+/// recording each in Activity.
+fn archive_finished(app: &mut App) {
+    let Some(config) = app.config.jira.as_ref() else {
+        return;
+    };
+    let archived = app
+        .state
+        .jira
+        .archive_finished(&config.archive, Local::now());
+    for (ticket_id, reason) in archived {
+        app.event_sender
+            .send_app_event(ActivityEvent(ticket_id, format!("Archived — {}", reason)));
     }
 }
 
 pub fn handle_generic_event(app: &mut App, event: GenericEvent) {
     if event == OpenInBrowser
-        && let Some(jira_ticket_idx) = app.state.jira.list_state.selected()
-        && let Some(config) = app.config.jira.clone()
+        && let Some(ticket) = app.state.jira.selected_ticket()
+        && let Some(config) = site_config(app, &ticket.id)
     {
-        let link = format!(
-            "{}/browse/{}",
-            config.url, app.state.jira.tickets[jira_ticket_idx].id
-        );
+        let link = format!("{}/browse/{}", config.url, ticket.id);
         if let Err(e) = open_link_in_browser(link.as_str()) {
             app.event_sender.send_app_event(AppLog(LogEntry::new(
                 LogLevel::Warning,
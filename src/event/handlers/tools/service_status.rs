@@ -2,12 +2,15 @@ use crate::app::App;
 use crate::environment::Environment::{Preproduction, Production, Staging};
 use crate::event::events::AppEvent::{ActivityEvent, AppLog};
 use crate::event::events::ServiceStatusEvent::{
-    GetCommitRefErrored, GetCommitRefOk, ListMove, Scan, ScanServiceEnv,
+    GetCommitRefErrored, GetCommitRefOk, ListMove, PendingTicketsErrored, PendingTicketsOk, Scan,
+    ScanServiceEnv, WatchPendingTickets,
 };
-use crate::event::events::{Direction, GenericEvent, ServiceStatusEvent};
+use crate::event::events::{Direction, GenericEvent, JiraEvent, ServiceStatusEvent};
 use crate::state::log::{LogEntry, LogLevel, log_source};
 use crate::state::service_status::CommitRefStatus;
 use crate::utils::browser::open_link_in_browser;
+use crate::utils::git;
+use crate::utils::path::expand_tilde;
 use crate::utils::string_copy::copy_to_clipboard;
 
 const SERVICE_NAME: &str = log_source::SERVICE_STATUS;
@@ -66,12 +69,25 @@ pub fn handle_event(app: &mut App, event: ServiceStatusEvent) {
                 .set_commit_fetching(service_idx, &env);
             let sender = app.event_sender.clone();
             let config = app.config.servicestatus.clone();
+            let generation = app.state.service_status.generation;
 
             app.healthcheck_api
-                .get_commit_ref(service_idx, env, config, sender);
+                .get_commit_ref(service_idx, generation, env, config, sender);
         }
-        GetCommitRefOk(commit, service_idx, env) => {
-            let old_status = app.state.service_status.services[service_idx].commit_ref_status();
+        GetCommitRefOk(commit, service_idx, env, generation) => {
+            // A config reload can move or drop the service while its scan is in flight.
+            if generation != app.state.service_status.generation {
+                return;
+            }
+            let Some(old_status) = app
+                .state
+                .service_status
+                .services
+                .get(service_idx)
+                .map(|service| service.commit_ref_status())
+            else {
+                return;
+            };
             app.state
                 .service_status
                 .set_commit_ok(service_idx, &env, commit);
@@ -85,8 +101,12 @@ pub fn handle_event(app: &mut App, event: ServiceStatusEvent) {
                 app.event_sender
                     .send_app_event(ActivityEvent(svc_cfg.name.clone(), msg));
             }
+            look_up_pending_tickets(app, service_idx);
         }
-        GetCommitRefErrored(error, service_idx, env) => {
+        GetCommitRefErrored(error, service_idx, env, generation) => {
+            if generation != app.state.service_status.generation {
+                return;
+            }
             app.state
                 .service_status
                 .set_commit_error(service_idx, &env, error.clone());
@@ -100,6 +120,48 @@ pub fn handle_event(app: &mut App, event: ServiceStatusEvent) {
                 )));
             }
         }
+        PendingTicketsOk(ids, service_idx, range, generation) => {
+            if generation != app.state.service_status.generation {
+                return;
+            }
+            app.state
+                .service_status
+                .set_pending_tickets(service_idx, &range, Ok(ids));
+        }
+        PendingTicketsErrored(error, service_idx, range, generation) => {
+            if generation != app.state.service_status.generation {
+                return;
+            }
+            if let Some(svc_cfg) = app.config.servicestatus.get(service_idx) {
+                app.event_sender.send_app_event(AppLog(
+                    LogEntry::new(
+                        LogLevel::Warning,
+                        SERVICE_NAME,
+                        format!(
+                            "{}: couldn't list the tickets going to production",
+                            svc_cfg.name
+                        ),
+                    )
+                    .with_detail(error.clone()),
+                ));
+            }
+            app.state
+                .service_status
+                .set_pending_tickets(service_idx, &range, Err(error));
+        }
+        WatchPendingTickets => {
+            let ids = app.state.service_status.selected_pending_tickets().to_vec();
+            if let Some(service_idx) = app.state.service_status.get_selected_service_idx()
+                && !ids.is_empty()
+            {
+                let source = format!(
+                    "the {} deployment",
+                    app.config.servicestatus[service_idx].name
+                );
+                app.event_sender
+                    .send_jira_event(JiraEvent::WatchTickets(ids, source));
+            }
+        }
     }
 }
 
@@ -131,6 +193,41 @@ pub fn handle_generic_event(app: &mut App, event: GenericEvent) {
     }
 }
 
+/// Reads the tickets in the commits production is waiting on from the service's
+/// local clone, if it has one.
+fn look_up_pending_tickets(app: &mut App, service_idx: usize) {
+    let Some(clone) = app
+        .config
+        .servicestatus
+        .get(service_idx)
+        .and_then(|svc_cfg| svc_cfg.clone.as_deref())
+        .map(expand_tilde)
+    else {
+        return;
+    };
+    let Some(range) = app.state.service_status.start_pending_lookup(service_idx) else {
+        return;
+    };
+    let sender = app.event_sender.clone();
+    let generation = app.state.service_status.generation;
+    tokio::task::spawn_blocking(move || {
+        match git::tickets_between(&clone, &range.from, &range.to) {
+            Ok(ids) => sender.send_service_status_event(PendingTicketsOk(
+                ids,
+                service_idx,
+                range,
+                generation,
+            )),
+            Err(e) => sender.send_service_status_event(PendingTicketsErrored(
+                e,
+                service_idx,
+                range,
+                generation,
+            )),
+        }
+    });
+}
+
 fn get_link_url(app: &App) -> Option<String> {
     if !app.state.service_status.has_link() {
         return None;
@@ -1,16 +1,24 @@
 use crate::app::App;
+use crate::client::auth_zero::api::{JwksSource, issuer_from_token_url, jwks_url_from_token_url};
+use crate::client::auth_zero::jwt::Expectations;
 use crate::event::events::AppEvent::AppLog;
 use crate::event::events::GenericEvent::CopyToClipboard;
 use crate::event::events::TokenGeneratorEvent::{
-    EnvListMove, GenerateToken, ServiceListMove, SetFocus, TokenFailed, TokenGenerated,
+    CancelPasteToken, EnvListMove, GenerateToken, OpenPasteToken, PasteTokenBackspace,
+    PasteTokenChar, ServiceListMove, SetFocus, SubmitPastedToken, TokenFailed, TokenGenerated,
+    TokenVerified, TokenVerifyFailed, VerifyToken,
 };
 use crate::event::events::{Event, GenericEvent, TokenGeneratorEvent};
 use crate::popup::model::Popup;
 use crate::state::log::{LogEntry, LogLevel, log_source};
-use crate::state::token_generator::Token;
+use crate::state::token_generator::{Token, Verification};
+use crate::token_export::token_file::write_token_file;
+use crate::token_export::token_server::store_token;
 use crate::ui::widgets::popup::{Part, Type};
+use crate::utils::path::expand_tilde;
 use crate::utils::string_copy::copy_to_clipboard;
 use crate::utils::update_list_state;
+use std::time::Duration;
 
 const SERVICE_NAME: &str = log_source::TOKEN_GENERATOR;
 
@@ -70,11 +78,16 @@ pub fn handle_event(app: &mut App, event: TokenGeneratorEvent) {
 
             let sender = app.event_sender.clone();
             let config = app.config.tokengenerator.clone();
+            let generation = app.state.token_generator.generation;
 
             app.auth_zero_api
-                .fetch_token(service_idx, env_idx, config, sender);
+                .fetch_token(service_idx, env_idx, generation, config, sender);
         }
-        TokenGenerated(token, service_idx, env_idx) => {
+        TokenGenerated(token, service_idx, env_idx, generation) => {
+            // A config reload can move or drop the service while its token is requested.
+            if generation != app.state.token_generator.generation {
+                return;
+            }
             let svc_name = app
                 .config
                 .tokengenerator
@@ -97,6 +110,8 @@ pub fn handle_event(app: &mut App, event: TokenGeneratorEvent) {
                 format!("Token generated: {}/{}", svc_name, env_name),
             )));
 
+            export_token(app, service_idx, env_idx, &token);
+
             app.state
                 .token_generator
                 .set_token_ready(service_idx, env_idx, token);
@@ -110,7 +125,10 @@ pub fn handle_event(app: &mut App, event: TokenGeneratorEvent) {
                 .with_action('c', "copy", Event::Generic(CopyToClipboard)),
             );
         }
-        TokenFailed(error, service_idx, env_idx) => {
+        TokenFailed(error, service_idx, env_idx, generation) => {
+            if generation != app.state.token_generator.generation {
+                return;
+            }
             let svc_name = app
                 .config
                 .tokengenerator
@@ -140,9 +158,175 @@ pub fn handle_event(app: &mut App, event: TokenGeneratorEvent) {
                 .with_detail(error),
             ));
         }
+        VerifyToken => {
+            let Some(token) = app
+                .state
+                .token_generator
+                .get_token_for_selected_service_env()
+                .value()
+                .map(str::to_string)
+            else {
+                app.event_sender.send_app_event(AppLog(LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    "No token to verify — generate one or press [p] to paste one",
+                )));
+                return;
+            };
+            verify_token(app, token, None);
+        }
+        OpenPasteToken => {
+            app.state.token_generator.open_paste();
+        }
+        CancelPasteToken => {
+            app.state.token_generator.close_paste();
+        }
+        PasteTokenChar(c) => {
+            app.state.token_generator.pasted_token.insert(c);
+        }
+        PasteTokenBackspace => {
+            app.state.token_generator.pasted_token.backspace();
+        }
+        SubmitPastedToken => {
+            let token = app.state.token_generator.take_pasted_token();
+            app.state.token_generator.close_paste();
+            if let Some(token) = token {
+                verify_token(app, token, Some("pasted token"));
+            }
+        }
+        TokenVerified(report) => {
+            let label = match &app.state.token_generator.verification {
+                Some(Verification::Verifying(label)) => label.clone(),
+                _ => return,
+            };
+
+            let entry = if report.is_valid() {
+                LogEntry::new(
+                    LogLevel::Info,
+                    SERVICE_NAME,
+                    format!("Token verified: {}", label),
+                )
+            } else {
+                let reasons = report
+                    .rejections()
+                    .iter()
+                    .map(|c| format!("{}: {}", c.name, c.detail))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                LogEntry::new(
+                    LogLevel::Warning,
+                    SERVICE_NAME,
+                    format!("Token would be rejected: {}", label),
+                )
+                .with_detail(reasons)
+            };
+            app.event_sender.send_app_event(AppLog(entry));
+
+            app.state.token_generator.finish_verification(report);
+        }
+        TokenVerifyFailed(error) => {
+            let label = match &app.state.token_generator.verification {
+                Some(Verification::Verifying(label)) => label.clone(),
+                _ => return,
+            };
+
+            app.state.token_generator.fail_verification(error.clone());
+
+            app.event_sender.send_app_event(AppLog(
+                LogEntry::new(
+                    LogLevel::Error,
+                    SERVICE_NAME,
+                    format!("Token verification failed — {}", label),
+                )
+                .with_detail(error),
+            ));
+        }
+    }
+}
+
+/// Publishes a freshly generated token to the token server and, when configured,
+/// the credential's token file.
+fn export_token(app: &mut App, service_idx: usize, env_idx: usize, token: &str) {
+    let Some(service) = app.config.tokengenerator.services.get(service_idx) else {
+        return;
+    };
+    let Some(credentials) = service.credentials.get(env_idx) else {
+        return;
+    };
+
+    store_token(
+        &app.token_store,
+        &service.name,
+        &credentials.env.to_string(),
+        token.to_string(),
+    );
+
+    if let Some(token_file) = &credentials.token_file {
+        let path = expand_tilde(token_file);
+        let entry = match write_token_file(&path, token) {
+            Ok(()) => LogEntry::new(
+                LogLevel::Info,
+                SERVICE_NAME,
+                format!("Token written to {}", path.display()),
+            ),
+            Err(e) => LogEntry::new(
+                LogLevel::Error,
+                SERVICE_NAME,
+                format!("Token file write failed — {}", path.display()),
+            )
+            .with_detail(e.to_string()),
+        };
+        app.event_sender.send_app_event(AppLog(entry));
     }
 }
 
+/// Verifies `token` against the issuer, audience and signing keys of the selected
+/// service/environment. `origin` prefixes the label when the token didn't come
+/// from the generator itself.
+fn verify_token(app: &mut App, token: String, origin: Option<&str>) {
+    let (service_idx, env_idx) = app.state.token_generator.get_selected_service_env();
+    let config = &app.config.tokengenerator;
+    let Some(service) = config.services.get(service_idx) else {
+        return;
+    };
+    let Some(env) = service.credentials.get(env_idx).map(|c| c.env.clone()) else {
+        return;
+    };
+
+    let target = format!("{}/{}", service.name, env.to_string().to_lowercase());
+    let label = match origin {
+        Some(origin) => format!("{} for {}", origin, target),
+        None => target,
+    };
+
+    let token_url = config.auth0.get_from_env(&env);
+    let source = match config.jwks.files.get(&env) {
+        Some(path) => Some(JwksSource::File(expand_tilde(path))),
+        None => jwks_url_from_token_url(token_url).map(JwksSource::Url),
+    };
+    let issuer = issuer_from_token_url(token_url);
+
+    app.state.token_generator.start_verification(label);
+
+    let (Some(source), Some(issuer)) = (source, issuer) else {
+        app.event_sender
+            .send_token_generator_event(TokenVerifyFailed(format!(
+                "No valid Auth0 URL configured for {}",
+                env
+            )));
+        return;
+    };
+
+    let expected = Expectations {
+        issuer,
+        audience: service.audience.clone(),
+    };
+    let cache_ttl = Duration::from_secs(config.jwks.cache_minutes * 60);
+
+    app.auth_zero_api
+        .verify_token(token, source, expected, cache_ttl, app.event_sender.clone());
+}
+
 pub fn handle_generic_event(app: &mut App, event: GenericEvent) {
     if event == CopyToClipboard {
         let token = app
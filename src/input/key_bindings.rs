@@ -1,4 +1,4 @@
-use crate::app::{AppFocus, Tool};
+use crate::app::{AppFocus, ToolId};
 use crate::event::events::{
     AppEvent as App, Direction, Event, GenericEvent as Generic, JiraConfigEvent as JiraConfig,
     JiraEvent as Jira, ServiceStatusConfigEvent as ServiceStatusConfig,
@@ -6,13 +6,20 @@ use crate::event::events::{
     TokenGeneratorEvent as TokenGen,
 };
 use crate::input::key_context::KeyContext::{
-    Config, Editing, Global, List, Logs, Popup, TokenGen as TokenGenCtx, Tool as ToolCtx,
-    ToolConfig, ToolConfigEditing, ToolIgnore,
+    Config, Editing, Global, Jira as JiraCtx, List, Logs, Popup, TokenGen as TokenGenCtx,
+    Tool as ToolCtx, ToolConfig, ToolConfigEditing, ToolIgnore,
 };
 use crate::input::key_event_map::KeyEventMap;
+use crate::state::jira::Focus as JiraFocus;
+use crate::state::jira_board::BoardStep;
 use crate::state::token_generator::Focus;
+use crate::tools;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+/// Rows scrolled by PageUp/PageDown in the Jira detail pane.
+const DETAIL_PAGE: u16 = 10;
+
+/// Registers the app's own bindings, then each tool's.
 pub fn register_bindings(key_event_map: &mut KeyEventMap) {
     // GLOBAL EVENTS
     key_event_map.add_static(
@@ -81,7 +88,7 @@ pub fn register_bindings(key_event_map: &mut KeyEventMap) {
         Config,
         KeyCode::Right,
         KeyModifiers::NONE,
-        App::OpenToolConfig(Tool::ServiceStatus).into(),
+        App::OpenToolConfig(ToolId::ServiceStatus).into(),
     );
     key_event_map.add_static(
         Config,
@@ -104,45 +111,106 @@ pub fn register_bindings(key_event_map: &mut KeyEventMap) {
         App::LogsListMove(Direction::Up).into(),
     );
 
+    // LIST EVENTS
+    key_event_map.add_static(
+        List,
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        Generic::SetFocus(AppFocus::Tool).into(),
+    );
+    key_event_map.add_static(
+        List,
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        App::ListMove(Direction::Down).into(),
+    );
+    key_event_map.add_static(
+        List,
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        App::ListMove(Direction::Up).into(),
+    );
+
+    // TOOL EVENTS — ← goes back to the list from every tool but the token
+    // generator, which uses it to move between its panes.
+    key_event_map.add_static(
+        ToolIgnore(ToolId::TokenGenerator),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        Generic::SetFocus(AppFocus::List).into(),
+    );
+
+    for tool in tools::all() {
+        tool.register_bindings(key_event_map);
+    }
+}
+
+pub fn register_service_status_bindings(key_event_map: &mut KeyEventMap) {
+    // SERVICE STATUS EVENTS
+    key_event_map.add_static(
+        ToolCtx(ToolId::ServiceStatus),
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        ServiceStatus::ListMove(Direction::Down).into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::ServiceStatus),
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        ServiceStatus::ListMove(Direction::Up).into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::ServiceStatus),
+        KeyCode::Char('s'),
+        KeyModifiers::NONE,
+        ServiceStatus::Scan.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::ServiceStatus),
+        KeyCode::Char('w'),
+        KeyModifiers::NONE,
+        ServiceStatus::WatchPendingTickets.into(),
+    );
+
     // TOOL CONFIG EVENTS (Service Status)
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Down,
         KeyModifiers::NONE,
         ServiceStatusConfig::ListMove(Direction::Down).into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Up,
         KeyModifiers::NONE,
         ServiceStatusConfig::ListMove(Direction::Up).into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Char('a'),
         KeyModifiers::NONE,
         ServiceStatusConfig::OpenAddService.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Char('e'),
         KeyModifiers::NONE,
         ServiceStatusConfig::OpenEditService.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Char('x'),
         KeyModifiers::NONE,
         ServiceStatusConfig::RemoveService.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Left,
         KeyModifiers::NONE,
         App::CloseToolConfig.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::ServiceStatus),
+        ToolConfig(ToolId::ServiceStatus),
         KeyCode::Esc,
         KeyModifiers::NONE,
         App::CloseToolConfig.into(),
@@ -150,171 +218,82 @@ pub fn register_bindings(key_event_map: &mut KeyEventMap) {
 
     // SERVICE STATUS ADD POPUP EVENTS
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Esc,
         KeyModifiers::NONE,
         App::CloseToolConfig.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Enter,
         KeyModifiers::NONE,
         ServiceStatusConfig::SubmitConfig.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Backspace,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormBackspace.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Left,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormLeft.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Right,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormRight.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Home,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormHome.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::End,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormEnd.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Delete,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormDelete.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Down,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormNextField.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Up,
         KeyModifiers::NONE,
         ServiceStatusConfig::PrevField.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::Tab,
         KeyModifiers::NONE,
         ServiceStatusConfig::FormNextField.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::ServiceStatus),
+        Editing(ToolId::ServiceStatus),
         KeyCode::BackTab,
         KeyModifiers::SHIFT,
         ServiceStatusConfig::PrevField.into(),
     );
-    key_event_map.add_dynamic(Editing(Tool::ServiceStatus), service_status_form_char);
-
-    // POP UP EVENTS
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Backspace,
-        KeyModifiers::NONE,
-        Jira::RemoveTicketIdChar.into(),
-    );
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Left,
-        KeyModifiers::NONE,
-        Jira::TicketIdLeft.into(),
-    );
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Right,
-        KeyModifiers::NONE,
-        Jira::TicketIdRight.into(),
-    );
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Home,
-        KeyModifiers::NONE,
-        Jira::TicketIdHome.into(),
-    );
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::End,
-        KeyModifiers::NONE,
-        Jira::TicketIdEnd.into(),
-    );
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Delete,
-        KeyModifiers::NONE,
-        Jira::TicketIdDelete.into(),
-    );
-    key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Enter,
-        KeyModifiers::NONE,
-        Jira::SubmitTicketId.into(),
-    );
-    key_event_map.add_dynamic(Editing(Tool::Jira), add_ticket_id_char);
-
-    // LIST EVENTS
-    key_event_map.add_static(
-        List,
-        KeyCode::Right,
-        KeyModifiers::NONE,
-        Generic::SetFocus(AppFocus::Tool).into(),
-    );
-    key_event_map.add_static(
-        List,
-        KeyCode::Down,
-        KeyModifiers::NONE,
-        App::ListMove(Direction::Down).into(),
-    );
-    key_event_map.add_static(
-        List,
-        KeyCode::Up,
-        KeyModifiers::NONE,
-        App::ListMove(Direction::Up).into(),
-    );
-
-    // SERVICE STATUS EVENTS
-    key_event_map.add_static(
-        ToolCtx(Tool::ServiceStatus),
-        KeyCode::Down,
-        KeyModifiers::NONE,
-        ServiceStatus::ListMove(Direction::Down).into(),
-    );
-    key_event_map.add_static(
-        ToolCtx(Tool::ServiceStatus),
-        KeyCode::Up,
-        KeyModifiers::NONE,
-        ServiceStatus::ListMove(Direction::Up).into(),
-    );
-    key_event_map.add_static(
-        ToolCtx(Tool::ServiceStatus),
-        KeyCode::Char('s'),
-        KeyModifiers::NONE,
-        ServiceStatus::Scan.into(),
-    );
+    key_event_map.add_dynamic(Editing(ToolId::ServiceStatus), service_status_form_char);
+}
 
+pub fn register_token_generator_bindings(key_event_map: &mut KeyEventMap) {
     // TOKEN GENERATOR EVENTS
-    key_event_map.add_static(
-        ToolIgnore(Tool::TokenGenerator),
-        KeyCode::Left,
-        KeyModifiers::NONE,
-        Generic::SetFocus(AppFocus::List).into(),
-    );
     key_event_map.add_static(
         TokenGenCtx(Focus::Service),
         KeyCode::Down,
@@ -340,7 +319,7 @@ pub fn register_bindings(key_event_map: &mut KeyEventMap) {
         TokenGen::EnvListMove(Direction::Up).into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::TokenGenerator),
+        ToolCtx(ToolId::TokenGenerator),
         KeyCode::Right,
         KeyModifiers::NONE,
         TokenGen::SetFocus(Focus::Env).into(),
@@ -358,69 +337,102 @@ pub fn register_bindings(key_event_map: &mut KeyEventMap) {
         TokenGen::SetFocus(Focus::Service).into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::TokenGenerator),
+        ToolCtx(ToolId::TokenGenerator),
         KeyCode::Enter,
         KeyModifiers::NONE,
         TokenGen::GenerateToken.into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::TokenGenerator),
+        ToolCtx(ToolId::TokenGenerator),
         KeyCode::Char('c'),
         KeyModifiers::NONE,
         Generic::CopyToClipboard.into(),
     );
+    key_event_map.add_static(
+        ToolCtx(ToolId::TokenGenerator),
+        KeyCode::Char('v'),
+        KeyModifiers::NONE,
+        TokenGen::VerifyToken.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::TokenGenerator),
+        KeyCode::Char('p'),
+        KeyModifiers::NONE,
+        TokenGen::OpenPasteToken.into(),
+    );
+
+    // TOKEN GENERATOR PASTE EVENTS
+    key_event_map.add_static(
+        TokenGenCtx(Focus::Paste),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        TokenGen::SubmitPastedToken.into(),
+    );
+    key_event_map.add_static(
+        TokenGenCtx(Focus::Paste),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        TokenGen::CancelPasteToken.into(),
+    );
+    key_event_map.add_static(
+        TokenGenCtx(Focus::Paste),
+        KeyCode::Backspace,
+        KeyModifiers::NONE,
+        TokenGen::PasteTokenBackspace.into(),
+    );
+    key_event_map.add_dynamic(TokenGenCtx(Focus::Paste), paste_token_char);
 
     // TOKEN GENERATOR CONFIG EVENTS
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Down,
         KeyModifiers::NONE,
         TokenGenConfig::ConfigListMove(Direction::Down).into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Up,
         KeyModifiers::NONE,
         TokenGenConfig::ConfigListMove(Direction::Up).into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Char('a'),
         KeyModifiers::NONE,
         TokenGenConfig::OpenAddService.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Char('e'),
         KeyModifiers::NONE,
         TokenGenConfig::ConfigEdit.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Char('x'),
         KeyModifiers::NONE,
         TokenGenConfig::RemoveService.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Tab,
         KeyModifiers::NONE,
         TokenGenConfig::SwitchFocus.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::BackTab,
         KeyModifiers::SHIFT,
         TokenGenConfig::SwitchFocus.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Left,
         KeyModifiers::NONE,
         App::CloseToolConfig.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::TokenGenerator),
+        ToolConfig(ToolId::TokenGenerator),
         KeyCode::Esc,
         KeyModifiers::NONE,
         App::CloseToolConfig.into(),
@@ -428,225 +440,775 @@ pub fn register_bindings(key_event_map: &mut KeyEventMap) {
 
     // TOKEN GENERATOR CONFIG POPUP EVENTS
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Esc,
         KeyModifiers::NONE,
         App::CloseToolConfig.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Enter,
         KeyModifiers::NONE,
         TokenGenConfig::SubmitConfig.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Backspace,
         KeyModifiers::NONE,
         TokenGenConfig::FormBackspace.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Left,
         KeyModifiers::NONE,
         TokenGenConfig::FormLeft.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Right,
         KeyModifiers::NONE,
         TokenGenConfig::FormRight.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Home,
         KeyModifiers::NONE,
         TokenGenConfig::FormHome.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::End,
         KeyModifiers::NONE,
         TokenGenConfig::FormEnd.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Delete,
         KeyModifiers::NONE,
         TokenGenConfig::FormDelete.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Down,
         KeyModifiers::NONE,
         TokenGenConfig::FormNextField.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Up,
         KeyModifiers::NONE,
         TokenGenConfig::FormPrevField.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::Tab,
         KeyModifiers::NONE,
         TokenGenConfig::FormNextField.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::TokenGenerator),
+        Editing(ToolId::TokenGenerator),
         KeyCode::BackTab,
         KeyModifiers::SHIFT,
         TokenGenConfig::FormPrevField.into(),
     );
-    key_event_map.add_dynamic(Editing(Tool::TokenGenerator), token_gen_config_form_char);
+    key_event_map.add_dynamic(Editing(ToolId::TokenGenerator), token_gen_config_form_char);
+}
 
+pub fn register_jira_bindings(key_event_map: &mut KeyEventMap) {
     // JIRA EVENTS
     key_event_map.add_static(
-        ToolCtx(Tool::Jira),
+        ToolCtx(ToolId::Jira),
         KeyCode::Up,
         KeyModifiers::NONE,
         Jira::ListMove(Direction::Up).into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::Jira),
+        ToolCtx(ToolId::Jira),
         KeyCode::Down,
         KeyModifiers::NONE,
         Jira::ListMove(Direction::Down).into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::Jira),
+        ToolCtx(ToolId::Jira),
         KeyCode::Up,
         KeyModifiers::SHIFT,
         Jira::TicketMove(Direction::Up).into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::Jira),
+        ToolCtx(ToolId::Jira),
         KeyCode::Down,
         KeyModifiers::SHIFT,
         Jira::TicketMove(Direction::Down).into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::Jira),
+        ToolCtx(ToolId::Jira),
         KeyCode::Char('a'),
         KeyModifiers::NONE,
         Jira::NewTicket.into(),
     );
     key_event_map.add_static(
-        Editing(Tool::Jira),
-        KeyCode::Esc,
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('i'),
         KeyModifiers::NONE,
-        Jira::CancelNewTicket.into(),
+        Jira::ImportFromClipboard.into(),
     );
     key_event_map.add_static(
-        ToolCtx(Tool::Jira),
-        KeyCode::Char('x'),
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('f'),
         KeyModifiers::NONE,
-        Jira::RemoveTicket.into(),
+        Jira::ImportFromFile.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('n'),
+        KeyModifiers::NONE,
+        Jira::OpenCreateIssue.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('b'),
+        KeyModifiers::NONE,
+        Jira::CopyBranchName.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('g'),
+        KeyModifiers::NONE,
+        Jira::CheckOutBranch.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('m'),
+        KeyModifiers::NONE,
+        Jira::CopyCommitPrefix.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('r'),
+        KeyModifiers::NONE,
+        Jira::OpenComment.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('w'),
+        KeyModifiers::NONE,
+        Jira::OpenWorklog.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('s'),
+        KeyModifiers::NONE,
+        Jira::ToggleTimer.into(),
     );
-
-    // JIRA CONFIG EVENTS
     key_event_map.add_static(
-        ToolConfig(Tool::Jira),
+        ToolCtx(ToolId::Jira),
         KeyCode::Char('e'),
         KeyModifiers::NONE,
-        JiraConfig::OpenEdit.into(),
+        Jira::OpenNotes.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::Jira),
-        KeyCode::Left,
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('t'),
         KeyModifiers::NONE,
-        App::CloseToolConfig.into(),
+        Jira::OpenTransitions.into(),
     );
     key_event_map.add_static(
-        ToolConfig(Tool::Jira),
-        KeyCode::Esc,
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('v'),
         KeyModifiers::NONE,
-        App::CloseToolConfig.into(),
+        Jira::OpenBoard.into(),
     );
-
-    // JIRA CONFIG POPUP EVENTS
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
-        KeyCode::Esc,
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('/'),
         KeyModifiers::NONE,
-        App::CloseToolConfig.into(),
+        Jira::OpenFilter.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('z'),
+        KeyModifiers::NONE,
+        Jira::CycleSort.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('h'),
+        KeyModifiers::NONE,
+        Jira::CycleGroup.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('u'),
+        KeyModifiers::NONE,
+        Jira::RestoreTicket.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('p'),
+        KeyModifiers::NONE,
+        Jira::OpenPeople.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
         KeyCode::Enter,
         KeyModifiers::NONE,
-        JiraConfig::SubmitConfig.into(),
+        Jira::OpenDetail.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Tab,
+        KeyModifiers::NONE,
+        Jira::NextTab.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::BackTab,
+        KeyModifiers::SHIFT,
+        Jira::PrevTab.into(),
     );
+
+    // JIRA TICKET INPUT EVENTS
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
         KeyCode::Backspace,
         KeyModifiers::NONE,
-        JiraConfig::FormBackspace.into(),
+        Jira::RemoveTicketIdChar.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
         KeyCode::Left,
         KeyModifiers::NONE,
-        JiraConfig::FormLeft.into(),
+        Jira::TicketIdLeft.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
         KeyCode::Right,
         KeyModifiers::NONE,
-        JiraConfig::FormRight.into(),
+        Jira::TicketIdRight.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
         KeyCode::Home,
         KeyModifiers::NONE,
-        JiraConfig::FormHome.into(),
+        Jira::TicketIdHome.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
         KeyCode::End,
         KeyModifiers::NONE,
-        JiraConfig::FormEnd.into(),
+        Jira::TicketIdEnd.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
         KeyCode::Delete,
         KeyModifiers::NONE,
-        JiraConfig::FormDelete.into(),
+        Jira::TicketIdDelete.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
-        KeyCode::Down,
+        Editing(ToolId::Jira),
+        KeyCode::Enter,
         KeyModifiers::NONE,
-        JiraConfig::FormNextField.into(),
+        Jira::SubmitTicketId.into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
+        Editing(ToolId::Jira),
+        KeyCode::Tab,
+        KeyModifiers::NONE,
+        Jira::CycleTicketSite.into(),
+    );
+    key_event_map.add_dynamic(Editing(ToolId::Jira), add_ticket_id_char);
+
+    // JIRA DETAIL PANE EVENTS
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Detail),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CloseDetail.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Detail),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        Jira::CloseDetail.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Detail),
         KeyCode::Up,
         KeyModifiers::NONE,
-        JiraConfig::FormPrevField.into(),
+        Jira::ScrollDetail(Direction::Up, 1).into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
-        KeyCode::Tab,
+        JiraCtx(JiraFocus::Detail),
+        KeyCode::Down,
         KeyModifiers::NONE,
-        JiraConfig::FormNextField.into(),
+        Jira::ScrollDetail(Direction::Down, 1).into(),
     );
     key_event_map.add_static(
-        ToolConfigEditing(Tool::Jira),
-        KeyCode::BackTab,
-        KeyModifiers::SHIFT,
-        JiraConfig::FormPrevField.into(),
+        JiraCtx(JiraFocus::Detail),
+        KeyCode::PageUp,
+        KeyModifiers::NONE,
+        Jira::ScrollDetail(Direction::Up, DETAIL_PAGE).into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Detail),
+        KeyCode::PageDown,
+        KeyModifiers::NONE,
+        Jira::ScrollDetail(Direction::Down, DETAIL_PAGE).into(),
     );
-    key_event_map.add_dynamic(ToolConfigEditing(Tool::Jira), jira_config_form_char);
-}
-
-fn add_ticket_id_char(key_event: KeyEvent) -> Option<Event> {
-    key_event
-        .code
-        .as_char()
-        .map(|c| Jira::AddTicketIdChar(c).into())
-}
+
+    // JIRA BOARD EVENTS
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CloseBoard.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Char('v'),
+        KeyModifiers::NONE,
+        Jira::CloseBoard.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        Jira::BoardSelect(BoardStep::Up).into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        Jira::BoardSelect(BoardStep::Down).into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        Jira::BoardSelect(BoardStep::Left).into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        Jira::BoardSelect(BoardStep::Right).into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Left,
+        KeyModifiers::SHIFT,
+        Jira::MoveCard(BoardStep::Left).into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Board),
+        KeyCode::Right,
+        KeyModifiers::SHIFT,
+        Jira::MoveCard(BoardStep::Right).into(),
+    );
+
+    // JIRA NEW ISSUE FORM EVENTS
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CancelCreateIssue.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        Jira::SubmitCreateIssue.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Backspace,
+        KeyModifiers::NONE,
+        Jira::CreateFormBackspace.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        Jira::CreateFormLeft.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        Jira::CreateFormRight.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Home,
+        KeyModifiers::NONE,
+        Jira::CreateFormHome.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::End,
+        KeyModifiers::NONE,
+        Jira::CreateFormEnd.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Delete,
+        KeyModifiers::NONE,
+        Jira::CreateFormDelete.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        Jira::CreateFormNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        Jira::CreateFormPrevField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::Tab,
+        KeyModifiers::NONE,
+        Jira::CreateFormNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Create),
+        KeyCode::BackTab,
+        KeyModifiers::SHIFT,
+        Jira::CreateFormPrevField.into(),
+    );
+    key_event_map.add_dynamic(JiraCtx(JiraFocus::Create), jira_create_form_char);
+
+    // JIRA COMMENT FORM EVENTS
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CancelComment.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Char('s'),
+        KeyModifiers::CONTROL,
+        Jira::SubmitComment.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        Jira::CommentChar('\n').into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Backspace,
+        KeyModifiers::NONE,
+        Jira::CommentBackspace.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        Jira::CommentLeft.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        Jira::CommentRight.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Home,
+        KeyModifiers::NONE,
+        Jira::CommentHome.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::End,
+        KeyModifiers::NONE,
+        Jira::CommentEnd.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Comment),
+        KeyCode::Delete,
+        KeyModifiers::NONE,
+        Jira::CommentDelete.into(),
+    );
+    key_event_map.add_dynamic(JiraCtx(JiraFocus::Comment), jira_comment_form_char);
+
+    // JIRA LOG WORK FORM EVENTS
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CancelWorklog.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        Jira::SubmitWorklog.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Backspace,
+        KeyModifiers::NONE,
+        Jira::WorklogBackspace.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        Jira::WorklogLeft.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        Jira::WorklogRight.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Home,
+        KeyModifiers::NONE,
+        Jira::WorklogHome.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::End,
+        KeyModifiers::NONE,
+        Jira::WorklogEnd.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Delete,
+        KeyModifiers::NONE,
+        Jira::WorklogDelete.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        Jira::WorklogNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        Jira::WorklogPrevField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::Tab,
+        KeyModifiers::NONE,
+        Jira::WorklogNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Worklog),
+        KeyCode::BackTab,
+        KeyModifiers::SHIFT,
+        Jira::WorklogPrevField.into(),
+    );
+    key_event_map.add_dynamic(JiraCtx(JiraFocus::Worklog), jira_worklog_form_char);
+
+    // JIRA NOTES FORM EVENTS
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CancelNotes.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        Jira::SubmitNotes.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Backspace,
+        KeyModifiers::NONE,
+        Jira::NotesBackspace.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        Jira::NotesLeft.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        Jira::NotesRight.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Home,
+        KeyModifiers::NONE,
+        Jira::NotesHome.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::End,
+        KeyModifiers::NONE,
+        Jira::NotesEnd.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Delete,
+        KeyModifiers::NONE,
+        Jira::NotesDelete.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        Jira::NotesNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        Jira::NotesNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::Tab,
+        KeyModifiers::NONE,
+        Jira::NotesNextField.into(),
+    );
+    key_event_map.add_static(
+        JiraCtx(JiraFocus::Notes),
+        KeyCode::BackTab,
+        KeyModifiers::SHIFT,
+        Jira::NotesNextField.into(),
+    );
+    key_event_map.add_dynamic(JiraCtx(JiraFocus::Notes), jira_notes_form_char);
+    key_event_map.add_static(
+        Editing(ToolId::Jira),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        Jira::CancelNewTicket.into(),
+    );
+    key_event_map.add_static(
+        ToolCtx(ToolId::Jira),
+        KeyCode::Char('x'),
+        KeyModifiers::NONE,
+        Jira::RemoveTicket.into(),
+    );
+
+    // JIRA CONFIG EVENTS
+    key_event_map.add_static(
+        ToolConfig(ToolId::Jira),
+        KeyCode::Char('e'),
+        KeyModifiers::NONE,
+        JiraConfig::OpenEdit.into(),
+    );
+    key_event_map.add_static(
+        ToolConfig(ToolId::Jira),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        App::CloseToolConfig.into(),
+    );
+    key_event_map.add_static(
+        ToolConfig(ToolId::Jira),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        App::CloseToolConfig.into(),
+    );
+
+    // JIRA CONFIG POPUP EVENTS
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Esc,
+        KeyModifiers::NONE,
+        App::CloseToolConfig.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+        JiraConfig::SubmitConfig.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Backspace,
+        KeyModifiers::NONE,
+        JiraConfig::FormBackspace.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Left,
+        KeyModifiers::NONE,
+        JiraConfig::FormLeft.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Right,
+        KeyModifiers::NONE,
+        JiraConfig::FormRight.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Home,
+        KeyModifiers::NONE,
+        JiraConfig::FormHome.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::End,
+        KeyModifiers::NONE,
+        JiraConfig::FormEnd.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Delete,
+        KeyModifiers::NONE,
+        JiraConfig::FormDelete.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Down,
+        KeyModifiers::NONE,
+        JiraConfig::FormNextField.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Up,
+        KeyModifiers::NONE,
+        JiraConfig::FormPrevField.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::Tab,
+        KeyModifiers::NONE,
+        JiraConfig::FormNextField.into(),
+    );
+    key_event_map.add_static(
+        ToolConfigEditing(ToolId::Jira),
+        KeyCode::BackTab,
+        KeyModifiers::SHIFT,
+        JiraConfig::FormPrevField.into(),
+    );
+    key_event_map.add_dynamic(ToolConfigEditing(ToolId::Jira), jira_config_form_char);
+}
+
+fn add_ticket_id_char(key_event: KeyEvent) -> Option<Event> {
+    key_event
+        .code
+        .as_char()
+        .map(|c| Jira::AddTicketIdChar(c).into())
+}
+
+fn paste_token_char(key_event: KeyEvent) -> Option<Event> {
+    key_event
+        .code
+        .as_char()
+        .map(|c| TokenGen::PasteTokenChar(c).into())
+}
 
 fn service_status_form_char(key_event: KeyEvent) -> Option<Event> {
     key_event
@@ -662,6 +1224,31 @@ fn token_gen_config_form_char(key_event: KeyEvent) -> Option<Event> {
         .map(|c| TokenGenConfig::FormChar(c).into())
 }
 
+fn jira_create_form_char(key_event: KeyEvent) -> Option<Event> {
+    key_event
+        .code
+        .as_char()
+        .map(|c| Jira::CreateFormChar(c).into())
+}
+
+fn jira_comment_form_char(key_event: KeyEvent) -> Option<Event> {
+    key_event
+        .code
+        .as_char()
+        .map(|c| Jira::CommentChar(c).into())
+}
+
+fn jira_worklog_form_char(key_event: KeyEvent) -> Option<Event> {
+    key_event
+        .code
+        .as_char()
+        .map(|c| Jira::WorklogChar(c).into())
+}
+
+fn jira_notes_form_char(key_event: KeyEvent) -> Option<Event> {
+    key_event.code.as_char().map(|c| Jira::NotesChar(c).into())
+}
+
 fn jira_config_form_char(key_event: KeyEvent) -> Option<Event> {
     key_event
         .code
@@ -698,41 +1285,87 @@ mod tests {
     #[test_case(Config, KeyCode::Up, KeyModifiers::NONE, App::ConfigListMove(Up).into(); "config up")]
     #[test_case(Config, KeyCode::Enter, KeyModifiers::NONE, App::ToggleFeature.into(); "config enter toggles feature")]
     #[test_case(Config, KeyCode::Left, KeyModifiers::NONE, Generic::SetFocus(AppFocus::List).into(); "config left focuses tools list")]
-    #[test_case(Config, KeyCode::Right, KeyModifiers::NONE, App::OpenToolConfig(Tool::ServiceStatus).into(); "config right opens tool config")]
+    #[test_case(Config, KeyCode::Right, KeyModifiers::NONE, App::OpenToolConfig(ToolId::ServiceStatus).into(); "config right opens tool config")]
     #[test_case(Logs, KeyCode::Down, KeyModifiers::NONE, App::LogsListMove(Down).into(); "logs down navigates")]
     #[test_case(Logs, KeyCode::Up, KeyModifiers::NONE, App::LogsListMove(Up).into(); "logs up navigates")]
-    #[test_case(ToolConfig(Tool::ServiceStatus), KeyCode::Down, KeyModifiers::NONE, ServiceStatusConfig::ListMove(Down).into(); "tool config down")]
-    #[test_case(ToolConfig(Tool::ServiceStatus), KeyCode::Up, KeyModifiers::NONE, ServiceStatusConfig::ListMove(Up).into(); "tool config up")]
-    #[test_case(ToolConfig(Tool::ServiceStatus), KeyCode::Char('a'), KeyModifiers::NONE, ServiceStatusConfig::OpenAddService.into(); "tool config a opens add form")]
-    #[test_case(ToolConfig(Tool::ServiceStatus), KeyCode::Char('x'), KeyModifiers::NONE, ServiceStatusConfig::RemoveService.into(); "tool config x removes service")]
-    #[test_case(ToolConfig(Tool::ServiceStatus), KeyCode::Left, KeyModifiers::NONE, App::CloseToolConfig.into(); "tool config left closes")]
-    #[test_case(Editing(Tool::ServiceStatus), KeyCode::Enter, KeyModifiers::NONE, ServiceStatusConfig::SubmitConfig.into(); "service form enter submits")]
-    #[test_case(Editing(Tool::ServiceStatus), KeyCode::Backspace, KeyModifiers::NONE, ServiceStatusConfig::FormBackspace.into(); "service form backspace")]
-    #[test_case(Editing(Tool::ServiceStatus), KeyCode::Tab, KeyModifiers::NONE, ServiceStatusConfig::FormNextField.into(); "service form tab next field")]
-    #[test_case(Editing(Tool::ServiceStatus), KeyCode::BackTab, KeyModifiers::SHIFT, ServiceStatusConfig::PrevField.into(); "service form shift-tab prev field")]
+    #[test_case(ToolConfig(ToolId::ServiceStatus), KeyCode::Down, KeyModifiers::NONE, ServiceStatusConfig::ListMove(Down).into(); "tool config down")]
+    #[test_case(ToolConfig(ToolId::ServiceStatus), KeyCode::Up, KeyModifiers::NONE, ServiceStatusConfig::ListMove(Up).into(); "tool config up")]
+    #[test_case(ToolConfig(ToolId::ServiceStatus), KeyCode::Char('a'), KeyModifiers::NONE, ServiceStatusConfig::OpenAddService.into(); "tool config a opens add form")]
+    #[test_case(ToolConfig(ToolId::ServiceStatus), KeyCode::Char('x'), KeyModifiers::NONE, ServiceStatusConfig::RemoveService.into(); "tool config x removes service")]
+    #[test_case(ToolConfig(ToolId::ServiceStatus), KeyCode::Left, KeyModifiers::NONE, App::CloseToolConfig.into(); "tool config left closes")]
+    #[test_case(Editing(ToolId::ServiceStatus), KeyCode::Enter, KeyModifiers::NONE, ServiceStatusConfig::SubmitConfig.into(); "service form enter submits")]
+    #[test_case(Editing(ToolId::ServiceStatus), KeyCode::Backspace, KeyModifiers::NONE, ServiceStatusConfig::FormBackspace.into(); "service form backspace")]
+    #[test_case(Editing(ToolId::ServiceStatus), KeyCode::Tab, KeyModifiers::NONE, ServiceStatusConfig::FormNextField.into(); "service form tab next field")]
+    #[test_case(Editing(ToolId::ServiceStatus), KeyCode::BackTab, KeyModifiers::SHIFT, ServiceStatusConfig::PrevField.into(); "service form shift-tab prev field")]
     #[test_case(List, KeyCode::Right, KeyModifiers::NONE, Generic::SetFocus(AppFocus::Tool).into(); "list right focuses tool")]
     #[test_case(List, KeyCode::Down, KeyModifiers::NONE, App::ListMove(Down).into(); "list down")]
     #[test_case(List, KeyCode::Up, KeyModifiers::NONE, App::ListMove(Up).into(); "list up")]
-    #[test_case(ToolCtx(Tool::ServiceStatus), KeyCode::Down, KeyModifiers::NONE, ServiceStatus::ListMove(Down).into(); "service status down")]
-    #[test_case(ToolCtx(Tool::ServiceStatus), KeyCode::Up, KeyModifiers::NONE, ServiceStatus::ListMove(Up).into(); "service status up")]
-    #[test_case(ToolCtx(Tool::ServiceStatus), KeyCode::Char('s'), KeyModifiers::NONE, ServiceStatus::Scan.into(); "s scans services")]
-    #[test_case(ToolIgnore(Tool::TokenGenerator), KeyCode::Left, KeyModifiers::NONE, Generic::SetFocus(AppFocus::List).into(); "tool left focuses list")]
+    #[test_case(ToolCtx(ToolId::ServiceStatus), KeyCode::Down, KeyModifiers::NONE, ServiceStatus::ListMove(Down).into(); "service status down")]
+    #[test_case(ToolCtx(ToolId::ServiceStatus), KeyCode::Up, KeyModifiers::NONE, ServiceStatus::ListMove(Up).into(); "service status up")]
+    #[test_case(ToolCtx(ToolId::ServiceStatus), KeyCode::Char('s'), KeyModifiers::NONE, ServiceStatus::Scan.into(); "s scans services")]
+    #[test_case(ToolCtx(ToolId::ServiceStatus), KeyCode::Char('w'), KeyModifiers::NONE, ServiceStatus::WatchPendingTickets.into(); "w watches pending tickets")]
+    #[test_case(ToolIgnore(ToolId::TokenGenerator), KeyCode::Left, KeyModifiers::NONE, Generic::SetFocus(AppFocus::List).into(); "tool left focuses list")]
     #[test_case(TokenGenCtx(Focus::Service), KeyCode::Down, KeyModifiers::NONE, TokenGen::ServiceListMove(Down).into(); "token service down")]
     #[test_case(TokenGenCtx(Focus::Service), KeyCode::Up, KeyModifiers::NONE, TokenGen::ServiceListMove(Up).into(); "token service up")]
     #[test_case(TokenGenCtx(Focus::Env), KeyCode::Down, KeyModifiers::NONE, TokenGen::EnvListMove(Down).into(); "token env down")]
     #[test_case(TokenGenCtx(Focus::Env), KeyCode::Up, KeyModifiers::NONE, TokenGen::EnvListMove(Up).into(); "token env up")]
-    #[test_case(ToolCtx(Tool::TokenGenerator), KeyCode::Right, KeyModifiers::NONE, TokenGen::SetFocus(Focus::Env).into(); "token right focuses env")]
+    #[test_case(ToolCtx(ToolId::TokenGenerator), KeyCode::Right, KeyModifiers::NONE, TokenGen::SetFocus(Focus::Env).into(); "token right focuses env")]
     #[test_case(TokenGenCtx(Focus::Service), KeyCode::Left, KeyModifiers::NONE, Generic::SetFocus(AppFocus::List).into(); "token service left focuses list")]
     #[test_case(TokenGenCtx(Focus::Env), KeyCode::Left, KeyModifiers::NONE, TokenGen::SetFocus(Focus::Service).into(); "token env left focuses service")]
-    #[test_case(ToolCtx(Tool::TokenGenerator), KeyCode::Enter, KeyModifiers::NONE, TokenGen::GenerateToken.into(); "token enter generates")]
-    #[test_case(ToolCtx(Tool::Jira), KeyCode::Up, KeyModifiers::NONE, Jira::ListMove(Up).into(); "jira up")]
-    #[test_case(ToolCtx(Tool::Jira), KeyCode::Down, KeyModifiers::NONE, Jira::ListMove(Down).into(); "jira down")]
-    #[test_case(ToolCtx(Tool::Jira), KeyCode::Up, KeyModifiers::SHIFT, Jira::TicketMove(Up).into(); "jira shift up moves ticket")]
-    #[test_case(ToolCtx(Tool::Jira), KeyCode::Down, KeyModifiers::SHIFT, Jira::TicketMove(Down).into(); "jira shift down moves ticket")]
-    #[test_case(ToolCtx(Tool::Jira), KeyCode::Char('a'), KeyModifiers::NONE, Jira::NewTicket.into(); "jira a adds ticket")]
-    #[test_case(ToolCtx(Tool::Jira), KeyCode::Char('x'), KeyModifiers::NONE, Jira::RemoveTicket.into(); "jira x removes ticket")]
-    #[test_case(Editing(Tool::Jira), KeyCode::Backspace, KeyModifiers::NONE, Jira::RemoveTicketIdChar.into(); "form backspace removes char")]
-    #[test_case(Editing(Tool::Jira), KeyCode::Enter, KeyModifiers::NONE, Jira::SubmitTicketId.into(); "form enter submits")]
+    #[test_case(ToolCtx(ToolId::TokenGenerator), KeyCode::Enter, KeyModifiers::NONE, TokenGen::GenerateToken.into(); "token enter generates")]
+    #[test_case(ToolCtx(ToolId::TokenGenerator), KeyCode::Char('v'), KeyModifiers::NONE, TokenGen::VerifyToken.into(); "token v verifies")]
+    #[test_case(ToolCtx(ToolId::TokenGenerator), KeyCode::Char('p'), KeyModifiers::NONE, TokenGen::OpenPasteToken.into(); "token p opens paste")]
+    #[test_case(TokenGenCtx(Focus::Paste), KeyCode::Enter, KeyModifiers::NONE, TokenGen::SubmitPastedToken.into(); "paste enter submits")]
+    #[test_case(TokenGenCtx(Focus::Paste), KeyCode::Esc, KeyModifiers::NONE, TokenGen::CancelPasteToken.into(); "paste esc cancels")]
+    #[test_case(TokenGenCtx(Focus::Paste), KeyCode::Char('q'), KeyModifiers::NONE, TokenGen::PasteTokenChar('q').into(); "paste captures chars")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Up, KeyModifiers::NONE, Jira::ListMove(Up).into(); "jira up")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Enter, KeyModifiers::NONE, Jira::OpenDetail.into(); "jira enter opens detail")]
+    #[test_case(JiraCtx(JiraFocus::Detail), KeyCode::Esc, KeyModifiers::NONE, Jira::CloseDetail.into(); "jira detail esc closes")]
+    #[test_case(JiraCtx(JiraFocus::Detail), KeyCode::Down, KeyModifiers::NONE, Jira::ScrollDetail(Down, 1).into(); "jira detail down scrolls")]
+    #[test_case(JiraCtx(JiraFocus::Detail), KeyCode::PageUp, KeyModifiers::NONE, Jira::ScrollDetail(Up, DETAIL_PAGE).into(); "jira detail page up scrolls")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Down, KeyModifiers::NONE, Jira::ListMove(Down).into(); "jira down")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Up, KeyModifiers::SHIFT, Jira::TicketMove(Up).into(); "jira shift up moves ticket")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Down, KeyModifiers::SHIFT, Jira::TicketMove(Down).into(); "jira shift down moves ticket")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('a'), KeyModifiers::NONE, Jira::NewTicket.into(); "jira a adds ticket")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('i'), KeyModifiers::NONE, Jira::ImportFromClipboard.into(); "jira i imports from clipboard")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('f'), KeyModifiers::NONE, Jira::ImportFromFile.into(); "jira f imports from file")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('n'), KeyModifiers::NONE, Jira::OpenCreateIssue.into(); "jira n opens new issue form")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('b'), KeyModifiers::NONE, Jira::CopyBranchName.into(); "jira b copies branch name")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('g'), KeyModifiers::NONE, Jira::CheckOutBranch.into(); "jira g checks out branch")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('m'), KeyModifiers::NONE, Jira::CopyCommitPrefix.into(); "jira m copies commit prefix")]
+    #[test_case(JiraCtx(JiraFocus::Create), KeyCode::Enter, KeyModifiers::NONE, Jira::SubmitCreateIssue.into(); "new issue enter submits")]
+    #[test_case(JiraCtx(JiraFocus::Create), KeyCode::Esc, KeyModifiers::NONE, Jira::CancelCreateIssue.into(); "new issue esc cancels")]
+    #[test_case(JiraCtx(JiraFocus::Create), KeyCode::Char('q'), KeyModifiers::NONE, Jira::CreateFormChar('q').into(); "new issue captures chars")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('r'), KeyModifiers::NONE, Jira::OpenComment.into(); "jira r opens comment form")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('w'), KeyModifiers::NONE, Jira::OpenWorklog.into(); "jira w opens log work form")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('s'), KeyModifiers::NONE, Jira::ToggleTimer.into(); "jira s toggles timer")]
+    #[test_case(JiraCtx(JiraFocus::Comment), KeyCode::Enter, KeyModifiers::NONE, Jira::CommentChar('\n').into(); "comment enter adds newline")]
+    #[test_case(JiraCtx(JiraFocus::Comment), KeyCode::Char('s'), KeyModifiers::CONTROL, Jira::SubmitComment.into(); "comment ctrl-s posts")]
+    #[test_case(JiraCtx(JiraFocus::Comment), KeyCode::Char('s'), KeyModifiers::NONE, Jira::CommentChar('s').into(); "comment captures chars")]
+    #[test_case(JiraCtx(JiraFocus::Worklog), KeyCode::Enter, KeyModifiers::NONE, Jira::SubmitWorklog.into(); "log work enter submits")]
+    #[test_case(JiraCtx(JiraFocus::Worklog), KeyCode::Tab, KeyModifiers::NONE, Jira::WorklogNextField.into(); "log work tab next field")]
+    #[test_case(JiraCtx(JiraFocus::Worklog), KeyCode::Char('1'), KeyModifiers::NONE, Jira::WorklogChar('1').into(); "log work captures chars")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('e'), KeyModifiers::NONE, Jira::OpenNotes.into(); "jira e opens notes")]
+    #[test_case(JiraCtx(JiraFocus::Notes), KeyCode::Enter, KeyModifiers::NONE, Jira::SubmitNotes.into(); "notes enter saves")]
+    #[test_case(JiraCtx(JiraFocus::Notes), KeyCode::BackTab, KeyModifiers::SHIFT, Jira::NotesNextField.into(); "notes shift-tab switches field")]
+    #[test_case(JiraCtx(JiraFocus::Notes), KeyCode::Char('x'), KeyModifiers::NONE, Jira::NotesChar('x').into(); "notes captures chars")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('x'), KeyModifiers::NONE, Jira::RemoveTicket.into(); "jira x removes ticket")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('t'), KeyModifiers::NONE, Jira::OpenTransitions.into(); "jira t opens transitions")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('v'), KeyModifiers::NONE, Jira::OpenBoard.into(); "jira v opens board")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('/'), KeyModifiers::NONE, Jira::OpenFilter.into(); "jira slash opens filter")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('z'), KeyModifiers::NONE, Jira::CycleSort.into(); "jira z cycles sort")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('h'), KeyModifiers::NONE, Jira::CycleGroup.into(); "jira h cycles grouping")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('u'), KeyModifiers::NONE, Jira::RestoreTicket.into(); "jira u restores archived ticket")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Char('p'), KeyModifiers::NONE, Jira::OpenPeople.into(); "jira p opens assign and watch")]
+    #[test_case(JiraCtx(JiraFocus::Board), KeyCode::Esc, KeyModifiers::NONE, Jira::CloseBoard.into(); "board esc closes")]
+    #[test_case(JiraCtx(JiraFocus::Board), KeyCode::Right, KeyModifiers::NONE, Jira::BoardSelect(BoardStep::Right).into(); "board right selects next column")]
+    #[test_case(JiraCtx(JiraFocus::Board), KeyCode::Up, KeyModifiers::NONE, Jira::BoardSelect(BoardStep::Up).into(); "board up selects card above")]
+    #[test_case(JiraCtx(JiraFocus::Board), KeyCode::Left, KeyModifiers::SHIFT, Jira::MoveCard(BoardStep::Left).into(); "board shift left moves card")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::Tab, KeyModifiers::NONE, Jira::NextTab.into(); "jira tab next list")]
+    #[test_case(ToolCtx(ToolId::Jira), KeyCode::BackTab, KeyModifiers::SHIFT, Jira::PrevTab.into(); "jira shift-tab previous list")]
+    #[test_case(Editing(ToolId::Jira), KeyCode::Backspace, KeyModifiers::NONE, Jira::RemoveTicketIdChar.into(); "form backspace removes char")]
+    #[test_case(Editing(ToolId::Jira), KeyCode::Enter, KeyModifiers::NONE, Jira::SubmitTicketId.into(); "form enter submits")]
+    #[test_case(Editing(ToolId::Jira), KeyCode::Tab, KeyModifiers::NONE, Jira::CycleTicketSite.into(); "form tab cycles site")]
     fn binding_resolves_to_expected_event(
         context: KeyContext,
         code: KeyCode,
@@ -748,7 +1381,7 @@ mod tests {
     fn popup_dynamic_handler_maps_char_to_add_ticket_id_char() {
         let map = registered_map();
         let result = map.resolve(
-            Editing(Tool::Jira),
+            Editing(ToolId::Jira),
             KeyEvent::new(KeyCode::Char('A'), KeyModifiers::NONE),
         );
         assert_eq!(result, Some(Jira::AddTicketIdChar('A').into()));
@@ -758,8 +1391,8 @@ mod tests {
     fn popup_dynamic_handler_returns_none_for_non_char() {
         let map = registered_map();
         let result = map.resolve(
-            Editing(Tool::Jira),
-            KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE),
+            Editing(ToolId::Jira),
+            KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE),
         );
         assert_eq!(result, None);
     }
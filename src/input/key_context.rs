@@ -1,4 +1,5 @@
-use crate::app::Tool;
+use crate::app::ToolId;
+use crate::state::jira::Focus as JiraFocus;
 use crate::state::token_generator::Focus as TokenFocus;
 
 #[derive(Eq, Hash, PartialEq, Clone)]
@@ -8,10 +9,11 @@ pub enum KeyContext {
     List,
     Config,
     Logs,
-    ToolConfig(Tool),
-    Tool(Tool),
-    ToolIgnore(Tool),
-    Editing(Tool),
-    ToolConfigEditing(Tool),
+    ToolConfig(ToolId),
+    Tool(ToolId),
+    ToolIgnore(ToolId),
+    Editing(ToolId),
+    ToolConfigEditing(ToolId),
     TokenGen(TokenFocus),
+    Jira(JiraFocus),
 }
@@ -1,3 +1,6 @@
+// A few older tests set fields on a `Default` value one by one.
+#![cfg_attr(test, allow(clippy::field_reassign_with_default))]
+
 mod app;
 mod client;
 mod config;
@@ -8,19 +11,89 @@ mod input;
 mod persistence;
 pub mod popup;
 mod state;
+mod token_export;
+mod tools;
 mod ui;
 mod utils;
 
 use crate::app::App;
-use crate::config::loader::ConfigLoader;
+use crate::config::loader::{CONFIG_VERSION, ConfigLoader};
+use crate::config::model::Config;
+use crate::error::model::ConfigError;
+use similar::TextDiff;
+use std::io::{self, Write};
+
+const USAGE: &str = "usage: dev-tool [config migrate [--dry-run]]";
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
+    let mut config_loader = ConfigLoader::new(".devtool", "config.yaml");
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => {}
+        ["config", "migrate"] => return migrate_config(&mut config_loader, false),
+        ["config", "migrate", "--dry-run"] => return migrate_config(&mut config_loader, true),
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    let (config, problems) = match config_loader.read_or_create_config() {
+        Ok(config) => (config, Vec::new()),
+        Err(ConfigError::Invalid(problems)) => {
+            eprintln!("{}", ConfigError::Invalid(problems.clone()));
+            if !offer_broken_mode()? {
+                std::process::exit(1);
+            }
+            (Config::default(), problems)
+        }
+        Err(e) => return Err(e.into()),
+    };
     let terminal = ratatui::init();
-    let config_loader = ConfigLoader::new(".devtool", "config.yaml");
-    let config = config_loader.read_or_create_config()?;
-    let result = App::new(config, config_loader).run(terminal).await;
+    let result = App::new(config, config_loader)
+        .with_config_problems(problems)
+        .run(terminal)
+        .await;
     ratatui::restore();
     result
 }
+
+/// `dev-tool config migrate`: upgrades config.yaml to the current version, or with
+/// `--dry-run` shows the change as a diff without writing it.
+fn migrate_config(config_loader: &mut ConfigLoader, dry_run: bool) -> color_eyre::Result<()> {
+    let Some(migration) = config_loader.plan_migration()? else {
+        println!("config.yaml is already at version {}", CONFIG_VERSION);
+        return Ok(());
+    };
+    if dry_run {
+        let diff = TextDiff::from_lines(&migration.before, &migration.after);
+        print!(
+            "{}",
+            diff.unified_diff().header(
+                &format!("config.yaml (version {})", migration.from),
+                &format!("config.yaml (version {})", CONFIG_VERSION),
+            )
+        );
+        return Ok(());
+    }
+    let backup = config_loader.apply_migration(&migration)?;
+    println!(
+        "Upgraded config.yaml from version {} to {}; the original was kept at {}",
+        migration.from,
+        CONFIG_VERSION,
+        backup.display()
+    );
+    Ok(())
+}
+
+/// Asks whether to open the TUI with no tools, listing the problems until the
+/// file is fixed.
+fn offer_broken_mode() -> io::Result<bool> {
+    print!("Open dev-tool anyway and show these until config.yaml is fixed? [y/N] ");
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(answer.trim().eq_ignore_ascii_case("y"))
+}
@@ -1,4 +1,5 @@
 use crate::state::jira::Ticket;
+use crate::state::jira_view::ListLayout;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone)]
@@ -9,4 +10,10 @@ pub(crate) struct Persistence {
 #[derive(Deserialize, Serialize, Clone, Default)]
 pub(crate) struct Jira {
     pub tickets: Vec<Ticket>,
+    /// How the watchlist was last sorted and grouped.
+    #[serde(default)]
+    pub layout: ListLayout,
+    /// Tickets archived off the watchlist, most recently archived first.
+    #[serde(default)]
+    pub archive: Vec<Ticket>,
 }
@@ -1,6 +1,7 @@
 use crate::error::model::PersistenceError;
 use crate::persistence::model::{Jira, Persistence};
 use crate::state::jira::Ticket;
+use crate::state::jira_view::ListLayout;
 use std::fs;
 use std::io::ErrorKind;
 use std::path::PathBuf;
@@ -22,9 +23,29 @@ impl JiraFile {
         self.file.read_persistence().map(|p| p.jira)
     }
 
-    pub fn write_jira(&self, tickets: &[Ticket]) -> Result<(), PersistenceError> {
+    #[cfg(test)]
+    pub(crate) fn write_jira(&self, tickets: &[Ticket]) -> Result<(), PersistenceError> {
+        let mut persistence: Persistence = self.file.read_persistence()?;
+        persistence.jira.tickets = tickets.to_owned();
+        self.file.write_persistence(persistence)
+    }
+
+    /// Saves the watchlist and the archive in one write, so a ticket moving between
+    /// them is never in neither.
+    pub fn write_tickets(
+        &self,
+        tickets: &[Ticket],
+        archive: &[Ticket],
+    ) -> Result<(), PersistenceError> {
         let mut persistence: Persistence = self.file.read_persistence()?;
         persistence.jira.tickets = tickets.to_owned();
+        persistence.jira.archive = archive.to_owned();
+        self.file.write_persistence(persistence)
+    }
+
+    pub fn write_layout(&self, layout: ListLayout) -> Result<(), PersistenceError> {
+        let mut persistence: Persistence = self.file.read_persistence()?;
+        persistence.jira.layout = layout;
         self.file.write_persistence(persistence)
     }
 }
@@ -75,6 +96,7 @@ impl PersistenceFile {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::jira_view::{GroupMode, SortMode};
     use tempfile::TempDir;
 
     fn temp_persistence_path(dir: &TempDir) -> PathBuf {
@@ -111,6 +133,7 @@ mod tests {
         let persistence = Persistence {
             jira: Jira {
                 tickets: vec![sample_ticket()],
+                ..Default::default()
             },
         };
         file.write_persistence(persistence).unwrap();
@@ -188,6 +211,7 @@ mod tests {
             title: String::from("OVERWRITTEN"),
             assignee: String::from("John"),
             status: String::from("COMPLETED"),
+            ..Default::default()
         };
 
         JiraFile::new_from_path(path.clone())
@@ -213,6 +237,7 @@ mod tests {
             title: "Second Ticket".to_string(),
             status: "Breaking prod".to_string(),
             assignee: "Not me".to_string(),
+            ..Default::default()
         };
         JiraFile::new_from_path(path.clone())
             .write_jira(&[sample_ticket(), second_ticket])
@@ -233,4 +258,43 @@ mod tests {
         assert_eq!(saved_tickets[1].id, "TEST-2");
         assert_eq!(saved_tickets[1].title, "Second Ticket");
     }
+
+    #[test]
+    fn write_layout_keeps_tickets() {
+        let dir = TempDir::new().unwrap();
+        let path = temp_persistence_path(&dir);
+        let file = JiraFile::new_from_path(path);
+        file.write_jira(&[sample_ticket()]).unwrap();
+
+        let layout = ListLayout {
+            sort: SortMode::Priority,
+            group: GroupMode::Epic,
+        };
+        file.write_layout(layout).unwrap();
+        file.write_jira(&[sample_ticket()]).unwrap();
+
+        let saved = file.read_jira().unwrap();
+        assert_eq!(saved.layout, layout);
+        assert_eq!(saved.tickets.len(), 1);
+    }
+
+    #[test]
+    fn write_tickets_keeps_watchlist_and_archive_separate() {
+        let dir = TempDir::new().unwrap();
+        let path = temp_persistence_path(&dir);
+        let file = JiraFile::new_from_path(path);
+        file.write_jira(&[sample_ticket(), sample_ticket()])
+            .unwrap();
+
+        let archived = Ticket {
+            id: "TEST-2".to_string(),
+            ..sample_ticket()
+        };
+        file.write_tickets(&[sample_ticket()], std::slice::from_ref(&archived))
+            .unwrap();
+
+        let saved = file.read_jira().unwrap();
+        assert_eq!(saved.tickets, vec![sample_ticket()]);
+        assert_eq!(saved.archive, vec![archived]);
+    }
 }
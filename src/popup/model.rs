@@ -31,4 +31,28 @@ impl Popup {
         });
         self
     }
+
+    /// Key for the `index`th option of a `Type::Select` popup: 1–9, then a–z.
+    pub fn select_key(index: usize) -> Option<char> {
+        match index {
+            0..9 => char::from_digit(index as u32 + 1, 10),
+            9..35 => char::from_u32('a' as u32 + (index - 9) as u32),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, Some('1'); "first option")]
+    #[test_case(8, Some('9'); "ninth option")]
+    #[test_case(9, Some('a'); "tenth option")]
+    #[test_case(34, Some('z'); "last option")]
+    #[test_case(35, None; "beyond last option")]
+    fn select_key_returns_expected(index: usize, expected: Option<char>) {
+        assert_eq!(Popup::select_key(index), expected);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::config::model::Config;
+use crate::config::validate::Problem;
 use crate::popup::model::Popup;
 use crate::state::config_editor::ConfigEditor;
 use crate::state::jira::Jira;
@@ -8,8 +9,9 @@ use crate::state::service_status::ServiceStatus;
 use crate::state::service_status_config::ServiceStatusConfigEditor;
 use crate::state::token_generator::TokenGenerator;
 use crate::state::token_generator_config::TokenGeneratorConfigEditor;
-pub(crate) use crate::state::tools::Tool;
+pub(crate) use crate::state::tools::ToolId;
 use crate::state::tools::ToolList;
+use crate::tools;
 use ratatui::widgets::ListState;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -17,14 +19,14 @@ pub enum AppFocus {
     List,
     Tool,
     Config,
-    ToolConfig(Tool),
+    ToolConfig(ToolId),
     JiraInput,
     Logs,
 }
 
 pub struct AppState {
     pub tool_list: ToolList,
-    pub current_tool: Tool,
+    pub current_tool: ToolId,
     pub service_status: ServiceStatus,
     pub token_generator: TokenGenerator,
     pub jira: Jira,
@@ -35,6 +37,8 @@ pub struct AppState {
     pub token_generator_config_editor: TokenGeneratorConfigEditor,
     pub jira_config_editor: JiraConfigEditor,
     pub log: LogState,
+    /// What's wrong with config.yaml while the app runs without it.
+    pub config_problems: Vec<Problem>,
 }
 
 impl AppState {
@@ -46,14 +50,14 @@ impl AppState {
         let has_jira_config = config.jira.is_some();
         let config_editor = ConfigEditor::new(&config.features);
         let tool_list_items = config_editor.enabled_tools(has_jira_config);
-        Self {
+        let mut state = Self {
             tool_list: ToolList {
                 items: tool_list_items,
                 list_state: ListState::default().with_selected(Some(0)),
             },
-            current_tool: Tool::ServiceStatus,
-            service_status: ServiceStatus::new(config.servicestatus.len()),
-            token_generator: TokenGenerator::new(&config.tokengenerator.services),
+            current_tool: ToolId::ServiceStatus,
+            service_status: ServiceStatus::new(0),
+            token_generator: TokenGenerator::new(&[]),
             jira,
             focus: AppFocus::List,
             popup: None,
@@ -62,7 +66,12 @@ impl AppState {
             token_generator_config_editor: TokenGeneratorConfigEditor::new(),
             jira_config_editor: JiraConfigEditor::new(),
             log: LogState::new(),
+            config_problems: Vec::new(),
+        };
+        for tool in tools::all() {
+            tool.load_state(&mut state, config);
         }
+        state
     }
 
     pub fn has_popup(&self) -> bool {
@@ -109,10 +118,11 @@ impl AppState {
 #[cfg(test)]
 mod tests {
     use crate::app::AppFocus;
+    use crate::config::loader::CONFIG_VERSION;
     use crate::config::model::{Auth0Config, Config, JiraConfig, TokenGenerator};
     use crate::persistence::persister::JiraFile;
     use crate::popup::model::Popup;
-    use crate::state::app::{AppState, Tool};
+    use crate::state::app::{AppState, ToolId};
     use crate::state::jira::Jira;
     use crate::ui::widgets::popup::Type;
     use tempfile::TempDir;
@@ -124,6 +134,7 @@ mod tests {
 
     fn test_config() -> Config {
         Config {
+            version: CONFIG_VERSION,
             servicestatus: vec![],
             tokengenerator: TokenGenerator {
                 auth0: Auth0Config {
@@ -133,11 +144,14 @@ mod tests {
                     production: "".to_string(),
                 },
                 services: vec![],
+                jwks: Default::default(),
+                server: None,
             },
             jira: Some(JiraConfig {
                 url: "".to_string(),
                 email: "".to_string(),
                 token: "".to_string(),
+                ..Default::default()
             }),
             features: crate::config::model::Features::default(),
         }
@@ -185,14 +199,14 @@ mod tests {
         let mut state = AppState::build(&test_config(), test_jira());
         // Start on TokenGenerator (index 1)
         state.tool_list.list_state.select(Some(1));
-        state.current_tool = Tool::TokenGenerator;
+        state.current_tool = ToolId::TokenGenerator;
         // Disable Jira; TokenGenerator stays enabled
         state.config_editor.items[2].enabled = false;
 
         state.rebuild_tool_list(true);
 
         // TokenGenerator should still be selected
-        assert_eq!(state.current_tool, Tool::TokenGenerator);
+        assert_eq!(state.current_tool, ToolId::TokenGenerator);
         assert_eq!(state.tool_list.list_state.selected(), Some(1));
     }
 
@@ -201,7 +215,7 @@ mod tests {
         let mut state = AppState::build(&test_config(), test_jira());
         // Start on Jira (index 2)
         state.tool_list.list_state.select(Some(2));
-        state.current_tool = Tool::Jira;
+        state.current_tool = ToolId::Jira;
         // Disable Jira
         state.config_editor.items[2].enabled = false;
 
@@ -209,7 +223,7 @@ mod tests {
 
         // Should move up to index 1 (TokenGenerator)
         assert_eq!(state.tool_list.list_state.selected(), Some(1));
-        assert_eq!(state.current_tool, Tool::TokenGenerator);
+        assert_eq!(state.current_tool, ToolId::TokenGenerator);
     }
 
     #[test]
@@ -1,9 +1,10 @@
 use crate::config::model::Features;
-use crate::state::app::Tool;
+use crate::state::app::ToolId;
+use crate::tools;
 use ratatui::widgets::ListState;
 
 pub struct ConfigEditorItem {
-    pub tool: Tool,
+    pub tool: ToolId,
     pub enabled: bool,
 }
 
@@ -13,24 +14,17 @@ pub struct ConfigEditor {
 }
 
 impl ConfigEditor {
-    /// All three tools are always shown in the config list. Jira's enabled state
+    /// Every registered tool is always shown in the config list. Jira's enabled state
     /// is independent of whether a jira config section exists — the config flag and
     /// the config section are separate concerns.
     pub fn new(features: &Features) -> Self {
-        let items = vec![
-            ConfigEditorItem {
-                tool: Tool::ServiceStatus,
-                enabled: features.service_status,
-            },
-            ConfigEditorItem {
-                tool: Tool::TokenGenerator,
-                enabled: features.token_generator,
-            },
-            ConfigEditorItem {
-                tool: Tool::Jira,
-                enabled: features.jira,
-            },
-        ];
+        let items = tools::all()
+            .iter()
+            .map(|tool| ConfigEditorItem {
+                tool: tool.id(),
+                enabled: tool.is_enabled(features),
+            })
+            .collect();
         Self {
             items,
             list_state: ListState::default().with_selected(Some(0)),
@@ -39,7 +33,7 @@ impl ConfigEditor {
 
     /// Toggle the currently selected item. Returns the updated (Tool, enabled) pair,
     /// or None if nothing is selected.
-    pub fn toggle_selected(&mut self) -> Option<(Tool, bool)> {
+    pub fn toggle_selected(&mut self) -> Option<(ToolId, bool)> {
         let idx = self.list_state.selected()?;
         let item = self.items.get(idx)?;
         let currently_enabled = item.enabled;
@@ -52,11 +46,11 @@ impl ConfigEditor {
 
     /// Returns the list of tools that should appear in the tool list, respecting
     /// the has_jira_config constraint.
-    pub fn enabled_tools(&self, has_jira_config: bool) -> Vec<Tool> {
+    pub fn enabled_tools(&self, has_jira_config: bool) -> Vec<ToolId> {
         self.items
             .iter()
             .filter(|i| i.enabled)
-            .filter(|i| i.tool != Tool::Jira || has_jira_config)
+            .filter(|i| i.tool != ToolId::Jira || has_jira_config)
             .map(|i| i.tool)
             .collect()
     }
@@ -64,39 +58,17 @@ impl ConfigEditor {
     /// Sync the enabled state of each item from a `Features` value.
     pub fn sync_from_features(&mut self, features: &Features) {
         for item in &mut self.items {
-            item.enabled = match item.tool {
-                Tool::ServiceStatus => features.service_status,
-                Tool::TokenGenerator => features.token_generator,
-                Tool::Jira => features.jira,
-            };
+            item.enabled = item.tool.tool().is_enabled(features);
         }
     }
 
     /// Build a `Features` value from the current item state.
     pub fn to_features(&self) -> Features {
-        let service_status = self
-            .items
-            .iter()
-            .find(|i| i.tool == Tool::ServiceStatus)
-            .map(|i| i.enabled)
-            .unwrap_or(true);
-        let token_generator = self
-            .items
-            .iter()
-            .find(|i| i.tool == Tool::TokenGenerator)
-            .map(|i| i.enabled)
-            .unwrap_or(true);
-        let jira = self
-            .items
-            .iter()
-            .find(|i| i.tool == Tool::Jira)
-            .map(|i| i.enabled)
-            .unwrap_or(true);
-        Features {
-            service_status,
-            token_generator,
-            jira,
+        let mut features = Features::default();
+        for item in &self.items {
+            item.tool.tool().set_enabled(&mut features, item.enabled);
         }
+        features
     }
 }
 
@@ -153,7 +125,7 @@ mod tests {
         let mut editor = make_editor(features);
         editor.list_state.select(Some(0));
         let result = editor.toggle_selected();
-        assert_eq!(result, Some((Tool::ServiceStatus, true)));
+        assert_eq!(result, Some((ToolId::ServiceStatus, true)));
         assert!(editor.items[0].enabled);
     }
 
@@ -161,16 +133,16 @@ mod tests {
     fn enabled_tools_excludes_jira_when_no_jira_config() {
         let editor = make_editor(all_enabled());
         let tools = editor.enabled_tools(false);
-        assert!(!tools.contains(&Tool::Jira));
-        assert!(tools.contains(&Tool::ServiceStatus));
-        assert!(tools.contains(&Tool::TokenGenerator));
+        assert!(!tools.contains(&ToolId::Jira));
+        assert!(tools.contains(&ToolId::ServiceStatus));
+        assert!(tools.contains(&ToolId::TokenGenerator));
     }
 
     #[test]
     fn enabled_tools_includes_jira_when_config_present() {
         let editor = make_editor(all_enabled());
         let tools = editor.enabled_tools(true);
-        assert!(tools.contains(&Tool::Jira));
+        assert!(tools.contains(&ToolId::Jira));
     }
 
     #[test]
@@ -1,39 +1,196 @@
-use crate::client::jira::models::TicketResponse;
+use crate::client::jira::models::{
+    BoardConfiguration, Changelog, FieldChange, IssueDetails, NewWorklog, Status, StatusCategory,
+    TicketResponse, parse_time,
+};
+use crate::config::model::{JiraArchiveConfig, JiraGitConfig};
 use crate::event::events::Direction;
 use crate::persistence;
 use crate::persistence::persister::JiraFile;
+use crate::state::jira_archive::{self, Archive};
+use crate::state::jira_board::{self, Board, BoardStep, BoardView, Column};
+use crate::state::jira_comment::CommentForm;
+use crate::state::jira_create::CreateIssueForm;
+use crate::state::jira_notes::NotesForm;
+use crate::state::jira_view::{self, ListLayout, Row};
+use crate::state::jira_worklog::WorklogForm;
+use crate::utils::git::TicketBranch;
+use crate::utils::{ticket_template, update_list_state};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate};
 use log::error;
 use ratatui::widgets::ListState;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 use tui_text_field::TextField;
 
+/// How long a watchlist scan may run before outstanding tickets are marked as failed.
+pub const SCAN_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+pub enum Focus {
+    List,
+    Detail,
+    Create,
+    Comment,
+    Worklog,
+    Notes,
+    Board,
+}
+
+/// What the inline input at the bottom of the watchlist is collecting.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum TicketInput {
+    /// Ticket IDs and ranges, e.g. `ABC-1, ABC-7..9`.
+    #[default]
+    Ids,
+    /// A path to a text file to pull ticket IDs out of.
+    File,
+    /// Text to narrow the watchlist down to, applied as it is typed.
+    Filter,
+    /// A name or email to search for someone to assign the selected ticket to.
+    Assignee,
+}
+
+/// What pressing the timer key did to the selected ticket.
+#[derive(Clone, PartialEq, Debug)]
+pub enum TimerToggle {
+    Started(String),
+    /// The ticket ID and the time to log for it.
+    Stopped(String, NewWorklog),
+}
+
+/// How a bulk import went: the tickets added and the IDs Jira didn't return.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ImportSummary {
+    pub added: Vec<String>,
+    pub missing: Vec<String>,
+}
+
 #[derive(Clone)]
 pub struct Jira {
     pub tickets: Vec<Ticket>,
     pub list_state: ListState,
     pub adding_ticket: bool,
+    pub ticket_input: TicketInput,
     pub new_ticket_id: TextField,
+    /// The site the tickets being added go to; `None` routes each by its project key.
+    pub new_ticket_site: Option<String>,
+    /// Narrows the watchlist to tickets matching every word; see `jira_view::matches`.
+    pub filter: String,
+    pub layout: ListLayout,
     pub jira_file: JiraFile,
-    pub tickets_pending_scan: usize,
+    pub scan: Option<ScanBatch>,
+    /// Why the last refresh of a watchlist ticket failed; its details are stale
+    /// until a later scan succeeds.
+    pub scan_errors: HashMap<String, String>,
+    pub queries: Vec<QueryList>,
+    /// 0 is the watchlist; `n` is `queries[n - 1]`.
+    pub tab: usize,
+    pub focus: Focus,
+    pub detail: Option<TicketDetail>,
+    pub create: Option<CreateIssueForm>,
+    pub comment: Option<CommentForm>,
+    pub worklog: Option<WorklogForm>,
+    pub notes: Option<NotesForm>,
+    /// Local branches per watched ticket, from the last scan of the configured repos.
+    pub branches: HashMap<String, Vec<TicketBranch>>,
+    /// Set while the current tab is shown as a board rather than a list.
+    pub board: Option<Board>,
+    pub archive: Archive,
+    /// When each stopped timer was started, until its worklog is posted, so the
+    /// time isn't lost if posting fails.
+    pub stopped_timers: HashMap<String, DateTime<Local>>,
+}
+
+/// A watchlist scan in flight: the tickets still outstanding and how the rest went.
+#[derive(Clone, Debug)]
+pub struct ScanBatch {
+    started: Instant,
+    total: usize,
+    pending: HashSet<String>,
+    failed: Vec<(String, String)>,
+}
+
+/// The outcome of a finished scan; `failed` pairs a ticket ID with its error.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScanSummary {
+    pub total: usize,
+    pub failed: Vec<(String, String)>,
+}
+
+/// The ticket shown in the detail pane, fetched on demand when the pane opens.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TicketDetail {
+    pub id: String,
+    pub view: DetailView,
+    pub scroll: u16,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum DetailView {
+    Loading,
+    Loaded(Box<IssueDetails>),
+    Failed(String),
+}
+
+/// The latest results of a saved JQL query. Not persisted — refreshed every scan.
+#[derive(Clone)]
+pub struct QueryList {
+    pub name: String,
+    pub tickets: Vec<Ticket>,
+    pub list_state: ListState,
+    pub status: QueryStatus,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum QueryStatus {
+    Pending,
+    Loaded,
+    Failed(String),
+}
+
+impl QueryList {
+    fn new(name: String) -> QueryList {
+        Self {
+            name,
+            tickets: Vec::new(),
+            list_state: ListState::default(),
+            status: QueryStatus::Pending,
+        }
+    }
 }
 
 impl Jira {
     pub fn new() -> Jira {
         let jira_file = JiraFile::default();
-        let tickets = jira_file
-            .read_jira()
-            .unwrap_or_else(|e| {
-                error!("Failed to load jira tickets from file: {}", e);
-                persistence::model::Jira::default()
-            })
-            .tickets;
+        let saved = jira_file.read_jira().unwrap_or_else(|e| {
+            error!("Failed to load jira tickets from file: {}", e);
+            persistence::model::Jira::default()
+        });
         Self {
-            tickets,
+            tickets: saved.tickets,
             list_state: ListState::default().with_selected(None),
             adding_ticket: false,
+            ticket_input: TicketInput::Ids,
             new_ticket_id: TextField::empty(),
+            new_ticket_site: None,
+            filter: String::new(),
+            layout: saved.layout,
+            archive: Archive::new(saved.archive),
             jira_file,
-            tickets_pending_scan: 0,
+            scan: None,
+            scan_errors: HashMap::new(),
+            queries: Vec::new(),
+            tab: 0,
+            focus: Focus::List,
+            detail: None,
+            create: None,
+            comment: None,
+            worklog: None,
+            notes: None,
+            branches: HashMap::new(),
+            board: None,
+            stopped_timers: HashMap::new(),
         }
     }
 
@@ -43,287 +200,1598 @@ impl Jira {
             tickets: Vec::new(),
             list_state: ListState::default().with_selected(None),
             adding_ticket: false,
+            ticket_input: TicketInput::Ids,
             new_ticket_id: TextField::empty(),
+            new_ticket_site: None,
+            filter: String::new(),
+            layout: ListLayout::default(),
+            archive: Archive::default(),
             jira_file,
-            tickets_pending_scan: 0,
+            scan: None,
+            scan_errors: HashMap::new(),
+            queries: Vec::new(),
+            tab: 0,
+            focus: Focus::List,
+            detail: None,
+            create: None,
+            comment: None,
+            worklog: None,
+            notes: None,
+            branches: HashMap::new(),
+            board: None,
+            stopped_timers: HashMap::new(),
         }
     }
 
-    pub fn add_char_to_ticket_id(&mut self, c: char) {
-        self.new_ticket_id.insert(c.to_ascii_uppercase());
+    /// Replaces the query tabs with one per saved query name, dropping stale results.
+    pub fn set_queries(&mut self, names: Vec<String>) {
+        self.queries = names.into_iter().map(QueryList::new).collect();
+        self.tab = self.tab.min(self.tab_count() - 1);
     }
 
-    pub fn remove_char_from_ticket_id(&mut self) {
-        self.new_ticket_id.backspace();
+    pub fn is_watchlist_tab(&self) -> bool {
+        self.tab == 0
     }
 
-    pub fn add_ticket(&mut self, ticket_response: TicketResponse) {
-        self.tickets
-            .push(self.ticket_response_to_ticket(ticket_response));
+    /// The archive's tab comes after the queries, once anything has been archived.
+    pub fn is_archive_tab(&self) -> bool {
+        !self.archive.tickets.is_empty() && self.tab == self.queries.len() + 1
     }
 
-    pub fn remove_ticket(&mut self) {
-        if let Some(ticket_index) = self.list_state.selected() {
-            self.tickets.remove(ticket_index);
-        }
+    fn tab_count(&self) -> usize {
+        self.queries.len() + 1 + usize::from(!self.archive.tickets.is_empty())
     }
 
-    #[allow(dead_code)] // called in tests
-    pub fn update_ticket(&mut self, ticket_response: TicketResponse) {
-        let ticket = self.ticket_response_to_ticket(ticket_response);
-        // Exact match - No need to update
-        if self.tickets.contains(&ticket) {
-            return;
-        }
+    pub fn current_query(&self) -> Option<&QueryList> {
+        self.tab.checked_sub(1).and_then(|i| self.queries.get(i))
+    }
 
-        if let Some(t) = self.tickets.iter_mut().find(|t| t.id == ticket.id) {
-            *t = ticket;
+    pub fn next_tab(&mut self) {
+        self.tab = (self.tab + 1) % self.tab_count();
+    }
+
+    pub fn prev_tab(&mut self) {
+        let tabs = self.tab_count();
+        self.tab = (self.tab + tabs - 1) % tabs;
+    }
+
+    /// The ticket highlighted on the current tab.
+    pub fn selected_ticket(&self) -> Option<&Ticket> {
+        self.current_selection()
+            .and_then(|i| self.current_tickets().get(i))
+    }
+
+    /// Moves the selection through the current tab in the order it is shown.
+    pub fn move_selection(&mut self, direction: Direction) {
+        let order = jira_view::ticket_order(&self.rows());
+        let position = self
+            .current_selection()
+            .and_then(|selected| order.iter().position(|&i| i == selected));
+        let mut cursor = ListState::default().with_selected(position);
+        update_list_state::update_noneable_list(&mut cursor, direction, order.len());
+        let selected = cursor.selected().map(|p| order[p]);
+        self.current_list_state_mut().select(selected);
+    }
+
+    /// The current tab as shown: the watchlist filtered, sorted and grouped, a
+    /// query's results in the order Jira returned them, the archive filtered.
+    pub fn rows(&self) -> Vec<Row> {
+        match self.current_query() {
+            Some(query) => (0..query.tickets.len()).map(Row::Ticket).collect(),
+            None if self.is_archive_tab() => jira_view::arrange(
+                &self.archive.tickets,
+                self.active_filter(),
+                ListLayout::default(),
+            ),
+            None => jira_view::arrange(&self.tickets, self.active_filter(), self.layout),
         }
     }
 
-    /// Like `update_ticket` but returns a human-readable change description
-    /// if the ticket's status or assignee changed.
-    pub fn update_ticket_with_changes(
-        &mut self,
-        ticket_response: TicketResponse,
-    ) -> Option<(String, String)> {
-        let new_ticket = self.ticket_response_to_ticket(ticket_response);
-        // Exact match — nothing changed
-        if self.tickets.contains(&new_ticket) {
-            return None;
+    /// The current tab's filter, including what is being typed while the filter
+    /// is open.
+    pub fn active_filter(&self) -> &str {
+        if self.adding_ticket && self.ticket_input == TicketInput::Filter {
+            self.new_ticket_id.value()
+        } else {
+            self.saved_filter()
         }
+    }
 
-        let id = new_ticket.id.clone();
-        let change_msg = if let Some(existing) = self.tickets.iter().find(|t| t.id == new_ticket.id)
-        {
-            if existing.status != new_ticket.status {
-                format!(
-                    "Status changed: {} → {}",
-                    existing.status, new_ticket.status
-                )
-            } else if existing.assignee != new_ticket.assignee {
-                format!(
-                    "Assignee changed: {} → {}",
-                    existing.assignee, new_ticket.assignee
-                )
-            } else {
-                "Updated".to_string()
-            }
+    /// The filter kept on the current tab; only the watchlist and archive have one.
+    pub fn saved_filter(&self) -> &str {
+        if self.is_archive_tab() {
+            &self.archive.filter
         } else {
-            return None;
-        };
+            &self.filter
+        }
+    }
 
-        if let Some(t) = self.tickets.iter_mut().find(|t| t.id == id) {
-            *t = new_ticket;
+    fn saved_filter_mut(&mut self) -> &mut String {
+        if self.is_archive_tab() {
+            &mut self.archive.filter
+        } else {
+            &mut self.filter
         }
+    }
 
-        Some((id, change_msg))
+    pub fn clear_filter(&mut self) {
+        self.saved_filter_mut().clear();
     }
 
-    pub fn swap_tickets(&mut self, direction: Direction) {
-        if let Some(ticket_index) = self.list_state.selected() {
-            let new_index = match direction {
-                Direction::Up if ticket_index > 0 => ticket_index - 1,
-                Direction::Down if ticket_index < self.tickets.len() - 1 => ticket_index + 1,
-                _ => return,
-            };
-            let ticket = self.tickets.remove(ticket_index);
-            self.tickets.insert(new_index, ticket);
-            self.list_state.select(Some(new_index));
+    /// Keeps the typed filter and, if it hides the selected ticket, selects the
+    /// first one still shown.
+    pub fn apply_filter(&mut self) {
+        *self.saved_filter_mut() = self.new_ticket_id.value().trim().to_string();
+        self.adding_ticket = false;
+        self.new_ticket_id.clear();
+        let order = jira_view::ticket_order(&self.rows());
+        let list_state = self.current_list_state_mut();
+        if !list_state
+            .selected()
+            .is_some_and(|selected| order.contains(&selected))
+        {
+            list_state.select(order.first().copied());
         }
     }
 
-    fn ticket_response_to_ticket(&self, ticket: TicketResponse) -> Ticket {
-        Ticket::new(
-            ticket.key,
-            ticket.fields.summary,
-            ticket.fields.status.name,
-            match ticket.fields.assignee {
-                Some(assignee) => assignee.display_name,
-                None => "Unassigned".to_string(),
-            },
-        )
+    /// Whether the watchlist shows in the order tickets were added and moved in,
+    /// which is the only order moving a ticket makes sense in.
+    pub fn is_manual_order(&self) -> bool {
+        self.layout.is_manual() && self.filter.is_empty()
     }
-}
 
-#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
-pub struct Ticket {
-    pub id: String,
-    pub title: String,
-    pub status: String,
-    pub assignee: String,
-}
+    pub fn cycle_sort(&mut self) {
+        self.layout.sort = self.layout.sort.next();
+    }
 
-impl Ticket {
-    pub fn new(id: String, title: String, status: String, assignee: String) -> Ticket {
-        Self {
-            id,
-            title,
-            status,
-            assignee,
+    pub fn cycle_group(&mut self) {
+        self.layout.group = self.layout.group.next();
+    }
+
+    fn current_selection(&self) -> Option<usize> {
+        match self.current_query() {
+            Some(query) => query.list_state.selected(),
+            None if self.is_archive_tab() => self.archive.list_state.selected(),
+            None => self.list_state.selected(),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::client::jira::models::{Assignee, Fields, Status, TicketResponse};
-    use crate::event::events::Direction;
-    use crate::persistence::persister::JiraFile;
-    use crate::state::jira::{Jira, Ticket};
-    use std::path::PathBuf;
-    use tempfile::TempDir;
-    use test_case::test_case;
-    use tui_text_field::TextField;
+    /// The tickets on the current tab.
+    pub fn current_tickets(&self) -> &[Ticket] {
+        match self.current_query() {
+            Some(query) => &query.tickets,
+            None if self.is_archive_tab() => &self.archive.tickets,
+            None => &self.tickets,
+        }
+    }
 
-    fn get_jira_with_path(path: PathBuf) -> Jira {
-        Jira {
-            tickets: vec![
-                Ticket {
-                    id: "1".to_string(),
-                    title: "title 1".to_string(),
-                    status: "in progress".to_string(),
-                    assignee: "john".to_string(),
-                },
-                Ticket {
-                    id: "2".to_string(),
-                    title: "title 2".to_string(),
-                    status: "complete".to_string(),
-                    assignee: "jane".to_string(),
-                },
-            ],
-            list_state: Default::default(),
-            adding_ticket: false,
-            new_ticket_id: TextField::empty(),
-            jira_file: JiraFile::new_from_path(path),
-            tickets_pending_scan: 0,
+    fn current_list_state_mut(&mut self) -> &mut ListState {
+        if self.is_archive_tab() {
+            return &mut self.archive.list_state;
+        }
+        match self.tab.checked_sub(1) {
+            Some(i) => &mut self.queries[i].list_state,
+            None => &mut self.list_state,
         }
     }
 
-    fn temp_file_path(dir: &TempDir) -> PathBuf {
-        dir.path().join("jirafile.yaml")
+    /// Where focus goes back to when a pane or form closes.
+    fn resting_focus(&self) -> Focus {
+        if self.board.is_some() {
+            Focus::Board
+        } else {
+            Focus::List
+        }
     }
 
-    #[test_case(None, "Unassigned"; "Unassigned set as default")]
-    #[test_case(Some(Assignee{display_name:"John Smith".to_string()}), "John Smith"; "Assignee set from response")]
-    fn jira_add_ticket(assignee: Option<Assignee>, assignee_value: &str) {
-        let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+    /// Shows the current tab as board `id`, whose columns are still to be fetched.
+    pub fn open_board(&mut self, id: u64) {
+        self.detail = None;
+        self.board = Some(Board::new(id));
+        self.focus = Focus::Board;
+    }
 
-        let mut jira = get_jira_with_path(file_path);
-        jira.add_ticket(TicketResponse {
-            key: "TEST-1".to_string(),
-            fields: Fields {
-                assignee,
-                status: Status {
-                    name: "In Progress".to_string(),
-                },
-                summary: "Testing".to_string(),
-            },
-        });
+    pub fn close_board(&mut self) {
+        self.board = None;
+        self.focus = self.resting_focus();
+    }
 
-        assert_eq!(jira.tickets.len(), 3);
-        assert_eq!(jira.tickets[2].id, "TEST-1");
-        assert_eq!(jira.tickets[2].title, "Testing");
-        assert_eq!(jira.tickets[2].status, "In Progress");
-        assert_eq!(jira.tickets[2].assignee, assignee_value);
+    /// Fills in the board's columns, unless it has since been closed.
+    pub fn set_board(&mut self, id: u64, configuration: BoardConfiguration) {
+        if let Some(board) = self.board.as_mut().filter(|b| b.id == id) {
+            board.set_configuration(configuration);
+        }
     }
 
-    #[test_case(Some(0), 1; "Remove ticket leaving one")]
-    #[test_case(None, 2; "Nothing removed when nothing selected")]
-    fn jira_remove_ticket(selection: Option<usize>, length: usize) {
-        let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+    pub fn set_board_failed(&mut self, id: u64, error: String) {
+        if let Some(board) = self.board.as_mut().filter(|b| b.id == id) {
+            board.view = BoardView::Failed(error);
+        }
+    }
 
-        let mut jira = get_jira_with_path(file_path);
-        jira.list_state.select(selection);
-        jira.remove_ticket();
+    /// The current tab's tickets per board column; empty without a loaded board.
+    pub fn board_layout(&self) -> Vec<Vec<usize>> {
+        self.board
+            .as_ref()
+            .map(|board| board.layout(self.current_tickets()))
+            .unwrap_or_default()
+    }
 
-        assert_eq!(jira.tickets.len(), length);
+    pub fn board_select(&mut self, step: BoardStep) {
+        let layout = self.board_layout();
+        let list_state = self.current_list_state_mut();
+        if let Some(selected) = jira_board::step(&layout, list_state.selected(), step) {
+            list_state.select(Some(selected));
+        }
     }
 
-    #[test]
-    fn jira_update_ticket_updates_existing_ticket() {
-        let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+    /// Starts moving the selected card a column sideways, returning the ticket to
+    /// fetch transitions for; the move finishes in [`Jira::take_card_move`].
+    pub fn start_card_move(&mut self, step: BoardStep) -> Option<String> {
+        let layout = self.board_layout();
+        let selected = self.current_list_state_mut().selected();
+        let column = jira_board::target_column(&layout, selected, step)?;
+        let id = self.current_tickets().get(selected?)?.id.clone();
+        self.board.as_mut()?.pending_move = Some((id.clone(), column));
+        Some(id)
+    }
 
-        let mut jira = get_jira_with_path(file_path);
+    /// The column `ticket_id` is being moved to, if its transitions were fetched
+    /// for a card move rather than the transition picker.
+    pub fn take_card_move(&mut self, ticket_id: &str) -> Option<Column> {
+        let board = self.board.as_mut()?;
+        let (_, column) = board.pending_move.take_if(|(id, _)| id == ticket_id)?;
+        board.columns().get(column).cloned()
+    }
 
-        jira.update_ticket(TicketResponse {
-            key: "1".to_string(),
-            fields: Fields {
-                assignee: Some(Assignee {
-                    display_name: "jane".to_string(),
-                }),
-                status: Status {
-                    name: "completed".to_string(),
-                },
-                summary: "Title 1".to_string(),
-            },
+    /// Opens the detail pane on the selected ticket, returning its ID to fetch.
+    pub fn open_detail(&mut self) -> Option<String> {
+        let id = self.selected_ticket()?.id.clone();
+        self.detail = Some(TicketDetail {
+            id: id.clone(),
+            view: DetailView::Loading,
+            scroll: 0,
         });
-
-        assert_eq!(jira.tickets[0].title, "Title 1");
-        assert_eq!(jira.tickets[0].status, "completed");
-        assert_eq!(jira.tickets[0].assignee, "jane");
+        self.focus = Focus::Detail;
+        Some(id)
     }
 
-    #[test]
-    fn jira_update_ticket_does_not_update_if_ticket_has_not_changed() {
-        let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
-
-        let mut jira = get_jira_with_path(file_path);
-
-        jira.update_ticket(TicketResponse {
-            key: "1".to_string(),
-            fields: Fields {
-                assignee: Some(Assignee {
-                    display_name: "john".to_string(),
-                }),
-                status: Status {
-                    name: "in progress".to_string(),
-                },
-                summary: "title 1".to_string(),
-            },
-        });
+    pub fn close_detail(&mut self) {
+        self.detail = None;
+        self.focus = self.resting_focus();
+    }
 
-        assert_tickets_have_not_changed(jira);
+    /// Opens the new issue form, in the selected ticket's project when there is one.
+    pub fn open_create(&mut self) {
+        let project = self
+            .selected_ticket()
+            .or(self.tickets.first())
+            .map(|t| t.project().to_string())
+            .unwrap_or_default();
+        self.detail = None;
+        self.create = Some(CreateIssueForm::new(&project));
+        self.focus = Focus::Create;
     }
 
-    #[test]
-    fn jira_update_ticket_does_not_update_if_passed_unknown_ticket() {
-        let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+    pub fn close_create(&mut self) {
+        self.create = None;
+        self.focus = self.resting_focus();
+    }
 
-        let mut jira = get_jira_with_path(file_path);
+    /// Opens the comment form on the selected ticket.
+    pub fn open_comment(&mut self) {
+        let Some(id) = self.selected_ticket().map(|t| t.id.clone()) else {
+            return;
+        };
+        self.detail = None;
+        self.comment = Some(CommentForm::new(id));
+        self.focus = Focus::Comment;
+    }
 
-        jira.update_ticket(TicketResponse {
-            key: "3".to_string(),
-            fields: Fields {
-                assignee: Some(Assignee {
-                    display_name: "john".to_string(),
-                }),
-                status: Status {
-                    name: "ready for dev".to_string(),
-                },
-                summary: "title 3".to_string(),
-            },
-        });
+    pub fn close_comment(&mut self) {
+        self.comment = None;
+        self.focus = self.resting_focus();
+    }
 
-        assert_tickets_have_not_changed(jira);
+    /// Opens the log work form on the selected ticket, dated `today`.
+    pub fn open_worklog(&mut self, today: NaiveDate) {
+        let Some(id) = self.selected_ticket().map(|t| t.id.clone()) else {
+            return;
+        };
+        self.detail = None;
+        self.worklog = Some(WorklogForm::new(id, today));
+        self.focus = Focus::Worklog;
     }
 
-    #[test]
-    fn jira_add_char_to_ticket_id_adds_char() {
-        let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+    pub fn close_worklog(&mut self) {
+        self.worklog = None;
+        self.focus = self.resting_focus();
+    }
 
-        let mut jira = get_jira_with_path(file_path);
-        jira.add_char_to_ticket_id('s');
-        assert_eq!(jira.new_ticket_id.value(), "S");
+    /// Opens the notes form on the selected watchlist ticket; notes are only kept
+    /// for tickets on the watchlist.
+    pub fn open_notes(&mut self) {
+        if !self.is_watchlist_tab() {
+            return;
+        }
+        let Some(ticket) = self.list_state.selected().and_then(|i| self.tickets.get(i)) else {
+            return;
+        };
+        self.detail = None;
+        self.notes = Some(NotesForm::new(ticket));
+        self.focus = Focus::Notes;
+    }
 
-        jira.add_char_to_ticket_id('-');
-        assert_eq!(jira.new_ticket_id.value(), "S-");
+    pub fn close_notes(&mut self) {
+        self.notes = None;
+        self.focus = self.resting_focus();
+    }
+
+    /// Stores the notes and reminder on a watched ticket, returning false if it
+    /// has since been removed.
+    pub fn set_notes(
+        &mut self,
+        id: &str,
+        notes: String,
+        remind_at: Option<DateTime<Local>>,
+    ) -> bool {
+        let Some(ticket) = self.tickets.iter_mut().find(|t| t.id == id) else {
+            return false;
+        };
+        ticket.notes = notes;
+        ticket.remind_at = remind_at;
+        true
+    }
+
+    /// Takes the reminders that have come due by `now`, as ticket ID and note, so
+    /// each goes off once.
+    pub fn take_due_reminders(&mut self, now: DateTime<Local>) -> Vec<(String, String)> {
+        self.tickets
+            .iter_mut()
+            .filter(|t| t.remind_at.is_some_and(|at| at <= now))
+            .map(|t| {
+                t.remind_at = None;
+                (t.id.clone(), t.notes.clone())
+            })
+            .collect()
+    }
+
+    /// Starts or stops the timer on the selected watchlist ticket. Stopping gives
+    /// back the elapsed time, rounded to the nearest minute and at least one.
+    pub fn toggle_timer(&mut self, now: DateTime<Local>) -> Option<TimerToggle> {
+        if !self.is_watchlist_tab() {
+            return None;
+        }
+        let ticket = self
+            .list_state
+            .selected()
+            .and_then(|i| self.tickets.get_mut(i))?;
+        match ticket.timer_started.take() {
+            None => {
+                ticket.timer_started = Some(now);
+                Some(TimerToggle::Started(ticket.id.clone()))
+            }
+            Some(started) => {
+                let elapsed = (now - started).num_seconds().max(0) as u64;
+                let minutes = ((elapsed + 30) / 60).max(1);
+                self.stopped_timers.insert(ticket.id.clone(), started);
+                Some(TimerToggle::Stopped(
+                    ticket.id.clone(),
+                    NewWorklog {
+                        time_spent_seconds: minutes * 60,
+                        started,
+                        comment: String::new(),
+                    },
+                ))
+            }
+        }
+    }
+
+    /// Forgets the start time kept for a stopped timer once its worklog is posted.
+    pub fn finish_timer(&mut self, ticket_id: &str) {
+        self.stopped_timers.remove(ticket_id);
+    }
+
+    /// Restarts a stopped timer from its original start time after its worklog
+    /// couldn't be posted, so the time can be logged again. Returns whether there
+    /// was one to restore; a timer started again since then is left running.
+    pub fn restore_timer(&mut self, ticket_id: &str) -> bool {
+        let Some(started) = self.stopped_timers.remove(ticket_id) else {
+            return false;
+        };
+        match self.tickets.iter_mut().find(|t| t.id == ticket_id) {
+            Some(ticket) if ticket.timer_started.is_none() => {
+                ticket.timer_started = Some(started);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Shows `details` if the pane is still open on that ticket; late responses
+    /// for a ticket that has since been closed are dropped.
+    pub fn set_details(&mut self, details: IssueDetails) {
+        if let Some(detail) = self.detail.as_mut().filter(|d| d.id == details.key) {
+            detail.view = DetailView::Loaded(Box::new(details));
+        }
+    }
+
+    pub fn set_details_failed(&mut self, id: &str, error: String) {
+        if let Some(detail) = self.detail.as_mut().filter(|d| d.id == id) {
+            detail.view = DetailView::Failed(error);
+        }
+    }
+
+    /// Scrolls the detail pane; the renderer clamps the offset to the content height.
+    pub fn scroll_detail(&mut self, direction: Direction, amount: u16) {
+        if let Some(detail) = self.detail.as_mut() {
+            detail.scroll = match direction {
+                Direction::Up => detail.scroll.saturating_sub(amount),
+                Direction::Down => detail.scroll.saturating_add(amount),
+            };
+        }
+    }
+
+    pub fn set_query_results(
+        &mut self,
+        query_idx: usize,
+        site: &str,
+        responses: Vec<TicketResponse>,
+    ) {
+        let tickets = responses
+            .into_iter()
+            .map(|response| Ticket::from(response).on_site(site))
+            .collect::<Vec<_>>();
+        if let Some(query) = self.queries.get_mut(query_idx) {
+            let selected = query
+                .list_state
+                .selected()
+                .map(|i| i.min(tickets.len().saturating_sub(1)));
+            query
+                .list_state
+                .select(selected.filter(|_| !tickets.is_empty()));
+            query.tickets = tickets;
+            query.status = QueryStatus::Loaded;
+        }
+    }
+
+    pub fn set_query_failed(&mut self, query_idx: usize, error: String) {
+        if let Some(query) = self.queries.get_mut(query_idx) {
+            query.status = QueryStatus::Failed(error);
+        }
+    }
+
+    /// Sets the status of `id` wherever it is listed, returning the status it had
+    /// before (from the first list it was found in).
+    pub fn set_ticket_status(&mut self, id: &str, status: &Status) -> Option<String> {
+        let mut previous = None;
+        for ticket in self.listed_mut(id) {
+            let old = std::mem::replace(&mut ticket.status, status.name.clone());
+            ticket.status_category = status.category();
+            ticket.status_id = status.id.clone();
+            previous.get_or_insert(old);
+        }
+        previous
+    }
+
+    /// Sets who `id` is assigned to wherever it is listed, returning who it was
+    /// assigned to before (from the first list it was found in).
+    pub fn set_ticket_assignee(&mut self, id: &str, assignee: Option<&str>) -> Option<String> {
+        let assignee = assignee.unwrap_or("Unassigned");
+        let mut previous = None;
+        for ticket in self.listed_mut(id) {
+            let old = std::mem::replace(&mut ticket.assignee, assignee.to_string());
+            previous.get_or_insert(old);
+        }
+        previous
+    }
+
+    /// Marks whether the configured user watches `id` in Jira, wherever it is listed.
+    pub fn set_watching(&mut self, id: &str, watching: bool) {
+        for ticket in self.listed_mut(id) {
+            ticket.watching = watching;
+        }
+    }
+
+    /// Every copy of `id` on the watchlist and in the query tabs.
+    fn listed_mut(&mut self, id: &str) -> impl Iterator<Item = &mut Ticket> {
+        std::iter::once(&mut self.tickets)
+            .chain(self.queries.iter_mut().map(|q| &mut q.tickets))
+            .flat_map(|tickets| tickets.iter_mut())
+            .filter(move |t| t.id == id)
+    }
+
+    pub fn is_scanning(&self) -> bool {
+        self.scan.is_some()
+    }
+
+    /// Starts a scan of every watchlist ticket, returning the IDs to fetch. Returns
+    /// `None` if a scan is already running or there is nothing to scan.
+    pub fn start_scan(&mut self, now: Instant) -> Option<Vec<String>> {
+        if self.is_scanning() || self.tickets.is_empty() {
+            return None;
+        }
+        let pending = self
+            .tickets
+            .iter()
+            .map(|t| t.id.clone())
+            .collect::<HashSet<_>>();
+        let ids = pending.iter().cloned().collect();
+        self.scan = Some(ScanBatch {
+            started: now,
+            total: pending.len(),
+            pending,
+            failed: Vec::new(),
+        });
+        Some(ids)
+    }
+
+    /// Records the result of refreshing `id`. Failures mark the ticket as stale;
+    /// success clears it. Returns the summary once the last outstanding ticket is in.
+    pub fn finish_scan_ticket(
+        &mut self,
+        id: &str,
+        result: Result<(), String>,
+    ) -> Option<ScanSummary> {
+        match result {
+            Ok(()) => {
+                self.scan_errors.remove(id);
+            }
+            Err(error) => {
+                self.scan_errors.insert(id.to_string(), error.clone());
+                if let Some(batch) = self.scan.as_mut().filter(|b| b.pending.contains(id)) {
+                    batch.failed.push((id.to_string(), error));
+                }
+            }
+        }
+
+        let batch = self.scan.as_mut()?;
+        batch.pending.remove(id);
+        if batch.pending.is_empty() {
+            return self.take_scan_summary();
+        }
+        None
+    }
+
+    /// Ends a scan that has outlived [`SCAN_TIMEOUT`], failing whatever is still
+    /// outstanding. Responses that arrive afterwards still update their tickets.
+    pub fn expire_scan(&mut self, now: Instant) -> Option<ScanSummary> {
+        let batch = self
+            .scan
+            .as_mut()
+            .filter(|b| now.duration_since(b.started) >= SCAN_TIMEOUT)?;
+        let mut timed_out = batch.pending.drain().collect::<Vec<_>>();
+        timed_out.sort();
+        for id in timed_out {
+            let error = "Timed out".to_string();
+            self.scan_errors.insert(id.clone(), error.clone());
+            batch.failed.push((id, error));
+        }
+        self.take_scan_summary()
+    }
+
+    fn take_scan_summary(&mut self) -> Option<ScanSummary> {
+        self.scan.take().map(|batch| ScanSummary {
+            total: batch.total,
+            failed: batch.failed,
+        })
+    }
+
+    /// Replaces the branch links with a new scan, most recently committed first.
+    pub fn set_branches(&mut self, found: Vec<(String, TicketBranch)>) {
+        self.branches.clear();
+        for (id, branch) in found {
+            self.branches.entry(id).or_default().push(branch);
+        }
+        for branches in self.branches.values_mut() {
+            branches.sort_by_key(|b| std::cmp::Reverse(b.last_commit));
+        }
+    }
+
+    pub fn add_char_to_ticket_id(&mut self, c: char) {
+        match self.ticket_input {
+            TicketInput::Ids => self.new_ticket_id.insert(c.to_ascii_uppercase()),
+            TicketInput::File | TicketInput::Filter | TicketInput::Assignee => {
+                self.new_ticket_id.insert(c)
+            }
+        }
+    }
+
+    pub fn remove_char_from_ticket_id(&mut self) {
+        self.new_ticket_id.backspace();
+    }
+
+    /// Watches a ticket, taking it out of the archive if it was there. It won't
+    /// be archived again until its status changes.
+    pub fn add_ticket(&mut self, site: &str, ticket_response: TicketResponse) {
+        let ticket = self
+            .ticket_response_to_ticket(ticket_response)
+            .on_site(site);
+        self.archive.remove(&ticket.id);
+        self.tickets.push(Ticket {
+            held_in: ticket.status.clone(),
+            ..ticket
+        });
+        self.tab = self.tab.min(self.tab_count() - 1);
+    }
+
+    /// Steps the site for the tickets being added through `sites`, then back to
+    /// routing by project key.
+    pub fn cycle_new_ticket_site(&mut self, sites: &[String]) {
+        self.new_ticket_site = match &self.new_ticket_site {
+            None => sites.first().cloned(),
+            Some(site) => sites.iter().skip_while(|s| *s != site).nth(1).cloned(),
+        };
+    }
+
+    /// The site of ticket `id`, wherever it is listed.
+    pub fn site_of(&self, id: &str) -> Option<&str> {
+        std::iter::once(&self.tickets)
+            .chain(self.queries.iter().map(|q| &q.tickets))
+            .flatten()
+            .find(|t| t.id == id)
+            .map(|t| t.site.as_str())
+    }
+
+    /// The IDs from `ids` that aren't on the watchlist yet.
+    pub fn unwatched(&self, ids: Vec<String>) -> Vec<String> {
+        ids.into_iter()
+            .filter(|id| !self.tickets.iter().any(|t| &t.id == id))
+            .collect()
+    }
+
+    /// Adds the tickets found for a bulk import in the order they were requested,
+    /// skipping any that reached the watchlist in the meantime.
+    pub fn import_tickets(
+        &mut self,
+        site: &str,
+        requested: &[String],
+        mut responses: Vec<TicketResponse>,
+    ) -> ImportSummary {
+        responses.sort_by_key(|r| {
+            requested
+                .iter()
+                .position(|id| id == &r.key)
+                .unwrap_or(usize::MAX)
+        });
+        let missing = requested
+            .iter()
+            .filter(|id| !responses.iter().any(|r| &r.key == *id))
+            .cloned()
+            .collect();
+        let mut added = Vec::new();
+        for response in responses {
+            if self.tickets.iter().any(|t| t.id == response.key) {
+                continue;
+            }
+            added.push(response.key.clone());
+            self.add_ticket(site, response);
+        }
+        ImportSummary { added, missing }
+    }
+
+    pub fn remove_ticket(&mut self) {
+        if let Some(ticket_index) = self.list_state.selected() {
+            let ticket = self.tickets.remove(ticket_index);
+            self.scan_errors.remove(&ticket.id);
+        }
+    }
+
+    /// Moves the watchlist tickets `config` says are finished to the archive,
+    /// returning each one's ID and why.
+    pub fn archive_finished(
+        &mut self,
+        config: &JiraArchiveConfig,
+        now: DateTime<Local>,
+    ) -> Vec<(String, String)> {
+        let selected = self.list_state.selected();
+        let mut archived = Vec::new();
+        let mut kept = Vec::new();
+        for ticket in std::mem::take(&mut self.tickets) {
+            match jira_archive::archive_reason(&ticket, config, now) {
+                Some(reason) => {
+                    archived.push((ticket.id.clone(), reason));
+                    self.scan_errors.remove(&ticket.id);
+                    self.archive.add(ticket, now);
+                }
+                None => kept.push(ticket),
+            }
+        }
+        self.tickets = kept;
+        if !archived.is_empty() {
+            let last = self.tickets.len().checked_sub(1);
+            self.list_state
+                .select(selected.and_then(|s| last.map(|last| s.min(last))));
+        }
+        archived
+    }
+
+    /// Moves the selected archived ticket back to the end of the watchlist, where
+    /// it stays until its status changes. Returns its ID.
+    pub fn restore_selected(&mut self) -> Option<String> {
+        if !self.is_archive_tab() {
+            return None;
+        }
+        let i = self.archive.list_state.selected()?;
+        let ticket = self.archive.take(i)?;
+        let id = ticket.id.clone();
+        if !self.tickets.iter().any(|t| t.id == id) {
+            self.tickets.push(Ticket {
+                held_in: ticket.status.clone(),
+                ..ticket
+            });
+        }
+        self.tab = self.tab.min(self.tab_count() - 1);
+        Some(id)
+    }
+
+    /// Deletes the selected ticket from the archive for good, returning its ID.
+    pub fn remove_archived(&mut self) -> Option<String> {
+        if !self.is_archive_tab() {
+            return None;
+        }
+        let i = self.archive.list_state.selected()?;
+        let id = self.archive.take(i)?.id;
+        self.tab = self.tab.min(self.tab_count() - 1);
+        Some(id)
+    }
+
+    #[allow(dead_code)] // called in tests
+    pub fn update_ticket(&mut self, ticket_response: TicketResponse) {
+        let ticket = self.ticket_response_to_ticket(ticket_response);
+        if let Some(t) = self.tickets.iter_mut().find(|t| t.id == ticket.id) {
+            // Exact match - No need to update
+            let ticket = ticket.keeping_local_fields(t);
+            if *t != ticket {
+                *t = ticket;
+            }
+        }
+    }
+
+    /// Like `update_ticket` but returns human-readable descriptions of what
+    /// changed: one per field edit in the changelog since the last scan, or a
+    /// status/assignee summary when there is no changelog to go on.
+    pub fn update_ticket_with_changes(
+        &mut self,
+        mut ticket_response: TicketResponse,
+    ) -> Option<(String, Vec<String>)> {
+        let changelog = ticket_response.changelog.take();
+        let new_ticket = self.ticket_response_to_ticket(ticket_response);
+        let existing = self.tickets.iter().find(|t| t.id == new_ticket.id)?;
+        // Without the change in the changelog, the status has held since it was
+        // last seen to change, or since now.
+        let status_since = changelog
+            .as_ref()
+            .and_then(Changelog::status_changed_at)
+            .or(existing
+                .status_since
+                .filter(|_| existing.status == new_ticket.status))
+            .or_else(|| Some(Local::now().fixed_offset()));
+        let new_ticket = Ticket {
+            history_seen: changelog
+                .as_ref()
+                .and_then(Changelog::latest)
+                .max(existing.history_seen),
+            status_since,
+            ..new_ticket.keeping_local_fields(existing)
+        };
+        // Exact match — nothing changed
+        if *existing == new_ticket {
+            return None;
+        }
+
+        // Without a previous scan to compare against, the whole changelog would be news.
+        let mut changes: Vec<String> = match (&changelog, existing.history_seen) {
+            (Some(changelog), Some(seen)) => changelog
+                .changes_since(Some(seen))
+                .iter()
+                .map(FieldChange::describe)
+                .collect(),
+            _ => Vec::new(),
+        };
+        if changes.is_empty() {
+            changes.extend(existing.change_summary(&new_ticket));
+        }
+
+        let id = new_ticket.id.clone();
+        if let Some(t) = self.tickets.iter_mut().find(|t| t.id == id) {
+            *t = new_ticket;
+        }
+        if changes.is_empty() {
+            None
+        } else {
+            Some((id, changes))
+        }
+    }
+
+    pub fn swap_tickets(&mut self, direction: Direction) {
+        if let Some(ticket_index) = self.list_state.selected() {
+            let new_index = match direction {
+                Direction::Up if ticket_index > 0 => ticket_index - 1,
+                Direction::Down if ticket_index < self.tickets.len() - 1 => ticket_index + 1,
+                _ => return,
+            };
+            let ticket = self.tickets.remove(ticket_index);
+            self.tickets.insert(new_index, ticket);
+            self.list_state.select(Some(new_index));
+        }
+    }
+
+    fn ticket_response_to_ticket(&self, ticket: TicketResponse) -> Ticket {
+        Ticket::from(ticket)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, Default)]
+pub struct Ticket {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub assignee: String,
+    #[serde(default)]
+    pub status_category: StatusCategory,
+    /// Jira's issue type, e.g. "Bug"; empty for tickets saved before it was tracked.
+    #[serde(default)]
+    pub issue_type: String,
+    /// The status's ID, which board columns are matched on; empty for tickets saved
+    /// before it was tracked.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub status_id: String,
+    /// When the work timer was started, if it is running.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timer_started: Option<DateTime<Local>>,
+    /// Private notes, never sent to Jira.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    /// When to pop the notes up as a reminder; cleared once it has gone off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remind_at: Option<DateTime<Local>>,
+    /// The newest changelog entry already reported, so a scan only reports edits after it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_seen: Option<DateTime<FixedOffset>>,
+    /// The Jira site the ticket lives on, by name; empty for the main site.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub site: String,
+    /// When the ticket was last edited in Jira.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<FixedOffset>>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub priority: String,
+    /// The epic the ticket belongs to, as `KEY Summary`; empty when it has none.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub epic: String,
+    /// When the ticket moved into its current status, from the changelog or else
+    /// when a scan first saw it there.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_since: Option<DateTime<FixedOffset>>,
+    /// The status the ticket was added or restored in. It isn't archived while it
+    /// is still in it, so a finished ticket watched on purpose stays put.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub held_in: String,
+    /// When the ticket was moved to the archive; `None` on the watchlist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Local>>,
+    /// Whether the configured user watches the ticket in Jira.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub watching: bool,
+}
+
+impl Ticket {
+    pub fn new(id: String, title: String, status: String, assignee: String) -> Ticket {
+        Self {
+            id,
+            title,
+            status,
+            assignee,
+            status_category: StatusCategory::default(),
+            issue_type: String::new(),
+            status_id: String::new(),
+            timer_started: None,
+            notes: String::new(),
+            remind_at: None,
+            history_seen: None,
+            site: String::new(),
+            updated: None,
+            priority: String::new(),
+            epic: String::new(),
+            status_since: None,
+            held_in: String::new(),
+            archived_at: None,
+            watching: false,
+        }
+    }
+
+    fn on_site(self, site: &str) -> Ticket {
+        Ticket {
+            site: site.to_string(),
+            ..self
+        }
+    }
+
+    /// Carries over what is only tracked locally, such as a running timer or notes,
+    /// to a fresher copy of this ticket from Jira.
+    fn keeping_local_fields(self, existing: &Ticket) -> Ticket {
+        Ticket {
+            timer_started: existing.timer_started,
+            notes: existing.notes.clone(),
+            remind_at: existing.remind_at,
+            site: existing.site.clone(),
+            // Held only until the status moves on.
+            held_in: Some(&existing.held_in)
+                .filter(|held_in| **held_in == self.status)
+                .cloned()
+                .unwrap_or_default(),
+            ..self
+        }
+    }
+
+    /// A one-line summary of how `new` differs from this ticket, for when there is
+    /// no changelog. Filling in what wasn't tracked before isn't news.
+    fn change_summary(&self, new: &Ticket) -> Option<String> {
+        if self.status != new.status {
+            return Some(format!("Status changed: {} → {}", self.status, new.status));
+        }
+        if self.assignee != new.assignee {
+            return Some(format!(
+                "Assignee changed: {} → {}",
+                self.assignee, new.assignee
+            ));
+        }
+        let backfill = |old: &String, new: &String| {
+            if old.is_empty() {
+                new.clone()
+            } else {
+                old.clone()
+            }
+        };
+        let backfilled = Ticket {
+            issue_type: backfill(&self.issue_type, &new.issue_type),
+            status_id: backfill(&self.status_id, &new.status_id),
+            priority: backfill(&self.priority, &new.priority),
+            epic: backfill(&self.epic, &new.epic),
+            status_category: match self.status_category {
+                StatusCategory::Unknown => new.status_category,
+                category => category,
+            },
+            history_seen: new.history_seen,
+            updated: new.updated,
+            status_since: new.status_since,
+            watching: new.watching,
+            ..self.clone()
+        };
+        (backfilled != *new).then(|| "Updated".to_string())
+    }
+
+    /// The project key, e.g. `ABC` for `ABC-123`.
+    pub fn project(&self) -> &str {
+        self.id
+            .split_once('-')
+            .map_or(&self.id, |(project, _)| project)
+    }
+
+    pub fn branch_name(&self, git: &JiraGitConfig) -> Result<String, String> {
+        self.render(git, &git.branch_template)
+    }
+
+    pub fn commit_prefix(&self, git: &JiraGitConfig) -> Result<String, String> {
+        self.render(git, &git.commit_template)
+    }
+
+    fn render(&self, git: &JiraGitConfig, template: &str) -> Result<String, String> {
+        ticket_template::render(
+            template,
+            &[
+                ("id", &self.id),
+                ("project", self.project()),
+                ("title", &self.title),
+                ("type", git.commit_type(&self.issue_type)),
+                ("issue_type", &self.issue_type),
+            ],
+        )
+    }
+}
+
+impl From<TicketResponse> for Ticket {
+    fn from(ticket: TicketResponse) -> Ticket {
+        Ticket {
+            status_category: ticket.fields.status.category(),
+            watching: (ticket.fields.watches.as_ref()).is_some_and(|w| w.is_watching),
+            status_since: (ticket.changelog.as_ref()).and_then(Changelog::status_changed_at),
+            epic: ticket.fields.epic().unwrap_or_default(),
+            issue_type: ticket.fields.issuetype.map(|t| t.name).unwrap_or_default(),
+            status_id: ticket.fields.status.id.clone(),
+            updated: ticket.fields.updated.as_deref().and_then(parse_time),
+            priority: (ticket.fields.priority.as_ref())
+                .map(|p| p.name.clone())
+                .unwrap_or_default(),
+            ..Ticket::new(
+                ticket.key,
+                ticket.fields.summary,
+                ticket.fields.status.name,
+                match ticket.fields.assignee {
+                    Some(assignee) => assignee.display_name,
+                    None => "Unassigned".to_string(),
+                },
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::jira::models::NewWorklog;
+    use crate::client::jira::models::{
+        Assignee, BoardColumn, BoardConfiguration, ChangeItem, Changelog, ColumnConfig, Fields,
+        History, Status, StatusCategory, StatusCategoryRef, StatusRef, TicketResponse,
+    };
+    use crate::client::jira::models::{DetailFields, IssueDetails, Named};
+    use crate::config::model::{JiraArchiveConfig, JiraGitConfig};
+    use crate::event::events::Direction;
+    use crate::persistence::persister::JiraFile;
+    use crate::state::jira::{
+        DetailView, Focus, ImportSummary, Jira, QueryStatus, SCAN_TIMEOUT, ScanSummary, Ticket,
+        TicketDetail, TicketInput, TimerToggle,
+    };
+    use crate::state::jira_archive::Archive;
+    use crate::state::jira_board::{BoardStep, BoardView};
+    use crate::state::jira_view::{ListLayout, Row, SortMode};
+    use crate::utils::git::TicketBranch;
+    use chrono::{Local, NaiveDate, TimeDelta, TimeZone};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+    use tempfile::TempDir;
+    use test_case::test_case;
+    use tui_text_field::TextField;
+
+    fn get_jira_with_path(path: PathBuf) -> Jira {
+        Jira {
+            tickets: vec![
+                Ticket {
+                    id: "1".to_string(),
+                    title: "title 1".to_string(),
+                    status: "in progress".to_string(),
+                    assignee: "john".to_string(),
+                    ..Default::default()
+                },
+                Ticket {
+                    id: "2".to_string(),
+                    title: "title 2".to_string(),
+                    status: "complete".to_string(),
+                    assignee: "jane".to_string(),
+                    ..Default::default()
+                },
+            ],
+            list_state: Default::default(),
+            adding_ticket: false,
+            ticket_input: TicketInput::Ids,
+            new_ticket_id: TextField::empty(),
+            new_ticket_site: None,
+            filter: String::new(),
+            layout: ListLayout::default(),
+            archive: Archive::default(),
+            jira_file: JiraFile::new_from_path(path),
+            scan: None,
+            scan_errors: HashMap::new(),
+            queries: Vec::new(),
+            tab: 0,
+            focus: Focus::List,
+            detail: None,
+            create: None,
+            comment: None,
+            worklog: None,
+            notes: None,
+            branches: HashMap::new(),
+            board: None,
+            stopped_timers: HashMap::new(),
+        }
+    }
+
+    fn ticket_response(key: &str) -> TicketResponse {
+        TicketResponse {
+            key: key.to_string(),
+            fields: Fields {
+                assignee: None,
+                status: Status {
+                    name: "To Do".to_string(),
+                    ..Default::default()
+                },
+                summary: format!("{} summary", key),
+                issuetype: None,
+                ..Default::default()
+            },
+            changelog: None,
+        }
+    }
+
+    fn temp_file_path(dir: &TempDir) -> PathBuf {
+        dir.path().join("jirafile.yaml")
+    }
+
+    #[test_case(None, "Unassigned"; "Unassigned set as default")]
+    #[test_case(Some(Assignee{display_name:"John Smith".to_string()}), "John Smith"; "Assignee set from response")]
+    fn jira_add_ticket(assignee: Option<Assignee>, assignee_value: &str) {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.add_ticket(
+            "",
+            TicketResponse {
+                key: "TEST-1".to_string(),
+                fields: Fields {
+                    assignee,
+                    status: Status {
+                        name: "In Progress".to_string(),
+                        ..Default::default()
+                    },
+                    summary: "Testing".to_string(),
+                    issuetype: None,
+                    ..Default::default()
+                },
+                changelog: None,
+            },
+        );
+
+        assert_eq!(jira.tickets.len(), 3);
+        assert_eq!(jira.tickets[2].id, "TEST-1");
+        assert_eq!(jira.tickets[2].title, "Testing");
+        assert_eq!(jira.tickets[2].status, "In Progress");
+        assert_eq!(jira.tickets[2].assignee, assignee_value);
+    }
+
+    #[test_case(Some(0), 1; "Remove ticket leaving one")]
+    #[test_case(None, 2; "Nothing removed when nothing selected")]
+    fn jira_remove_ticket(selection: Option<usize>, length: usize) {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.list_state.select(selection);
+        jira.remove_ticket();
+
+        assert_eq!(jira.tickets.len(), length);
+    }
+
+    #[test]
+    fn jira_update_ticket_updates_existing_ticket() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+
+        jira.update_ticket(TicketResponse {
+            key: "1".to_string(),
+            fields: Fields {
+                assignee: Some(Assignee {
+                    display_name: "jane".to_string(),
+                }),
+                status: Status {
+                    name: "completed".to_string(),
+                    ..Default::default()
+                },
+                summary: "Title 1".to_string(),
+                issuetype: None,
+                ..Default::default()
+            },
+            changelog: None,
+        });
+
+        assert_eq!(jira.tickets[0].title, "Title 1");
+        assert_eq!(jira.tickets[0].status, "completed");
+        assert_eq!(jira.tickets[0].assignee, "jane");
+    }
+
+    #[test]
+    fn jira_update_ticket_does_not_update_if_ticket_has_not_changed() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+
+        jira.update_ticket(TicketResponse {
+            key: "1".to_string(),
+            fields: Fields {
+                assignee: Some(Assignee {
+                    display_name: "john".to_string(),
+                }),
+                status: Status {
+                    name: "in progress".to_string(),
+                    ..Default::default()
+                },
+                summary: "title 1".to_string(),
+                issuetype: None,
+                ..Default::default()
+            },
+            changelog: None,
+        });
+
+        assert_tickets_have_not_changed(jira);
+    }
+
+    #[test]
+    fn jira_update_ticket_does_not_update_if_passed_unknown_ticket() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+
+        jira.update_ticket(TicketResponse {
+            key: "3".to_string(),
+            fields: Fields {
+                assignee: Some(Assignee {
+                    display_name: "john".to_string(),
+                }),
+                status: Status {
+                    name: "ready for dev".to_string(),
+                    ..Default::default()
+                },
+                summary: "title 3".to_string(),
+                issuetype: None,
+                ..Default::default()
+            },
+            changelog: None,
+        });
+
+        assert_tickets_have_not_changed(jira);
+    }
+
+    fn response(key: &str, status: &str, summary: &str, issue_type: &str) -> TicketResponse {
+        TicketResponse {
+            key: key.to_string(),
+            fields: Fields {
+                assignee: Some(Assignee {
+                    display_name: "john".to_string(),
+                }),
+                status: Status {
+                    name: status.to_string(),
+                    ..Default::default()
+                },
+                summary: summary.to_string(),
+                issuetype: Some(Named {
+                    name: issue_type.to_string(),
+                }),
+                ..Default::default()
+            },
+            changelog: None,
+        }
+    }
+
+    #[test]
+    fn update_ticket_with_changes_reports_status_change() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        let change = jira.update_ticket_with_changes(response("1", "done", "title 1", "Bug"));
+
+        assert_eq!(
+            change,
+            Some((
+                "1".to_string(),
+                vec!["Status changed: in progress → done".to_string()]
+            ))
+        );
+        assert_eq!(jira.tickets[0].issue_type, "Bug");
+    }
+
+    fn history(created: &str, field: &str, from: &str, to: &str) -> History {
+        History {
+            author: Some(Assignee {
+                display_name: "Alice".to_string(),
+            }),
+            created: created.to_string(),
+            items: vec![ChangeItem {
+                field: field.to_string(),
+                from: Some(from.to_string()),
+                to: Some(to.to_string()),
+            }],
+        }
+    }
+
+    fn with_changelog(response: TicketResponse, histories: Vec<History>) -> TicketResponse {
+        TicketResponse {
+            changelog: Some(Changelog { histories }),
+            ..response
+        }
+    }
+
+    #[test]
+    fn update_ticket_with_changes_reports_each_edit_since_last_scan() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        let seen = history(
+            "2024-03-01T09:00:00.000+0000",
+            "status",
+            "To Do",
+            "in progress",
+        );
+        jira.update_ticket_with_changes(with_changelog(
+            response("1", "in progress", "title 1", "Bug"),
+            vec![seen.clone()],
+        ));
+        assert_eq!(
+            jira.tickets[0].history_seen,
+            seen.created_at(),
+            "first scan only remembers where the history is up to"
+        );
+
+        let change = jira.update_ticket_with_changes(with_changelog(
+            response("1", "done", "title 2", "Bug"),
+            vec![
+                history(
+                    "2024-03-01T11:00:00.000+0000",
+                    "status",
+                    "in progress",
+                    "done",
+                ),
+                seen,
+                history(
+                    "2024-03-01T10:00:00.000+0000",
+                    "summary",
+                    "title 1",
+                    "title 2",
+                ),
+            ],
+        ));
+
+        assert_eq!(
+            change,
+            Some((
+                "1".to_string(),
+                vec![
+                    "summary: title 1 → title 2 (Alice, 2024-03-01 10:00)".to_string(),
+                    "status: in progress → done (Alice, 2024-03-01 11:00)".to_string(),
+                ]
+            ))
+        );
+        assert_eq!(
+            jira.tickets[0].history_seen.map(|at| at.to_rfc3339()),
+            Some("2024-03-01T11:00:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn update_ticket_with_changes_falls_back_to_summary_without_changelog() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.update_ticket_with_changes(with_changelog(
+            response("1", "in progress", "title 1", "Bug"),
+            vec![history(
+                "2024-03-01T09:00:00.000+0000",
+                "status",
+                "To Do",
+                "in progress",
+            )],
+        ));
+
+        let change =
+            jira.update_ticket_with_changes(response("1", "in progress", "title 2", "Bug"));
+
+        assert_eq!(change, Some(("1".to_string(), vec!["Updated".to_string()])));
+        assert!(
+            jira.tickets[0].history_seen.is_some(),
+            "a scan without a changelog keeps the history position"
+        );
+    }
+
+    #[test]
+    fn update_ticket_with_changes_backfills_issue_type_quietly() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        let change =
+            jira.update_ticket_with_changes(response("1", "in progress", "title 1", "Story"));
+
+        assert_eq!(change, None);
+        assert_eq!(jira.tickets[0].issue_type, "Story");
+    }
+
+    #[test]
+    fn update_ticket_with_changes_backfills_status_category_quietly() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        // Saved before status categories were tracked.
+        jira.tickets = vec![
+            serde_yaml::from_str(
+                "id: '1'\ntitle: title 1\nstatus: in progress\nassignee: john\nissue_type: Story\n",
+            )
+            .unwrap(),
+        ];
+        assert_eq!(jira.tickets[0].status_category, StatusCategory::Unknown);
+
+        let mut response = response("1", "in progress", "title 1", "Story");
+        response.fields.status.status_category = Some(StatusCategoryRef {
+            key: StatusCategory::InProgress,
+        });
+        let change = jira.update_ticket_with_changes(response);
+
+        assert_eq!(change, None);
+        assert_eq!(jira.tickets[0].status_category, StatusCategory::InProgress);
+    }
+
+    #[test]
+    fn ticket_branch_name_and_commit_prefix_use_templates() {
+        let ticket = Ticket {
+            id: "ABC-12".to_string(),
+            title: "Fix login redirect".to_string(),
+            issue_type: "Bug".to_string(),
+            ..Default::default()
+        };
+        let git = JiraGitConfig::default();
+        assert_eq!(
+            ticket.branch_name(&git),
+            Ok("fix/ABC-12-fix-login-redirect".to_string())
+        );
+        assert_eq!(ticket.commit_prefix(&git), Ok("fix(ABC-12): ".to_string()));
+
+        let git = JiraGitConfig {
+            branch_template: "{project}/{slug(issue_type)}/{id}".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(ticket.branch_name(&git), Ok("ABC/bug/ABC-12".to_string()));
+    }
+
+    fn branch(name: &str, last_commit: &str) -> TicketBranch {
+        TicketBranch {
+            repository: "app".to_string(),
+            branch: name.to_string(),
+            ahead_behind: None,
+            last_commit: chrono::DateTime::parse_from_rfc3339(last_commit).ok(),
+        }
+    }
+
+    #[test]
+    fn set_branches_groups_by_ticket_newest_first() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.branches.insert(
+            "stale".to_string(),
+            vec![branch("old", "2024-01-01T00:00:00Z")],
+        );
+
+        jira.set_branches(vec![
+            ("1".to_string(), branch("feat/1-a", "2024-01-01T00:00:00Z")),
+            ("2".to_string(), branch("feat/2", "2024-02-01T00:00:00Z")),
+            ("1".to_string(), branch("fix/1-b", "2024-03-01T00:00:00Z")),
+        ]);
+
+        let names = |id: &str| {
+            jira.branches[id]
+                .iter()
+                .map(|b| b.branch.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("1"), vec!["fix/1-b", "feat/1-a"]);
+        assert_eq!(names("2"), vec!["feat/2"]);
+        assert!(!jira.branches.contains_key("stale"));
+    }
+
+    #[test]
+    fn jira_add_char_to_ticket_id_adds_char() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.add_char_to_ticket_id('s');
+        assert_eq!(jira.new_ticket_id.value(), "S");
+
+        jira.add_char_to_ticket_id('-');
+        assert_eq!(jira.new_ticket_id.value(), "S-");
+    }
+
+    #[test]
+    fn jira_add_char_to_file_path_keeps_case() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.ticket_input = TicketInput::File;
+        jira.add_char_to_ticket_id('a');
+        jira.add_char_to_ticket_id('B');
+        assert_eq!(jira.new_ticket_id.value(), "aB");
+    }
+
+    #[test]
+    fn move_selection_follows_the_sorted_order() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.layout.sort = SortMode::Assignee;
+
+        jira.move_selection(Direction::Down);
+        assert_eq!(jira.selected_ticket().unwrap().id, "2");
+        jira.move_selection(Direction::Down);
+        assert_eq!(jira.selected_ticket().unwrap().id, "1");
+        jira.move_selection(Direction::Down);
+        assert_eq!(jira.selected_ticket().unwrap().id, "1");
+        assert!(!jira.is_manual_order());
+    }
+
+    #[test]
+    fn filter_narrows_rows_while_typing_and_moves_a_hidden_selection() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        jira.adding_ticket = true;
+        jira.ticket_input = TicketInput::Filter;
+        for c in "Jane".chars() {
+            jira.add_char_to_ticket_id(c);
+        }
+        assert_eq!(jira.rows(), vec![Row::Ticket(1)]);
+
+        jira.apply_filter();
+        assert_eq!(jira.filter, "Jane");
+        assert!(!jira.adding_ticket);
+        assert_eq!(jira.selected_ticket().unwrap().id, "2");
+        assert_eq!(jira.rows(), vec![Row::Ticket(1)]);
+        assert!(!jira.is_manual_order());
+    }
+
+    #[test]
+    fn cycle_new_ticket_site_steps_through_sites_then_back_to_routing() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        let sites = vec![String::new(), "acme".to_string()];
+
+        jira.cycle_new_ticket_site(&sites);
+        assert_eq!(jira.new_ticket_site.as_deref(), Some(""));
+        jira.cycle_new_ticket_site(&sites);
+        assert_eq!(jira.new_ticket_site.as_deref(), Some("acme"));
+        jira.cycle_new_ticket_site(&sites);
+        assert_eq!(jira.new_ticket_site, None);
+    }
+
+    #[test]
+    fn added_ticket_keeps_its_site_across_scans() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.add_ticket("acme", ticket_response("ACME-1"));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("Q-1")]);
+
+        jira.update_ticket(ticket_response("ACME-1"));
+        assert_eq!(jira.site_of("ACME-1"), Some("acme"));
+        assert_eq!(jira.site_of("Q-1"), Some(""));
+        assert_eq!(jira.site_of("NOPE-1"), None);
+    }
+
+    #[test]
+    fn unwatched_drops_tickets_on_watchlist() {
+        let dir = TempDir::new().unwrap();
+        let jira = get_jira_with_path(temp_file_path(&dir));
+
+        let ids = vec!["2".to_string(), "TEST-1".to_string()];
+        assert_eq!(jira.unwatched(ids), vec!["TEST-1".to_string()]);
+    }
+
+    #[test]
+    fn import_tickets_adds_in_requested_order_and_reports_missing() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        let requested = ["TEST-3", "TEST-1", "TEST-2", "2"].map(String::from);
+        let summary = jira.import_tickets(
+            "",
+            &requested,
+            vec![
+                ticket_response("TEST-1"),
+                ticket_response("TEST-3"),
+                ticket_response("2"),
+            ],
+        );
+
+        assert_eq!(
+            summary,
+            ImportSummary {
+                added: vec!["TEST-3".to_string(), "TEST-1".to_string()],
+                missing: vec!["TEST-2".to_string()],
+            }
+        );
+        let ids = jira
+            .tickets
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["1", "2", "TEST-3", "TEST-1"]);
     }
 
     #[test_case("", ""; "empty field is noop")]
@@ -331,88 +1799,798 @@ mod tests {
     #[test_case("SE", "S"; "removes a single character")]
     fn jira_remove_char_from_ticket_id(initial: &str, expected: &str) {
         let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.new_ticket_id = TextField::new(initial.to_string());
+        jira.remove_char_from_ticket_id();
+        assert_eq!(jira.new_ticket_id.value(), expected);
+    }
+
+    #[test]
+    fn jira_swap_tickets_does_nothing_when_moving_top_ticket_up() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.list_state.select(Some(0));
+
+        jira.swap_tickets(Direction::Up);
+        assert_tickets_have_not_changed(jira);
+    }
+
+    #[test]
+    fn jira_swap_tickets_does_nothing_when_moving_bottom_ticket_down() {
+        let dir = TempDir::new().unwrap();
+        let file_path = temp_file_path(&dir);
+
+        let mut jira = get_jira_with_path(file_path);
+        jira.list_state.select(Some(1));
+
+        jira.swap_tickets(Direction::Down);
+        assert_tickets_have_not_changed(jira);
+    }
+
+    #[test]
+    fn jira_swap_tickets_move_bottom_ticket_up() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(1));
+
+        jira.swap_tickets(Direction::Up);
+        assert_eq!(
+            jira.tickets,
+            vec![
+                Ticket {
+                    id: "2".to_string(),
+                    title: "title 2".to_string(),
+                    status: "complete".to_string(),
+                    assignee: "jane".to_string(),
+                    ..Default::default()
+                },
+                Ticket {
+                    id: "1".to_string(),
+                    title: "title 1".to_string(),
+                    status: "in progress".to_string(),
+                    assignee: "john".to_string(),
+                    ..Default::default()
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn jira_swap_tickets_move_top_ticket_down() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+
+        jira.swap_tickets(Direction::Down);
+        assert_eq!(
+            jira.tickets,
+            vec![
+                Ticket {
+                    id: "2".to_string(),
+                    title: "title 2".to_string(),
+                    status: "complete".to_string(),
+                    assignee: "jane".to_string(),
+                    ..Default::default()
+                },
+                Ticket {
+                    id: "1".to_string(),
+                    title: "title 1".to_string(),
+                    status: "in progress".to_string(),
+                    assignee: "john".to_string(),
+                    ..Default::default()
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn set_queries_creates_pending_tabs() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        jira.set_queries(vec!["Mine".to_string(), "Reported".to_string()]);
+
+        assert_eq!(jira.queries.len(), 2);
+        assert_eq!(jira.queries[1].name, "Reported");
+        assert_eq!(jira.queries[1].status, QueryStatus::Pending);
+    }
+
+    #[test]
+    fn set_queries_clamps_tab_when_queries_removed() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string(), "Reported".to_string()]);
+        jira.tab = 2;
+
+        jira.set_queries(vec!["Mine".to_string()]);
+
+        assert_eq!(jira.tab, 1);
+    }
+
+    #[test]
+    fn next_and_prev_tab_wrap() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+
+        jira.next_tab();
+        assert_eq!(jira.tab, 1);
+        jira.next_tab();
+        assert_eq!(jira.tab, 0);
+        jira.prev_tab();
+        assert_eq!(jira.tab, 1);
+    }
+
+    #[test]
+    fn next_tab_stays_on_watchlist_without_queries() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        jira.next_tab();
+
+        assert!(jira.is_watchlist_tab());
+    }
+
+    #[test]
+    fn selected_ticket_follows_current_tab() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("Q-1"), ticket_response("Q-2")]);
+        jira.list_state.select(Some(1));
+        jira.queries[0].list_state.select(Some(0));
+
+        assert_eq!(jira.selected_ticket().unwrap().id, "2");
+
+        jira.next_tab();
+        assert_eq!(jira.selected_ticket().unwrap().id, "Q-1");
+    }
+
+    #[test]
+    fn move_selection_moves_current_tab_list() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("Q-1"), ticket_response("Q-2")]);
+        jira.next_tab();
+
+        jira.move_selection(Direction::Down);
+
+        assert_eq!(jira.queries[0].list_state.selected(), Some(0));
+        assert_eq!(jira.list_state.selected(), None);
+    }
+
+    #[test]
+    fn set_query_results_clamps_selection() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("Q-1"), ticket_response("Q-2")]);
+        jira.queries[0].list_state.select(Some(1));
+
+        jira.set_query_results(0, "", vec![ticket_response("Q-1")]);
+        assert_eq!(jira.queries[0].list_state.selected(), Some(0));
+        assert_eq!(jira.queries[0].status, QueryStatus::Loaded);
+
+        jira.set_query_results(0, "", vec![]);
+        assert_eq!(jira.queries[0].list_state.selected(), None);
+    }
+
+    #[test]
+    fn set_query_failed_records_error() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+
+        jira.set_query_failed(0, "Bad JQL".to_string());
+
+        assert_eq!(
+            jira.queries[0].status,
+            QueryStatus::Failed("Bad JQL".to_string())
+        );
+    }
+
+    #[test]
+    fn set_ticket_status_updates_watchlist_and_queries() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("1")]);
+
+        let done = Status {
+            id: "6".to_string(),
+            name: "Done".to_string(),
+            status_category: Some(StatusCategoryRef {
+                key: StatusCategory::Done,
+            }),
+        };
+        let previous = jira.set_ticket_status("1", &done);
+
+        assert_eq!(previous, Some("in progress".to_string()));
+        assert_eq!(jira.tickets[0].status, "Done");
+        assert_eq!(jira.tickets[0].status_id, "6");
+        assert_eq!(jira.tickets[0].status_category, StatusCategory::Done);
+        assert_eq!(jira.queries[0].tickets[0].status, "Done");
+    }
+
+    #[test]
+    fn ticket_from_response_keeps_status_category() {
+        let mut response = ticket_response("ABC-1");
+        response.fields.status.status_category = Some(StatusCategoryRef {
+            key: StatusCategory::InProgress,
+        });
+
+        let ticket = Ticket::from(response);
+
+        assert_eq!(ticket.status_category, StatusCategory::InProgress);
+        assert_eq!(ticket.project(), "ABC");
+    }
+
+    #[test]
+    fn set_ticket_status_returns_none_for_unknown_ticket() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        let done = Status {
+            name: "Done".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(jira.set_ticket_status("99", &done), None);
+        assert_tickets_have_not_changed(jira);
+    }
+
+    #[test]
+    fn set_ticket_assignee_updates_watchlist_and_queries() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("1")]);
+
+        assert_eq!(
+            jira.set_ticket_assignee("1", Some("Alice")),
+            Some("john".to_string())
+        );
+        assert_eq!(jira.tickets[0].assignee, "Alice");
+        assert_eq!(jira.queries[0].tickets[0].assignee, "Alice");
+
+        jira.set_ticket_assignee("1", None);
+        assert_eq!(jira.tickets[0].assignee, "Unassigned");
+        assert_eq!(jira.set_ticket_assignee("99", Some("Alice")), None);
+    }
+
+    #[test]
+    fn set_watching_marks_every_copy() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.set_query_results(0, "", vec![ticket_response("2")]);
+
+        jira.set_watching("2", true);
+
+        assert!(jira.tickets[1].watching);
+        assert!(jira.queries[0].tickets[0].watching);
+        assert!(!jira.tickets[0].watching);
+    }
+
+    #[test]
+    fn watching_in_jira_alone_is_not_a_change() {
+        let old = Ticket::new(
+            "1".to_string(),
+            "title".to_string(),
+            "To Do".to_string(),
+            "john".to_string(),
+        );
+        let new = Ticket {
+            watching: true,
+            ..old.clone()
+        };
+        assert_eq!(old.change_summary(&new), None);
+    }
+
+    fn issue_details(key: &str) -> IssueDetails {
+        IssueDetails {
+            key: key.to_string(),
+            fields: DetailFields {
+                summary: format!("{} summary", key),
+                status: Status {
+                    name: "To Do".to_string(),
+                    ..Default::default()
+                },
+                assignee: None,
+                description: None,
+                comment: None,
+                subtasks: Vec::new(),
+                issuelinks: Vec::new(),
+                priority: None,
+                issuetype: None,
+                labels: Vec::new(),
+                fix_versions: Vec::new(),
+            },
+            changelog: None,
+        }
+    }
+
+    #[test]
+    fn open_detail_loads_selected_ticket() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(1));
+
+        assert_eq!(jira.open_detail(), Some("2".to_string()));
+        assert_eq!(jira.focus, Focus::Detail);
+        let detail = jira.detail.unwrap();
+        assert_eq!(detail.id, "2");
+        assert_eq!(detail.view, DetailView::Loading);
+    }
+
+    #[test]
+    fn open_detail_without_selection_does_nothing() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        assert_eq!(jira.open_detail(), None);
+        assert_eq!(jira.focus, Focus::List);
+        assert!(jira.detail.is_none());
+    }
+
+    #[test]
+    fn open_create_uses_selected_ticket_project() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.tickets.push(Ticket {
+            id: "OPS-7".to_string(),
+            ..Default::default()
+        });
+        jira.list_state.select(Some(2));
+        jira.detail = Some(TicketDetail {
+            id: "OPS-7".to_string(),
+            view: DetailView::Loading,
+            scroll: 0,
+        });
+
+        jira.open_create();
+
+        assert_eq!(jira.focus, Focus::Create);
+        assert_eq!(jira.detail, None);
+        assert_eq!(jira.create.as_ref().unwrap().project.value(), "OPS");
+
+        jira.close_create();
+        assert_eq!(jira.focus, Focus::List);
+        assert!(jira.create.is_none());
+    }
+
+    #[test]
+    fn open_comment_and_worklog_target_selected_ticket() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.open_comment();
+        assert!(jira.comment.is_none());
 
-        let mut jira = get_jira_with_path(file_path);
-        jira.new_ticket_id = TextField::new(initial.to_string());
-        jira.remove_char_from_ticket_id();
-        assert_eq!(jira.new_ticket_id.value(), expected);
+        jira.list_state.select(Some(1));
+        jira.open_comment();
+        assert_eq!(jira.focus, Focus::Comment);
+        assert_eq!(jira.comment.as_ref().unwrap().ticket_id, "2");
+        jira.close_comment();
+        assert_eq!(jira.focus, Focus::List);
+
+        jira.open_worklog(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(jira.focus, Focus::Worklog);
+        let form = jira.worklog.as_ref().unwrap();
+        assert_eq!(form.ticket_id, "2");
+        assert_eq!(form.date.value(), "2024-03-01");
+        jira.close_worklog();
+        assert!(jira.worklog.is_none());
     }
 
     #[test]
-    fn jira_swap_tickets_does_nothing_when_moving_top_ticket_up() {
+    fn toggle_timer_starts_then_stops_with_rounded_minutes() {
         let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        let start = Local.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
 
-        let mut jira = get_jira_with_path(file_path);
+        assert_eq!(
+            jira.toggle_timer(start),
+            Some(TimerToggle::Started("1".to_string()))
+        );
+        assert_eq!(jira.tickets[0].timer_started, Some(start));
+
+        let stopped = jira.toggle_timer(start + TimeDelta::seconds(95 * 60 + 31));
+        assert_eq!(
+            stopped,
+            Some(TimerToggle::Stopped(
+                "1".to_string(),
+                NewWorklog {
+                    time_spent_seconds: 96 * 60,
+                    started: start,
+                    comment: String::new(),
+                }
+            ))
+        );
+        assert_eq!(jira.tickets[0].timer_started, None);
+    }
+
+    #[test]
+    fn toggle_timer_logs_at_least_a_minute() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
         jira.list_state.select(Some(0));
+        let start = Local.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        jira.toggle_timer(start);
 
-        jira.swap_tickets(Direction::Up);
-        assert_tickets_have_not_changed(jira);
+        match jira.toggle_timer(start + TimeDelta::seconds(5)) {
+            Some(TimerToggle::Stopped(_, worklog)) => assert_eq!(worklog.time_spent_seconds, 60),
+            other => panic!("expected the timer to stop, got {:?}", other),
+        }
     }
 
     #[test]
-    fn jira_swap_tickets_does_nothing_when_moving_bottom_ticket_down() {
+    fn failed_timer_worklog_restarts_the_timer() {
         let dir = TempDir::new().unwrap();
-        let file_path = temp_file_path(&dir);
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        let start = Local.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        jira.toggle_timer(start);
+        jira.toggle_timer(start + TimeDelta::minutes(30));
 
-        let mut jira = get_jira_with_path(file_path);
-        jira.list_state.select(Some(1));
+        assert!(jira.restore_timer("1"));
+        assert_eq!(jira.tickets[0].timer_started, Some(start));
+        assert!(!jira.restore_timer("1"));
 
-        jira.swap_tickets(Direction::Down);
-        assert_tickets_have_not_changed(jira);
+        jira.toggle_timer(start + TimeDelta::minutes(45));
+        jira.finish_timer("1");
+        assert!(!jira.restore_timer("1"));
+        assert_eq!(jira.tickets[0].timer_started, None);
     }
 
     #[test]
-    fn jira_swap_tickets_move_bottom_ticket_up() {
+    fn toggle_timer_only_runs_on_watchlist() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.next_tab();
+
+        assert_eq!(jira.toggle_timer(Local::now()), None);
+    }
+
+    #[test]
+    fn scan_keeps_running_timer_and_it_persists() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.tickets = vec![Ticket::from(ticket_response("ABC-1"))];
+        jira.list_state.select(Some(0));
+        let start = Local.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        jira.toggle_timer(start);
+
+        assert_eq!(
+            jira.update_ticket_with_changes(ticket_response("ABC-1")),
+            None
+        );
+        assert_eq!(jira.tickets[0].timer_started, Some(start));
+
+        jira.jira_file.write_jira(&jira.tickets).unwrap();
+        let saved = jira.jira_file.read_jira().unwrap().tickets;
+        assert_eq!(saved[0].timer_started, Some(start));
+    }
+
+    #[test]
+    fn open_notes_only_on_watchlist() {
         let dir = TempDir::new().unwrap();
         let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.tickets[1].notes = "Ping QA".to_string();
         jira.list_state.select(Some(1));
+        jira.set_queries(vec!["Mine".to_string()]);
+        jira.next_tab();
+
+        jira.open_notes();
+        assert!(jira.notes.is_none());
+
+        jira.prev_tab();
+        jira.open_notes();
+        assert_eq!(jira.focus, Focus::Notes);
+        assert_eq!(jira.notes.as_ref().unwrap().notes.value(), "Ping QA");
+
+        jira.close_notes();
+        assert_eq!(jira.focus, Focus::List);
+        assert!(jira.notes.is_none());
+    }
+
+    #[test]
+    fn set_notes_updates_watched_ticket() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        let at = Local.with_ymd_and_hms(2024, 3, 1, 16, 0, 0).unwrap();
+
+        assert!(jira.set_notes("2", "Ping QA".to_string(), Some(at)));
+        assert_eq!(jira.tickets[1].notes, "Ping QA");
+        assert_eq!(jira.tickets[1].remind_at, Some(at));
+        assert!(!jira.set_notes("GONE-1", String::new(), None));
+    }
+
+    #[test]
+    fn take_due_reminders_fires_each_once() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        let now = Local.with_ymd_and_hms(2024, 3, 1, 16, 0, 0).unwrap();
+        jira.set_notes("1", "Ping QA".to_string(), Some(now));
+        jira.set_notes("2", "Later".to_string(), Some(now + TimeDelta::minutes(1)));
 
-        jira.swap_tickets(Direction::Up);
         assert_eq!(
-            jira.tickets,
-            vec![
-                Ticket {
-                    id: "2".to_string(),
-                    title: "title 2".to_string(),
-                    status: "complete".to_string(),
-                    assignee: "jane".to_string(),
-                },
-                Ticket {
-                    id: "1".to_string(),
-                    title: "title 1".to_string(),
-                    status: "in progress".to_string(),
-                    assignee: "john".to_string(),
-                }
-            ]
-        )
+            jira.take_due_reminders(now),
+            vec![("1".to_string(), "Ping QA".to_string())]
+        );
+        assert_eq!(jira.take_due_reminders(now), vec![]);
+        assert_eq!(jira.tickets[0].notes, "Ping QA");
+        assert!(jira.tickets[1].remind_at.is_some());
     }
 
     #[test]
-    fn jira_swap_tickets_move_top_ticket_down() {
+    fn scan_keeps_notes_and_they_persist() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.tickets = vec![Ticket::from(ticket_response("ABC-1"))];
+        let at = Local.with_ymd_and_hms(2024, 3, 1, 16, 0, 0).unwrap();
+        jira.set_notes("ABC-1", "Ping QA".to_string(), Some(at));
+
+        assert_eq!(
+            jira.update_ticket_with_changes(ticket_response("ABC-1")),
+            None
+        );
+        assert_eq!(jira.tickets[0].notes, "Ping QA");
+
+        jira.jira_file.write_jira(&jira.tickets).unwrap();
+        let saved = jira.jira_file.read_jira().unwrap().tickets;
+        assert_eq!(saved[0].notes, "Ping QA");
+        assert_eq!(saved[0].remind_at, Some(at));
+    }
+
+    #[test]
+    fn close_detail_returns_focus_to_list() {
         let dir = TempDir::new().unwrap();
         let mut jira = get_jira_with_path(temp_file_path(&dir));
         jira.list_state.select(Some(0));
+        jira.open_detail();
 
-        jira.swap_tickets(Direction::Down);
+        jira.close_detail();
+
+        assert_eq!(jira.focus, Focus::List);
+        assert!(jira.detail.is_none());
+    }
+
+    #[test]
+    fn set_details_only_applies_to_open_ticket() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        jira.open_detail();
+
+        jira.set_details(issue_details("2"));
+        assert_eq!(jira.detail.as_ref().unwrap().view, DetailView::Loading);
+
+        jira.set_details(issue_details("1"));
         assert_eq!(
-            jira.tickets,
-            vec![
-                Ticket {
-                    id: "2".to_string(),
-                    title: "title 2".to_string(),
-                    status: "complete".to_string(),
-                    assignee: "jane".to_string(),
-                },
-                Ticket {
-                    id: "1".to_string(),
-                    title: "title 1".to_string(),
-                    status: "in progress".to_string(),
-                    assignee: "john".to_string(),
-                }
-            ]
-        )
+            jira.detail.as_ref().unwrap().view,
+            DetailView::Loaded(Box::new(issue_details("1")))
+        );
+    }
+
+    #[test]
+    fn set_details_failed_shows_error() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        jira.open_detail();
+
+        jira.set_details_failed("1", "Not found".to_string());
+
+        assert_eq!(
+            jira.detail.unwrap().view,
+            DetailView::Failed("Not found".to_string())
+        );
+    }
+
+    #[test]
+    fn scroll_detail_stops_at_top() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+        jira.open_detail();
+
+        jira.scroll_detail(Direction::Down, 10);
+        jira.scroll_detail(Direction::Up, 3);
+        assert_eq!(jira.detail.as_ref().unwrap().scroll, 7);
+
+        jira.scroll_detail(Direction::Up, 10);
+        assert_eq!(jira.detail.as_ref().unwrap().scroll, 0);
+    }
+
+    #[test]
+    fn start_scan_returns_every_watchlist_ticket() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+
+        let mut ids = jira.start_scan(Instant::now()).unwrap();
+        ids.sort();
+
+        assert_eq!(ids, vec!["1", "2"]);
+        assert!(jira.is_scanning());
+    }
+
+    #[test]
+    fn start_scan_refuses_while_running_or_empty() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.start_scan(Instant::now());
+        assert_eq!(jira.start_scan(Instant::now()), None);
+
+        let mut empty = Jira::new_empty(JiraFile::new_from_path(temp_file_path(&dir)));
+        assert_eq!(empty.start_scan(Instant::now()), None);
+        assert!(!empty.is_scanning());
+    }
+
+    #[test]
+    fn finish_scan_ticket_summarises_after_last_result() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.start_scan(Instant::now());
+
+        assert_eq!(jira.finish_scan_ticket("1", Ok(())), None);
+        let summary = jira.finish_scan_ticket("2", Err("Issue does not exist".to_string()));
+
+        assert_eq!(
+            summary,
+            Some(ScanSummary {
+                total: 2,
+                failed: vec![("2".to_string(), "Issue does not exist".to_string())],
+            })
+        );
+        assert!(!jira.is_scanning());
+        assert_eq!(
+            jira.scan_errors,
+            HashMap::from([("2".to_string(), "Issue does not exist".to_string())])
+        );
+    }
+
+    #[test]
+    fn finish_scan_ticket_clears_previous_error() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.scan_errors
+            .insert("1".to_string(), "Timed out".to_string());
+
+        jira.finish_scan_ticket("1", Ok(()));
+
+        assert!(jira.scan_errors.is_empty());
+    }
+
+    #[test]
+    fn expire_scan_waits_for_timeout() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        let started = Instant::now();
+        jira.start_scan(started);
+
+        assert_eq!(jira.expire_scan(started + Duration::from_secs(1)), None);
+        assert!(jira.is_scanning());
+    }
+
+    #[test]
+    fn expire_scan_fails_outstanding_tickets() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        let started = Instant::now();
+        jira.start_scan(started);
+        jira.finish_scan_ticket("1", Ok(()));
+
+        let summary = jira.expire_scan(started + SCAN_TIMEOUT).unwrap();
+
+        assert_eq!(summary.total, 2);
+        assert_eq!(
+            summary.failed,
+            vec![("2".to_string(), "Timed out".to_string())]
+        );
+        assert!(!jira.is_scanning());
+        assert!(jira.scan_errors.contains_key("2"));
+        // A new scan can start once the stuck one is expired.
+        assert!(jira.start_scan(started + SCAN_TIMEOUT).is_some());
+    }
+
+    #[test]
+    fn remove_ticket_clears_scan_error() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.scan_errors
+            .insert("1".to_string(), "Timed out".to_string());
+        jira.list_state.select(Some(0));
+
+        jira.remove_ticket();
+
+        assert!(jira.scan_errors.is_empty());
+    }
+
+    fn archive_done(jira: &mut Jira) -> Vec<(String, String)> {
+        jira.tickets[1].status_category = StatusCategory::Done;
+        jira.archive_finished(&JiraArchiveConfig::default(), Local::now())
+    }
+
+    #[test]
+    fn archive_finished_moves_done_tickets_to_the_archive_tab() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.scan_errors
+            .insert("2".to_string(), "Timed out".to_string());
+        jira.list_state.select(Some(1));
+
+        let archived = archive_done(&mut jira);
+
+        assert_eq!(
+            archived,
+            vec![("2".to_string(), "reached complete".to_string())]
+        );
+        assert_eq!(jira.tickets.len(), 1);
+        assert_eq!(jira.list_state.selected(), Some(0));
+        assert!(jira.scan_errors.is_empty());
+        assert!(jira.archive.tickets[0].archived_at.is_some());
+
+        jira.next_tab();
+        assert!(jira.is_archive_tab());
+        jira.move_selection(Direction::Down);
+        assert_eq!(jira.selected_ticket().unwrap().id, "2");
+    }
+
+    #[test]
+    fn restore_selected_holds_the_ticket_in_its_status() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        archive_done(&mut jira);
+        jira.next_tab();
+        jira.archive.list_state.select(Some(0));
+
+        assert_eq!(jira.restore_selected(), Some("2".to_string()));
+
+        assert!(jira.archive.tickets.is_empty());
+        assert!(jira.is_watchlist_tab());
+        assert_eq!(jira.tickets[1].held_in, "complete");
+        assert_eq!(jira.tickets[1].archived_at, None);
+        assert!(archive_done(&mut jira).is_empty());
+        assert_eq!(jira.restore_selected(), None);
+    }
+
+    #[test]
+    fn adding_an_archived_ticket_takes_it_out_of_the_archive() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.archive
+            .add(Ticket::from(ticket_response("A-1")), Local::now());
+
+        jira.add_ticket("", ticket_response("A-1"));
+
+        assert!(jira.archive.tickets.is_empty());
+        assert_eq!(jira.tickets[2].held_in, "To Do");
+    }
+
+    #[test]
+    fn archive_tab_keeps_its_own_filter() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        archive_done(&mut jira);
+        jira.next_tab();
+
+        jira.adding_ticket = true;
+        jira.ticket_input = TicketInput::Filter;
+        jira.new_ticket_id = TextField::new("nothing like it".to_string());
+        jira.apply_filter();
+
+        assert_eq!(jira.archive.filter, "nothing like it");
+        assert!(jira.rows().is_empty());
+        jira.prev_tab();
+        assert_eq!(jira.saved_filter(), "");
+        assert_eq!(jira.rows().len(), 1);
     }
 
     fn assert_tickets_have_not_changed(jira: Jira) {
@@ -424,14 +2602,81 @@ mod tests {
                     title: "title 1".to_string(),
                     status: "in progress".to_string(),
                     assignee: "john".to_string(),
+                    ..Default::default()
                 },
                 Ticket {
                     id: "2".to_string(),
                     title: "title 2".to_string(),
                     status: "complete".to_string(),
                     assignee: "jane".to_string(),
+                    ..Default::default()
                 }
             ]
         )
     }
+
+    fn board_configuration() -> BoardConfiguration {
+        let column = |name: &str, id: &str| BoardColumn {
+            name: name.to_string(),
+            statuses: vec![StatusRef { id: id.to_string() }],
+        };
+        BoardConfiguration {
+            name: "Team board".to_string(),
+            column_config: ColumnConfig {
+                columns: vec![column("To Do", "1"), column("Done", "6")],
+            },
+        }
+    }
+
+    #[test]
+    fn board_keeps_focus_when_a_pane_closes() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.list_state.select(Some(0));
+
+        jira.open_board(7);
+        assert_eq!(jira.focus, Focus::Board);
+        jira.open_detail();
+        jira.close_detail();
+        assert_eq!(jira.focus, Focus::Board);
+
+        jira.close_board();
+        assert_eq!(jira.focus, Focus::List);
+        assert!(jira.board.is_none());
+    }
+
+    #[test]
+    fn set_board_ignores_a_board_that_was_closed() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        jira.open_board(7);
+        jira.set_board(8, board_configuration());
+        assert_eq!(jira.board.as_ref().unwrap().view, BoardView::Loading);
+
+        jira.set_board(7, board_configuration());
+        assert_eq!(jira.board.as_ref().unwrap().columns().len(), 2);
+    }
+
+    #[test]
+    fn card_move_targets_the_neighbouring_column() {
+        let dir = TempDir::new().unwrap();
+        let mut jira = get_jira_with_path(temp_file_path(&dir));
+        for (ticket, status_id) in jira.tickets.iter_mut().zip(["1", "6"]) {
+            ticket.status_id = status_id.to_string();
+        }
+        jira.open_board(7);
+        jira.set_board(7, board_configuration());
+
+        jira.board_select(BoardStep::Down);
+        assert_eq!(jira.selected_ticket().unwrap().id, "1");
+        assert_eq!(jira.start_card_move(BoardStep::Left), None);
+        assert_eq!(
+            jira.start_card_move(BoardStep::Right),
+            Some("1".to_string())
+        );
+
+        assert_eq!(jira.take_card_move("2"), None);
+        assert_eq!(jira.take_card_move("1").unwrap().name, "Done");
+        assert_eq!(jira.take_card_move("1"), None, "a move is only taken once");
+    }
 }
@@ -0,0 +1,177 @@
+use crate::client::jira::models::StatusCategory;
+use crate::config::model::JiraArchiveConfig;
+use crate::state::jira::Ticket;
+use chrono::{DateTime, Local};
+use ratatui::widgets::ListState;
+
+/// Finished tickets moved off the watchlist, most recently archived first. They
+/// keep the details they had when archived and aren't scanned.
+#[derive(Clone, Default)]
+pub struct Archive {
+    pub tickets: Vec<Ticket>,
+    pub list_state: ListState,
+    /// Narrows the archive to tickets matching every word, as the watchlist filter does.
+    pub filter: String,
+}
+
+impl Archive {
+    pub fn new(tickets: Vec<Ticket>) -> Self {
+        Self {
+            tickets,
+            ..Default::default()
+        }
+    }
+
+    /// Files `ticket` at the top of the archive, replacing any older copy of it.
+    /// The selection stays on the ticket it was on.
+    pub fn add(&mut self, mut ticket: Ticket, now: DateTime<Local>) {
+        let selected = self.selected_id();
+        self.tickets.retain(|t| t.id != ticket.id);
+        ticket.archived_at = Some(now);
+        self.tickets.insert(0, ticket);
+        self.list_state
+            .select(selected.and_then(|id| self.tickets.iter().position(|t| t.id == id)));
+    }
+
+    /// Takes ticket `i` out of the archive, moving the selection to its neighbour.
+    pub fn take(&mut self, i: usize) -> Option<Ticket> {
+        if i >= self.tickets.len() {
+            return None;
+        }
+        let mut ticket = self.tickets.remove(i);
+        ticket.archived_at = None;
+        let last = self.tickets.len().checked_sub(1);
+        self.list_state.select(last.map(|last| i.min(last)));
+        Some(ticket)
+    }
+
+    /// Drops `id` from the archive, e.g. once it is back on the watchlist.
+    pub fn remove(&mut self, id: &str) {
+        if let Some(i) = self.tickets.iter().position(|t| t.id == id) {
+            let selected = self.selected_id().filter(|selected| selected != id);
+            self.tickets.remove(i);
+            self.list_state
+                .select(selected.and_then(|id| self.tickets.iter().position(|t| t.id == id)));
+        }
+    }
+
+    fn selected_id(&self) -> Option<String> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.tickets.get(i))
+            .map(|t| t.id.clone())
+    }
+}
+
+/// Why `ticket` should be archived by `now`, if it should. A ticket with a running
+/// timer or a reminder to come stays, as does one still in the status it was added
+/// or restored in.
+pub fn archive_reason(
+    ticket: &Ticket,
+    config: &JiraArchiveConfig,
+    now: DateTime<Local>,
+) -> Option<String> {
+    if ticket.timer_started.is_some()
+        || ticket.remind_at.is_some()
+        || (!ticket.held_in.is_empty() && ticket.held_in == ticket.status)
+    {
+        return None;
+    }
+    if config.done && ticket.status_category == StatusCategory::Done {
+        return Some(format!("reached {}", ticket.status));
+    }
+    let days = config.days_in(&ticket.status)?;
+    let stayed = (now.fixed_offset() - ticket.status_since?).num_days();
+    (stayed >= i64::from(days)).then(|| format!("{} days in {}", stayed, ticket.status))
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeDelta, TimeZone};
+    use std::collections::BTreeMap;
+    use test_case::test_case;
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap()
+    }
+
+    fn config() -> JiraArchiveConfig {
+        JiraArchiveConfig {
+            done: true,
+            after_days: BTreeMap::from([("Ready for release".to_string(), 14)]),
+        }
+    }
+
+    fn ticket(id: &str, status: &str, category: StatusCategory, days_in_status: i64) -> Ticket {
+        Ticket {
+            id: id.to_string(),
+            status: status.to_string(),
+            status_category: category,
+            status_since: Some((now() - TimeDelta::days(days_in_status)).fixed_offset()),
+            ..Default::default()
+        }
+    }
+
+    #[test_case(ticket("A-1", "Closed", StatusCategory::Done, 0), Some("reached Closed"); "done category")]
+    #[test_case(ticket("A-1", "Ready for release", StatusCategory::InProgress, 20), Some("20 days in Ready for release"); "long enough in a configured status")]
+    #[test_case(ticket("A-1", "Ready for release", StatusCategory::InProgress, 3), None; "not long enough yet")]
+    #[test_case(ticket("A-1", "In Progress", StatusCategory::InProgress, 90), None; "status not configured")]
+    #[test_case(Ticket { status_since: None, ..ticket("A-1", "Ready for release", StatusCategory::InProgress, 0) }, None; "unknown time in status")]
+    #[test_case(Ticket { held_in: "Closed".to_string(), ..ticket("A-1", "Closed", StatusCategory::Done, 0) }, None; "held in the status it was added in")]
+    #[test_case(Ticket { held_in: "To Do".to_string(), ..ticket("A-1", "Closed", StatusCategory::Done, 0) }, Some("reached Closed"); "moved on from the held status")]
+    #[test_case(Ticket { timer_started: Some(now()), ..ticket("A-1", "Closed", StatusCategory::Done, 0) }, None; "timer running")]
+    #[test_case(Ticket { remind_at: Some(now()), ..ticket("A-1", "Closed", StatusCategory::Done, 0) }, None; "reminder set")]
+    fn archive_reason_follows_config(ticket: Ticket, expected: Option<&str>) {
+        assert_eq!(
+            archive_reason(&ticket, &config(), now()).as_deref(),
+            expected
+        );
+    }
+
+    #[test]
+    fn archive_reason_skips_done_when_turned_off() {
+        let config = JiraArchiveConfig {
+            done: false,
+            ..config()
+        };
+        let closed = ticket("A-1", "Closed", StatusCategory::Done, 0);
+        assert_eq!(archive_reason(&closed, &config, now()), None);
+    }
+
+    #[test]
+    fn add_files_newest_first_and_keeps_the_selection() {
+        let mut archive = Archive::new(vec![ticket("A-1", "Closed", StatusCategory::Done, 0)]);
+        archive.list_state.select(Some(0));
+        archive.add(ticket("A-2", "Closed", StatusCategory::Done, 0), now());
+        archive.add(ticket("A-1", "Closed", StatusCategory::Done, 0), now());
+
+        let ids = archive
+            .tickets
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["A-1", "A-2"]);
+        assert_eq!(archive.tickets[0].archived_at, Some(now()));
+        assert_eq!(archive.list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn take_clears_archived_at_and_selects_a_neighbour() {
+        let mut archive = Archive::default();
+        archive.add(ticket("A-1", "Closed", StatusCategory::Done, 0), now());
+        archive.add(ticket("A-2", "Closed", StatusCategory::Done, 0), now());
+        archive.list_state.select(Some(1));
+
+        let taken = archive.take(1).unwrap();
+        assert_eq!(taken.id, "A-1");
+        assert_eq!(taken.archived_at, None);
+        assert_eq!(archive.list_state.selected(), Some(0));
+
+        archive.take(0);
+        assert_eq!(archive.list_state.selected(), None);
+        assert_eq!(archive.take(0), None);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::client::jira::models::BoardConfiguration;
+use crate::state::jira::Ticket;
+
+/// A step across the board: up and down within a column, left and right between them.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BoardStep {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A board column and the status IDs that put a card in it.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Column {
+    pub name: String,
+    pub status_ids: Vec<String>,
+}
+
+impl Column {
+    pub fn holds(&self, ticket: &Ticket) -> bool {
+        self.status_ids.contains(&ticket.status_id)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum BoardView {
+    Loading,
+    Loaded { name: String, columns: Vec<Column> },
+    Failed(String),
+}
+
+/// The board view over the current tab's tickets. The highlighted card is the
+/// tab's own selection, so every ticket action works on it as it does in the list.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Board {
+    pub id: u64,
+    pub view: BoardView,
+    /// A card waiting on its transitions: the ticket and the column it is going to.
+    pub pending_move: Option<(String, usize)>,
+}
+
+impl Board {
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            view: BoardView::Loading,
+            pending_move: None,
+        }
+    }
+
+    pub fn set_configuration(&mut self, configuration: BoardConfiguration) {
+        let columns = configuration
+            .column_config
+            .columns
+            .into_iter()
+            .map(|column| Column {
+                name: column.name,
+                status_ids: column.statuses.into_iter().map(|s| s.id).collect(),
+            })
+            .collect();
+        self.view = BoardView::Loaded {
+            name: configuration.name,
+            columns,
+        };
+    }
+
+    /// The columns, once the board has loaded.
+    pub fn columns(&self) -> &[Column] {
+        match &self.view {
+            BoardView::Loaded { columns, .. } => columns,
+            _ => &[],
+        }
+    }
+
+    /// The cards in each column, as indices into `tickets` in list order. Tickets
+    /// whose status no column holds are left off the board.
+    pub fn layout(&self, tickets: &[Ticket]) -> Vec<Vec<usize>> {
+        self.columns()
+            .iter()
+            .map(|column| {
+                tickets
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, ticket)| column.holds(ticket))
+                    .map(|(i, _)| i)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// The column and row of ticket `selected` on the board.
+pub fn position(layout: &[Vec<usize>], selected: usize) -> Option<(usize, usize)> {
+    layout.iter().enumerate().find_map(|(column, cards)| {
+        cards
+            .iter()
+            .position(|&i| i == selected)
+            .map(|row| (column, row))
+    })
+}
+
+/// The ticket to select after `step`. Sideways steps skip empty columns and keep
+/// to the same row where they can; with nothing on the board selected, the first
+/// card is.
+pub fn step(layout: &[Vec<usize>], selected: Option<usize>, step: BoardStep) -> Option<usize> {
+    let Some((column, row)) = selected.and_then(|s| position(layout, s)) else {
+        return layout.iter().flatten().next().copied();
+    };
+    let nearest = |cards: &Vec<usize>| cards.get(row.min(cards.len().checked_sub(1)?)).copied();
+    match step {
+        BoardStep::Up => layout[column].get(row.checked_sub(1)?).copied(),
+        BoardStep::Down => layout[column].get(row + 1).copied(),
+        BoardStep::Left => layout[..column].iter().rev().find_map(nearest),
+        BoardStep::Right => layout[column + 1..].iter().find_map(nearest),
+    }
+}
+
+/// The column a sideways move takes the selected card to, empty or not.
+pub fn target_column(
+    layout: &[Vec<usize>],
+    selected: Option<usize>,
+    step: BoardStep,
+) -> Option<usize> {
+    let (column, _) = position(layout, selected?)?;
+    match step {
+        BoardStep::Left => column.checked_sub(1),
+        BoardStep::Right => Some(column + 1).filter(|&c| c < layout.len()),
+        BoardStep::Up | BoardStep::Down => None,
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::jira::models::{BoardColumn, ColumnConfig, StatusRef};
+    use test_case::test_case;
+
+    fn board() -> Board {
+        let mut board = Board::new(7);
+        let column = |name: &str, ids: &[&str]| BoardColumn {
+            name: name.to_string(),
+            statuses: ids
+                .iter()
+                .map(|id| StatusRef { id: id.to_string() })
+                .collect(),
+        };
+        board.set_configuration(BoardConfiguration {
+            name: "Team board".to_string(),
+            column_config: ColumnConfig {
+                columns: vec![
+                    column("To Do", &["1"]),
+                    column("In Progress", &["3", "4"]),
+                    column("Review", &["5"]),
+                    column("Done", &["6"]),
+                ],
+            },
+        });
+        board
+    }
+
+    fn ticket(id: &str, status_id: &str) -> Ticket {
+        Ticket {
+            id: id.to_string(),
+            status_id: status_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// To Do: 0, 3 · In Progress: 1, 2, 5 · Review: (empty) · Done: 4. Ticket 6 isn't on the board.
+    fn layout() -> Vec<Vec<usize>> {
+        let tickets = [
+            ticket("A-0", "1"),
+            ticket("A-1", "3"),
+            ticket("A-2", "4"),
+            ticket("A-3", "1"),
+            ticket("A-4", "6"),
+            ticket("A-5", "3"),
+            ticket("A-6", "99"),
+        ];
+        board().layout(&tickets)
+    }
+
+    #[test]
+    fn layout_groups_tickets_by_column_status() {
+        assert_eq!(layout(), vec![vec![0, 3], vec![1, 2, 5], vec![], vec![4]]);
+        assert_eq!(board().columns()[1].name, "In Progress");
+    }
+
+    #[test]
+    fn layout_is_empty_until_loaded() {
+        assert!(Board::new(7).layout(&[ticket("A-0", "1")]).is_empty());
+    }
+
+    #[test_case(Some(0), BoardStep::Down, Some(3); "down the column")]
+    #[test_case(Some(3), BoardStep::Down, None; "bottom of the column")]
+    #[test_case(Some(2), BoardStep::Up, Some(1); "up the column")]
+    #[test_case(Some(0), BoardStep::Up, None; "top of the column")]
+    #[test_case(Some(3), BoardStep::Right, Some(2); "right keeps the row")]
+    #[test_case(Some(5), BoardStep::Left, Some(3); "left to a shorter column")]
+    #[test_case(Some(5), BoardStep::Right, Some(4); "right skips the empty column")]
+    #[test_case(Some(4), BoardStep::Right, None; "right of the last column")]
+    #[test_case(None, BoardStep::Down, Some(0); "nothing selected starts at the first card")]
+    #[test_case(Some(6), BoardStep::Left, Some(0); "selection off the board starts at the first card")]
+    fn step_moves_selection(
+        selected: Option<usize>,
+        direction: BoardStep,
+        expected: Option<usize>,
+    ) {
+        assert_eq!(step(&layout(), selected, direction), expected);
+    }
+
+    #[test_case(Some(5), BoardStep::Right, Some(2); "into an empty column")]
+    #[test_case(Some(3), BoardStep::Left, None; "left of the first column")]
+    #[test_case(Some(4), BoardStep::Right, None; "right of the last column")]
+    #[test_case(Some(1), BoardStep::Down, None; "not sideways")]
+    #[test_case(Some(6), BoardStep::Right, None; "not on the board")]
+    fn target_column_is_the_neighbour(
+        selected: Option<usize>,
+        direction: BoardStep,
+        expected: Option<usize>,
+    ) {
+        assert_eq!(target_column(&layout(), selected, direction), expected);
+    }
+}
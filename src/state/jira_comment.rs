@@ -0,0 +1,79 @@
+use tui_text_field::TextField;
+
+// ── Comment form ──────────────────────────────────────────────────────────────
+#[derive(Clone)]
+pub struct CommentForm {
+    pub ticket_id: String,
+    /// The comment text; Enter inserts a newline, so it can span several lines.
+    pub body: TextField,
+    /// Set while the comment is being posted, so it can't be sent twice.
+    pub submitting: bool,
+    /// Why the last post failed; the form stays open so the text isn't lost.
+    pub error: Option<String>,
+}
+
+impl CommentForm {
+    pub fn new(ticket_id: String) -> Self {
+        Self {
+            ticket_id,
+            body: TextField::empty(),
+            submitting: false,
+            error: None,
+        }
+    }
+
+    /// The comment to post, without leading or trailing blank lines.
+    pub fn to_text(&self) -> Result<String, String> {
+        let text = self.body.value().trim();
+        if text.is_empty() {
+            return Err("Comment is empty".to_string());
+        }
+        Ok(text.to_string())
+    }
+
+    /// The cursor's line and column (in characters) within the body.
+    pub fn cursor_line_col(&self) -> (usize, usize) {
+        let before = &self.body.value()[..self.body.cursor()];
+        let line = before.matches('\n').count();
+        let col = before
+            .rsplit_once('\n')
+            .map_or(before, |(_, current)| current)
+            .chars()
+            .count();
+        (line, col)
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form(body: &str) -> CommentForm {
+        let mut form = CommentForm::new("ABC-1".to_string());
+        form.body = TextField::new(body.to_string());
+        form
+    }
+
+    #[test]
+    fn to_text_trims_surrounding_whitespace() {
+        assert_eq!(
+            form("\n Deployed\nto staging \n\n").to_text(),
+            Ok("Deployed\nto staging".to_string())
+        );
+    }
+
+    #[test]
+    fn to_text_rejects_blank_comment() {
+        assert_eq!(form(" \n ").to_text(), Err("Comment is empty".to_string()));
+    }
+
+    #[test]
+    fn cursor_line_col_counts_lines_and_characters() {
+        assert_eq!(form("").cursor_line_col(), (0, 0));
+        assert_eq!(form("héllo").cursor_line_col(), (0, 5));
+        assert_eq!(form("one\ntwo\nthré").cursor_line_col(), (2, 4));
+        assert_eq!(form("one\n").cursor_line_col(), (1, 0));
+    }
+}
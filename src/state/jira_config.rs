@@ -129,6 +129,7 @@ mod tests {
             url: "https://jira.example.com".to_string(),
             email: "user@example.com".to_string(),
             token: "secret".to_string(),
+            ..Default::default()
         };
         let form = JiraConfigForm::from_existing(&cfg);
         assert_eq!(form.url.value(), "https://jira.example.com");
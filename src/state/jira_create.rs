@@ -0,0 +1,224 @@
+use crate::client::jira::models::{Assignment, NewIssue};
+use tui_text_field::TextField;
+
+/// The issue type a new ticket starts with; most release follow-ups are tasks.
+const DEFAULT_ISSUE_TYPE: &str = "Task";
+/// Typed into the assignee field to assign the ticket to yourself.
+pub const ASSIGN_TO_ME: &str = "me";
+
+// ── Field enum ────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CreateField {
+    Project,
+    IssueType,
+    Summary,
+    Description,
+    Assignee,
+    Labels,
+}
+
+impl CreateField {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Project => Self::IssueType,
+            Self::IssueType => Self::Summary,
+            Self::Summary => Self::Description,
+            Self::Description => Self::Assignee,
+            Self::Assignee => Self::Labels,
+            Self::Labels => Self::Project,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            Self::Project => Self::Labels,
+            Self::IssueType => Self::Project,
+            Self::Summary => Self::IssueType,
+            Self::Description => Self::Summary,
+            Self::Assignee => Self::Description,
+            Self::Labels => Self::Assignee,
+        }
+    }
+}
+
+// ── New issue form ────────────────────────────────────────────────────────────
+#[derive(Clone)]
+pub struct CreateIssueForm {
+    pub project: TextField,
+    pub issue_type: TextField,
+    pub summary: TextField,
+    pub description: TextField,
+    pub assignee: TextField,
+    pub labels: TextField,
+    pub active_field: CreateField,
+    /// Set while the create request is in flight, so Enter can't submit twice.
+    pub submitting: bool,
+    /// Why the last submit was rejected; the form stays open to fix it.
+    pub error: Option<String>,
+}
+
+impl CreateIssueForm {
+    /// A blank form in `project`, assigned to the current user. The cursor starts
+    /// on the summary when the project is already known.
+    pub fn new(project: &str) -> Self {
+        Self {
+            project: TextField::new(project.to_string()),
+            issue_type: TextField::new(DEFAULT_ISSUE_TYPE.to_string()),
+            summary: TextField::empty(),
+            description: TextField::empty(),
+            assignee: TextField::new(ASSIGN_TO_ME.to_string()),
+            labels: TextField::empty(),
+            active_field: if project.is_empty() {
+                CreateField::Project
+            } else {
+                CreateField::Summary
+            },
+            submitting: false,
+            error: None,
+        }
+    }
+
+    pub fn active_field(&self) -> &TextField {
+        match self.active_field {
+            CreateField::Project => &self.project,
+            CreateField::IssueType => &self.issue_type,
+            CreateField::Summary => &self.summary,
+            CreateField::Description => &self.description,
+            CreateField::Assignee => &self.assignee,
+            CreateField::Labels => &self.labels,
+        }
+    }
+
+    pub fn active_field_mut(&mut self) -> &mut TextField {
+        match self.active_field {
+            CreateField::Project => &mut self.project,
+            CreateField::IssueType => &mut self.issue_type,
+            CreateField::Summary => &mut self.summary,
+            CreateField::Description => &mut self.description,
+            CreateField::Assignee => &mut self.assignee,
+            CreateField::Labels => &mut self.labels,
+        }
+    }
+
+    /// Validates the form into a create request. A blank assignee leaves the ticket
+    /// unassigned; labels are separated by commas or spaces.
+    pub fn to_new_issue(&self) -> Result<NewIssue, String> {
+        let project = self.project.value().trim().to_ascii_uppercase();
+        let issue_type = self.issue_type.value().trim().to_string();
+        let summary = self.summary.value().trim().to_string();
+        let missing = [
+            ("Project", project.is_empty()),
+            ("Type", issue_type.is_empty()),
+            ("Summary", summary.is_empty()),
+        ]
+        .iter()
+        .filter(|(_, empty)| *empty)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(format!("Required: {}", missing.join(", ")));
+        }
+
+        let assignee = match self.assignee.value().trim() {
+            "" => Assignment::Unassigned,
+            me if me.eq_ignore_ascii_case(ASSIGN_TO_ME) => Assignment::Me,
+            user => Assignment::User(user.to_string()),
+        };
+        let labels = self
+            .labels
+            .value()
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        Ok(NewIssue {
+            project,
+            issue_type,
+            summary,
+            description: self.description.value().trim().to_string(),
+            assignee,
+            labels,
+        })
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn create_field_next_wraps() {
+        assert_eq!(CreateField::Labels.next(), CreateField::Project);
+        assert_eq!(CreateField::Project.next(), CreateField::IssueType);
+    }
+
+    #[test]
+    fn create_field_prev_wraps() {
+        assert_eq!(CreateField::Project.prev(), CreateField::Labels);
+        assert_eq!(CreateField::Summary.prev(), CreateField::IssueType);
+    }
+
+    #[test]
+    fn new_form_defaults_type_and_assignee() {
+        let form = CreateIssueForm::new("OPS");
+        assert_eq!(form.project.value(), "OPS");
+        assert_eq!(form.issue_type.value(), "Task");
+        assert_eq!(form.assignee.value(), "me");
+        assert_eq!(form.active_field, CreateField::Summary);
+    }
+
+    #[test]
+    fn new_form_without_project_starts_on_project() {
+        let form = CreateIssueForm::new("");
+        assert_eq!(form.active_field, CreateField::Project);
+    }
+
+    #[test]
+    fn to_new_issue_reads_all_fields() {
+        let mut form = CreateIssueForm::new("ops");
+        form.summary = TextField::new(" Rotate keys ".to_string());
+        form.description = TextField::new("Before Friday".to_string());
+        form.labels = TextField::new("release, follow-up  infra".to_string());
+
+        assert_eq!(
+            form.to_new_issue(),
+            Ok(NewIssue {
+                project: "OPS".to_string(),
+                issue_type: "Task".to_string(),
+                summary: "Rotate keys".to_string(),
+                description: "Before Friday".to_string(),
+                assignee: Assignment::Me,
+                labels: vec![
+                    "release".to_string(),
+                    "follow-up".to_string(),
+                    "infra".to_string()
+                ],
+            })
+        );
+    }
+
+    #[test_case("", Assignment::Unassigned; "blank is unassigned")]
+    #[test_case("ME", Assignment::Me; "me is case insensitive")]
+    #[test_case("5b10ac", Assignment::User("5b10ac".to_string()); "anything else is a user")]
+    fn to_new_issue_reads_assignee(value: &str, expected: Assignment) {
+        let mut form = CreateIssueForm::new("OPS");
+        form.summary = TextField::new("Summary".to_string());
+        form.assignee = TextField::new(value.to_string());
+        assert_eq!(form.to_new_issue().unwrap().assignee, expected);
+    }
+
+    #[test]
+    fn to_new_issue_lists_missing_required_fields() {
+        let mut form = CreateIssueForm::new("");
+        form.issue_type = TextField::empty();
+        assert_eq!(
+            form.to_new_issue(),
+            Err("Required: Project, Type, Summary".to_string())
+        );
+    }
+}
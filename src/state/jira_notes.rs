@@ -0,0 +1,197 @@
+use crate::state::jira::Ticket;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use tui_text_field::TextField;
+
+/// How reminders are shown and typed in full.
+pub const REMIND_AT_FORMAT: &str = "%Y-%m-%d %H:%M";
+/// When a reminder given only a date goes off.
+const DEFAULT_REMIND_TIME: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).expect("valid time");
+
+// ── Field enum ────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NotesField {
+    Notes,
+    RemindAt,
+}
+
+impl NotesField {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Notes => Self::RemindAt,
+            Self::RemindAt => Self::Notes,
+        }
+    }
+}
+
+// ── Notes form ────────────────────────────────────────────────────────────────
+#[derive(Clone)]
+pub struct NotesForm {
+    pub ticket_id: String,
+    pub notes: TextField,
+    pub remind_at: TextField,
+    pub active_field: NotesField,
+    /// Why the last save was rejected; the form stays open to fix it.
+    pub error: Option<String>,
+}
+
+impl NotesForm {
+    /// The form for `ticket`, filled with its current notes and reminder.
+    pub fn new(ticket: &Ticket) -> Self {
+        Self {
+            ticket_id: ticket.id.clone(),
+            notes: TextField::new(ticket.notes.clone()),
+            remind_at: TextField::new(
+                ticket
+                    .remind_at
+                    .map(|at| at.format(REMIND_AT_FORMAT).to_string())
+                    .unwrap_or_default(),
+            ),
+            active_field: NotesField::Notes,
+            error: None,
+        }
+    }
+
+    pub fn active_field(&self) -> &TextField {
+        match self.active_field {
+            NotesField::Notes => &self.notes,
+            NotesField::RemindAt => &self.remind_at,
+        }
+    }
+
+    pub fn active_field_mut(&mut self) -> &mut TextField {
+        match self.active_field {
+            NotesField::Notes => &mut self.notes,
+            NotesField::RemindAt => &mut self.remind_at,
+        }
+    }
+
+    /// The notes and reminder to save. A reminder needs something to remind about.
+    pub fn to_notes(
+        &self,
+        now: DateTime<Local>,
+    ) -> Result<(String, Option<DateTime<Local>>), String> {
+        let notes = self.notes.value().trim().to_string();
+        let remind_at = parse_remind_at(self.remind_at.value(), now)?;
+        if remind_at.is_some() && notes.is_empty() {
+            return Err("Add a note for the reminder".to_string());
+        }
+        Ok((notes, remind_at))
+    }
+}
+
+/// Reads a reminder time: blank for none, `HH:MM` for the next time the clock
+/// reads that, `YYYY-MM-DD` for 09:00 that day, or `YYYY-MM-DD HH:MM`.
+pub fn parse_remind_at(
+    input: &str,
+    now: DateTime<Local>,
+) -> Result<Option<DateTime<Local>>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    let at = if let Ok(time) = NaiveTime::parse_from_str(input, "%H:%M") {
+        let today = local(now.date_naive().and_time(time))?;
+        if today > now {
+            today
+        } else {
+            local((now.date_naive() + TimeDelta::days(1)).and_time(time))?
+        }
+    } else if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        local(date.and_time(DEFAULT_REMIND_TIME))?
+    } else if let Ok(date_time) = NaiveDateTime::parse_from_str(input, REMIND_AT_FORMAT) {
+        local(date_time)?
+    } else {
+        return Err(format!(
+            "Not a time: {} (use HH:MM or YYYY-MM-DD HH:MM)",
+            input
+        ));
+    };
+
+    if at <= now {
+        return Err(format!("{} has already passed", input));
+    }
+    Ok(Some(at))
+}
+
+fn local(date_time: NaiveDateTime) -> Result<DateTime<Local>, String> {
+    date_time
+        .and_local_timezone(Local)
+        .earliest()
+        .ok_or_else(|| format!("No such local time: {}", date_time))
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use test_case::test_case;
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 3, 1, 15, 0, 0).unwrap()
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2024, 3, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test_case("16:30", at(1, 16, 30); "later today")]
+    #[test_case("09:15", at(2, 9, 15); "time already passed today is tomorrow")]
+    #[test_case("2024-03-04", at(4, 9, 0); "date alone is the morning")]
+    #[test_case(" 2024-03-04 13:45 ", at(4, 13, 45); "date and time")]
+    fn parse_remind_at_accepts(input: &str, expected: DateTime<Local>) {
+        assert_eq!(parse_remind_at(input, now()), Ok(Some(expected)));
+    }
+
+    #[test]
+    fn parse_remind_at_blank_is_no_reminder() {
+        assert_eq!(parse_remind_at("  ", now()), Ok(None));
+    }
+
+    #[test_case("tomorrow"; "not a time")]
+    #[test_case("2024-03-01 14:00"; "earlier today")]
+    #[test_case("2024-02-28"; "past date")]
+    fn parse_remind_at_rejects(input: &str) {
+        assert!(parse_remind_at(input, now()).is_err());
+    }
+
+    #[test]
+    fn new_form_shows_existing_notes_and_reminder() {
+        let ticket = Ticket {
+            id: "ABC-1".to_string(),
+            notes: "Ping QA".to_string(),
+            remind_at: Some(at(4, 9, 0)),
+            ..Default::default()
+        };
+        let form = NotesForm::new(&ticket);
+        assert_eq!(form.notes.value(), "Ping QA");
+        assert_eq!(form.remind_at.value(), "2024-03-04 09:00");
+        assert_eq!(form.active_field, NotesField::Notes);
+    }
+
+    #[test]
+    fn to_notes_reads_both_fields() {
+        let mut form = NotesForm::new(&Ticket::default());
+        form.notes = TextField::new(" Ping QA after deploy ".to_string());
+        form.remind_at = TextField::new("16:00".to_string());
+        assert_eq!(
+            form.to_notes(now()),
+            Ok(("Ping QA after deploy".to_string(), Some(at(1, 16, 0))))
+        );
+    }
+
+    #[test]
+    fn to_notes_requires_a_note_for_a_reminder() {
+        let mut form = NotesForm::new(&Ticket::default());
+        form.remind_at = TextField::new("16:00".to_string());
+        assert_eq!(
+            form.to_notes(now()),
+            Err("Add a note for the reminder".to_string())
+        );
+    }
+}
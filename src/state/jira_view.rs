@@ -0,0 +1,335 @@
+use crate::client::jira::models::StatusCategory;
+use crate::state::jira::Ticket;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Priority names from most to least urgent, covering Jira Cloud's defaults and
+/// the older Server scheme. Anything else sorts after them.
+const PRIORITIES: [&str; 10] = [
+    "highest", "blocker", "critical", "high", "major", "medium", "low", "minor", "trivial",
+    "lowest",
+];
+
+// ── Sort and group modes ──────────────────────────────────────────────────────
+
+/// How the watchlist is ordered. `Manual` is the order tickets were added and
+/// moved in.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortMode {
+    #[default]
+    Manual,
+    Status,
+    Updated,
+    Assignee,
+    Priority,
+}
+
+impl SortMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Manual => Self::Status,
+            Self::Status => Self::Updated,
+            Self::Updated => Self::Assignee,
+            Self::Assignee => Self::Priority,
+            Self::Priority => Self::Manual,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Status => "status",
+            Self::Updated => "last updated",
+            Self::Assignee => "assignee",
+            Self::Priority => "priority",
+        }
+    }
+
+    fn compare(self, a: &Ticket, b: &Ticket) -> Ordering {
+        match self {
+            Self::Manual => Ordering::Equal,
+            Self::Status => status_key(a).cmp(&status_key(b)),
+            // Most recently updated first; never-seen timestamps last.
+            Self::Updated => b.updated.cmp(&a.updated),
+            Self::Assignee => assignee_key(a).cmp(&assignee_key(b)),
+            Self::Priority => priority_rank(&a.priority).cmp(&priority_rank(&b.priority)),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupMode {
+    #[default]
+    Ungrouped,
+    Status,
+    Epic,
+}
+
+impl GroupMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Ungrouped => Self::Status,
+            Self::Status => Self::Epic,
+            Self::Epic => Self::Ungrouped,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ungrouped => "none",
+            Self::Status => "status",
+            Self::Epic => "epic",
+        }
+    }
+
+    /// The group `ticket` falls in: a key to order the groups by and its heading.
+    fn group(self, ticket: &Ticket) -> Option<((u8, String), String)> {
+        match self {
+            Self::Ungrouped => None,
+            Self::Status => Some((status_key(ticket), ticket.status.clone())),
+            Self::Epic if ticket.epic.is_empty() => {
+                Some(((1, String::new()), "No epic".to_string()))
+            }
+            Self::Epic => Some(((0, ticket.epic.to_lowercase()), ticket.epic.clone())),
+        }
+    }
+}
+
+/// The sort and grouping picked for the watchlist, remembered between runs.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ListLayout {
+    #[serde(default)]
+    pub sort: SortMode,
+    #[serde(default)]
+    pub group: GroupMode,
+}
+
+impl ListLayout {
+    /// Whether tickets show in the order they were added and moved in.
+    pub fn is_manual(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+// ── Arranging ─────────────────────────────────────────────────────────────────
+
+/// A line of the arranged watchlist: a group heading, or a ticket by its index.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Row {
+    Heading(String),
+    Ticket(usize),
+}
+
+/// Whether every word of `filter` appears in the ticket's ID, title, assignee or
+/// status, ignoring case.
+pub fn matches(ticket: &Ticket, filter: &str) -> bool {
+    let haystack = [
+        ticket.id.as_str(),
+        &ticket.title,
+        &ticket.assignee,
+        &ticket.status,
+    ]
+    .join(" ")
+    .to_lowercase();
+    filter
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| haystack.contains(word))
+}
+
+/// The tickets matching `filter`, grouped and sorted by `layout`. Sorting is
+/// stable, so tickets that compare equal keep their manual order.
+pub fn arrange(tickets: &[Ticket], filter: &str, layout: ListLayout) -> Vec<Row> {
+    let mut order = (0..tickets.len())
+        .filter(|&i| matches(&tickets[i], filter))
+        .collect::<Vec<_>>();
+    order.sort_by(|&a, &b| layout.sort.compare(&tickets[a], &tickets[b]));
+    order.sort_by_cached_key(|&i| layout.group.group(&tickets[i]).map(|(key, _)| key));
+
+    let mut rows = Vec::with_capacity(order.len());
+    let mut current = None;
+    for i in order {
+        if let Some((key, heading)) = layout.group.group(&tickets[i])
+            && current.as_ref() != Some(&key)
+        {
+            rows.push(Row::Heading(heading));
+            current = Some(key);
+        }
+        rows.push(Row::Ticket(i));
+    }
+    rows
+}
+
+/// The ticket indices of `rows`, in display order.
+pub fn ticket_order(rows: &[Row]) -> Vec<usize> {
+    rows.iter()
+        .filter_map(|row| match row {
+            Row::Ticket(i) => Some(*i),
+            Row::Heading(_) => None,
+        })
+        .collect()
+}
+
+/// To do, then in progress, then done; by status name within each.
+fn status_key(ticket: &Ticket) -> (u8, String) {
+    let category = match ticket.status_category {
+        StatusCategory::ToDo => 0,
+        StatusCategory::InProgress => 1,
+        StatusCategory::Done => 2,
+        StatusCategory::Unknown => 3,
+    };
+    (category, ticket.status.to_lowercase())
+}
+
+/// Alphabetical, with unassigned tickets last.
+fn assignee_key(ticket: &Ticket) -> (bool, String) {
+    (
+        ticket.assignee == "Unassigned",
+        ticket.assignee.to_lowercase(),
+    )
+}
+
+fn priority_rank(priority: &str) -> usize {
+    PRIORITIES
+        .iter()
+        .position(|p| p.eq_ignore_ascii_case(priority))
+        .unwrap_or(PRIORITIES.len())
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use test_case::test_case;
+
+    fn ticket(id: &str, status: &str, category: StatusCategory, assignee: &str) -> Ticket {
+        Ticket {
+            id: id.to_string(),
+            title: format!("Title of {}", id),
+            status: status.to_string(),
+            status_category: category,
+            assignee: assignee.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn tickets() -> Vec<Ticket> {
+        let updated = |raw: &str| DateTime::parse_from_rfc3339(raw).ok();
+        vec![
+            Ticket {
+                priority: "Low".to_string(),
+                updated: updated("2024-03-01T10:00:00+00:00"),
+                epic: "ABC-1 Login revamp".to_string(),
+                ..ticket("ABC-10", "Done", StatusCategory::Done, "Zoe")
+            },
+            Ticket {
+                priority: "Highest".to_string(),
+                updated: updated("2024-03-03T10:00:00+00:00"),
+                ..ticket(
+                    "ABC-11",
+                    "In Progress",
+                    StatusCategory::InProgress,
+                    "Unassigned",
+                )
+            },
+            Ticket {
+                priority: "Medium".to_string(),
+                epic: "ABC-1 Login revamp".to_string(),
+                ..ticket("ABC-12", "To Do", StatusCategory::ToDo, "alice")
+            },
+            Ticket {
+                priority: "Custom".to_string(),
+                updated: updated("2024-03-02T10:00:00+00:00"),
+                ..ticket("ABC-13", "In Review", StatusCategory::InProgress, "Bob")
+            },
+        ]
+    }
+
+    fn order(sort: SortMode) -> Vec<usize> {
+        let layout = ListLayout {
+            sort,
+            group: GroupMode::Ungrouped,
+        };
+        ticket_order(&arrange(&tickets(), "", layout))
+    }
+
+    #[test_case(SortMode::Manual, vec![0, 1, 2, 3]; "manual")]
+    #[test_case(SortMode::Status, vec![2, 1, 3, 0]; "status category then name")]
+    #[test_case(SortMode::Updated, vec![1, 3, 0, 2]; "newest first")]
+    #[test_case(SortMode::Assignee, vec![2, 3, 0, 1]; "assignee with unassigned last")]
+    #[test_case(SortMode::Priority, vec![1, 2, 0, 3]; "priority with unknown last")]
+    fn arrange_sorts(sort: SortMode, expected: Vec<usize>) {
+        assert_eq!(order(sort), expected);
+    }
+
+    #[test_case("abc-12", vec![2]; "id")]
+    #[test_case("REVIEW", vec![3]; "status")]
+    #[test_case("title bob", vec![3]; "every word")]
+    #[test_case("zoe 11", vec![]; "words across tickets")]
+    fn arrange_filters(filter: &str, expected: Vec<usize>) {
+        assert_eq!(
+            ticket_order(&arrange(&tickets(), filter, ListLayout::default())),
+            expected
+        );
+    }
+
+    #[test]
+    fn arrange_groups_by_epic_with_no_epic_last() {
+        let layout = ListLayout {
+            sort: SortMode::Priority,
+            group: GroupMode::Epic,
+        };
+        assert_eq!(
+            arrange(&tickets(), "", layout),
+            vec![
+                Row::Heading("ABC-1 Login revamp".to_string()),
+                Row::Ticket(2),
+                Row::Ticket(0),
+                Row::Heading("No epic".to_string()),
+                Row::Ticket(1),
+                Row::Ticket(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrange_groups_by_status_in_category_order() {
+        let layout = ListLayout {
+            sort: SortMode::Manual,
+            group: GroupMode::Status,
+        };
+        let headings = arrange(&tickets(), "", layout)
+            .into_iter()
+            .filter_map(|row| match row {
+                Row::Heading(heading) => Some(heading),
+                Row::Ticket(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(headings, ["To Do", "In Progress", "In Review", "Done"]);
+    }
+
+    #[test]
+    fn modes_cycle_back_to_the_start() {
+        let mut sort = SortMode::Manual;
+        for _ in 0..5 {
+            sort = sort.next();
+        }
+        assert_eq!(sort, SortMode::Manual);
+        assert_eq!(GroupMode::Epic.next(), GroupMode::Ungrouped);
+    }
+
+    #[test]
+    fn layout_round_trips_through_yaml() {
+        let layout = ListLayout {
+            sort: SortMode::Updated,
+            group: GroupMode::Epic,
+        };
+        let yaml = serde_yaml::to_string(&layout).unwrap();
+        assert_eq!(yaml, "sort: updated\ngroup: epic\n");
+        assert_eq!(serde_yaml::from_str::<ListLayout>(&yaml).unwrap(), layout);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::client::jira::models::NewWorklog;
+use crate::utils::duration;
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeDelta};
+use tui_text_field::TextField;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+/// When work logged against an earlier day is recorded as having started.
+const DEFAULT_START: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).expect("valid time");
+
+// ── Field enum ────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum WorklogField {
+    Duration,
+    Date,
+    Comment,
+}
+
+impl WorklogField {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Duration => Self::Date,
+            Self::Date => Self::Comment,
+            Self::Comment => Self::Duration,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            Self::Duration => Self::Comment,
+            Self::Date => Self::Duration,
+            Self::Comment => Self::Date,
+        }
+    }
+}
+
+// ── Log work form ─────────────────────────────────────────────────────────────
+#[derive(Clone)]
+pub struct WorklogForm {
+    pub ticket_id: String,
+    pub duration: TextField,
+    pub date: TextField,
+    pub comment: TextField,
+    pub active_field: WorklogField,
+    /// Set while the worklog is being posted, so Enter can't log it twice.
+    pub submitting: bool,
+    /// Why the last submit was rejected; the form stays open to fix it.
+    pub error: Option<String>,
+}
+
+impl WorklogForm {
+    /// A blank entry for `today`, with the cursor on the duration.
+    pub fn new(ticket_id: String, today: NaiveDate) -> Self {
+        Self {
+            ticket_id,
+            duration: TextField::empty(),
+            date: TextField::new(today.format(DATE_FORMAT).to_string()),
+            comment: TextField::empty(),
+            active_field: WorklogField::Duration,
+            submitting: false,
+            error: None,
+        }
+    }
+
+    pub fn active_field(&self) -> &TextField {
+        match self.active_field {
+            WorklogField::Duration => &self.duration,
+            WorklogField::Date => &self.date,
+            WorklogField::Comment => &self.comment,
+        }
+    }
+
+    pub fn active_field_mut(&mut self) -> &mut TextField {
+        match self.active_field {
+            WorklogField::Duration => &mut self.duration,
+            WorklogField::Date => &mut self.date,
+            WorklogField::Comment => &mut self.comment,
+        }
+    }
+
+    /// Validates the form into a worklog. Work logged for today ends `now`; work on
+    /// an earlier day is recorded as starting at 09:00.
+    pub fn to_worklog(&self, now: DateTime<Local>) -> Result<NewWorklog, String> {
+        let seconds = duration::parse(self.duration.value())?;
+        let date_value = self.date.value().trim();
+        let date = NaiveDate::parse_from_str(date_value, DATE_FORMAT)
+            .map_err(|_| format!("Not a date: {} (use YYYY-MM-DD)", date_value))?;
+
+        let today = now.date_naive();
+        let started = if date > today {
+            return Err("Can't log work in the future".to_string());
+        } else if date == today {
+            let midnight = today
+                .and_time(NaiveTime::MIN)
+                .and_local_timezone(Local)
+                .earliest()
+                .unwrap_or(now);
+            (now - TimeDelta::seconds(seconds as i64)).max(midnight)
+        } else {
+            date.and_time(DEFAULT_START)
+                .and_local_timezone(Local)
+                .earliest()
+                .ok_or_else(|| format!("No such local time on {}", date_value))?
+        };
+
+        Ok(NewWorklog {
+            time_spent_seconds: seconds,
+            started,
+            comment: self.comment.value().trim().to_string(),
+        })
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 3, 1, 15, 0, 0).unwrap()
+    }
+
+    fn form(duration: &str, date: &str) -> WorklogForm {
+        let mut form = WorklogForm::new("ABC-1".to_string(), now().date_naive());
+        form.duration = TextField::new(duration.to_string());
+        form.date = TextField::new(date.to_string());
+        form
+    }
+
+    #[test]
+    fn worklog_field_next_and_prev_wrap() {
+        assert_eq!(WorklogField::Comment.next(), WorklogField::Duration);
+        assert_eq!(WorklogField::Duration.prev(), WorklogField::Comment);
+    }
+
+    #[test]
+    fn new_form_defaults_to_today() {
+        let form = WorklogForm::new("ABC-1".to_string(), now().date_naive());
+        assert_eq!(form.date.value(), "2024-03-01");
+        assert_eq!(form.active_field, WorklogField::Duration);
+    }
+
+    #[test]
+    fn to_worklog_today_ends_now() {
+        let mut form = form("1h30m", "2024-03-01");
+        form.comment = TextField::new(" Pairing ".to_string());
+
+        assert_eq!(
+            form.to_worklog(now()),
+            Ok(NewWorklog {
+                time_spent_seconds: 5400,
+                started: Local.with_ymd_and_hms(2024, 3, 1, 13, 30, 0).unwrap(),
+                comment: "Pairing".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn to_worklog_today_starts_no_earlier_than_midnight() {
+        let worklog = form("2d", "2024-03-01").to_worklog(now()).unwrap();
+        assert_eq!(
+            worklog.started,
+            Local.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_worklog_earlier_day_starts_in_the_morning() {
+        let worklog = form("2h", "2024-02-28").to_worklog(now()).unwrap();
+        assert_eq!(
+            worklog.started,
+            Local.with_ymd_and_hms(2024, 2, 28, 9, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_worklog_rejects_bad_input() {
+        assert!(form("soon", "2024-03-01").to_worklog(now()).is_err());
+        assert_eq!(
+            form("1h", "01/03/2024").to_worklog(now()),
+            Err("Not a date: 01/03/2024 (use YYYY-MM-DD)".to_string())
+        );
+        assert_eq!(
+            form("1h", "2024-03-02").to_worklog(now()),
+            Err("Can't log work in the future".to_string())
+        );
+    }
+}
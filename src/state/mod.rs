@@ -1,7 +1,14 @@
 pub(crate) mod app;
 pub(crate) mod config_editor;
 pub(crate) mod jira;
+pub(crate) mod jira_archive;
+pub(crate) mod jira_board;
+pub(crate) mod jira_comment;
 pub(crate) mod jira_config;
+pub(crate) mod jira_create;
+pub(crate) mod jira_notes;
+pub(crate) mod jira_view;
+pub(crate) mod jira_worklog;
 pub(crate) mod log;
 pub(crate) mod service_status;
 pub(crate) mod service_status_config;
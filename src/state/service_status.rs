@@ -1,9 +1,14 @@
+use crate::config::model::ServiceStatusConfig;
 use crate::environment::Environment;
 use ratatui::widgets::TableState;
 
 pub struct ServiceStatus {
     pub services: Vec<Service>,
     pub table_state: TableState,
+    /// Bumped on every config reload. Scans and lookups carry the generation they
+    /// started in, so a result that arrives after the services have moved is
+    /// dropped instead of landing on whichever service now has its index.
+    pub generation: u64,
 }
 
 impl ServiceStatus {
@@ -11,9 +16,51 @@ impl ServiceStatus {
         Self {
             services: vec![Service::default(); num_services],
             table_state: TableState::default().with_selected(None),
+            generation: 0,
         }
     }
 
+    /// Swaps `old` services for `new` after the config is reloaded. Services whose
+    /// config is unchanged keep their commits; the indices of the rest, which need
+    /// scanning, are returned. Results still in flight are dropped when they arrive,
+    /// so kept services that were waiting on one are scanned again too.
+    pub fn reload(
+        &mut self,
+        old: &[ServiceStatusConfig],
+        new: &[ServiceStatusConfig],
+    ) -> Vec<usize> {
+        self.generation += 1;
+        let mut rescan = Vec::new();
+        self.services = new
+            .iter()
+            .enumerate()
+            .map(|(idx, config)| {
+                let kept = old
+                    .iter()
+                    .position(|o| o == config)
+                    .and_then(|old_idx| self.services.get(old_idx).cloned());
+                match kept {
+                    Some(mut service) => {
+                        if service.is_waiting() {
+                            service.pending_tickets = PendingTickets::Unknown;
+                            rescan.push(idx);
+                        }
+                        service
+                    }
+                    None => {
+                        rescan.push(idx);
+                        Service::default()
+                    }
+                }
+            })
+            .collect();
+        let last = self.services.len().checked_sub(1);
+        let selected = self.table_state.selected();
+        self.table_state
+            .select(selected.and_then(|idx| last.map(|last| idx.min(last))));
+        rescan
+    }
+
     pub fn set_commit_fetching(&mut self, service_idx: usize, env: &Environment) {
         self.update_commit(service_idx, env, Commit::Fetching);
     }
@@ -27,14 +74,67 @@ impl ServiceStatus {
     }
 
     fn update_commit(&mut self, service_idx: usize, env: &Environment, commit: Commit) {
-        let service = &mut self.services[service_idx];
+        // A reload can drop the service while its scan is in flight.
+        let Some(service) = self.services.get_mut(service_idx) else {
+            return;
+        };
 
+        let range = service.pending_range();
         match env {
             Environment::Staging => service.staging = commit,
             Environment::Preproduction => service.preproduction = commit,
             Environment::Production => service.production = commit,
             _ => {}
         }
+        if service.pending_range() != range {
+            service.pending_tickets = PendingTickets::Unknown;
+        }
+    }
+
+    /// Marks the service's pending tickets as being looked up, returning the commits
+    /// to look between; `None` if it isn't waiting on production or already has them.
+    pub fn start_pending_lookup(&mut self, service_idx: usize) -> Option<CommitRange> {
+        let service = self.services.get_mut(service_idx)?;
+        if service.pending_tickets != PendingTickets::Unknown {
+            return None;
+        }
+        let range = service.pending_range()?;
+        service.pending_tickets = PendingTickets::Loading;
+        Some(range)
+    }
+
+    /// Records the tickets found for `range`, unless a deployment has moved the
+    /// service on since the lookup started.
+    pub fn set_pending_tickets(
+        &mut self,
+        service_idx: usize,
+        range: &CommitRange,
+        result: Result<Vec<String>, String>,
+    ) {
+        let Some(service) = self
+            .services
+            .get_mut(service_idx)
+            .filter(|s| s.pending_range().as_ref() == Some(range))
+        else {
+            return;
+        };
+        service.pending_tickets = match result {
+            Ok(ids) => PendingTickets::Found(ids),
+            Err(error) => PendingTickets::Failed(error),
+        };
+    }
+
+    /// The tickets waiting to go to production on the selected service.
+    pub fn selected_pending_tickets(&self) -> &[String] {
+        match self
+            .table_state
+            .selected()
+            .and_then(|idx| self.services.get(idx))
+            .map(|s| &s.pending_tickets)
+        {
+            Some(PendingTickets::Found(ids)) => ids,
+            _ => &[],
+        }
     }
 
     pub fn get_selected_service_idx(&self) -> Option<usize> {
@@ -112,6 +212,25 @@ pub struct Service {
     pub staging: Commit,
     pub preproduction: Commit,
     pub production: Commit,
+    pub pending_tickets: PendingTickets,
+}
+
+/// The commits preproduction has and production doesn't yet, as `from..to`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CommitRange {
+    pub from: String,
+    pub to: String,
+}
+
+/// The Jira tickets named in the commits waiting to go to production.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum PendingTickets {
+    /// Not looked up yet, or the service isn't waiting on production.
+    #[default]
+    Unknown,
+    Loading,
+    Found(Vec<String>),
+    Failed(String),
 }
 
 #[derive(PartialEq, Debug)]
@@ -129,11 +248,18 @@ impl Default for Service {
             staging: Commit::Empty,
             preproduction: Commit::Empty,
             production: Commit::Empty,
+            pending_tickets: PendingTickets::Unknown,
         }
     }
 }
 
 impl Service {
+    /// Whether a scan or pending ticket lookup for this service hasn't come back yet.
+    fn is_waiting(&self) -> bool {
+        [&self.staging, &self.preproduction, &self.production].contains(&&Commit::Fetching)
+            || self.pending_tickets == PendingTickets::Loading
+    }
+
     pub fn commit_ref_status(&self) -> CommitRefStatus {
         if self.production.is_errored()
             || self.preproduction.is_errored()
@@ -159,12 +285,27 @@ impl Service {
 
         CommitRefStatus::NothingMatches
     }
+
+    /// The commits going out with the next production deployment, once staging and
+    /// preproduction agree on what that is.
+    pub fn pending_range(&self) -> Option<CommitRange> {
+        if self.commit_ref_status() != CommitRefStatus::StagingPreprodMatch {
+            return None;
+        }
+        Some(CommitRange {
+            from: self.production.get_ref()?.to_string(),
+            to: self.preproduction.get_ref()?.to_string(),
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::config::model::ServiceStatusConfig;
     use crate::environment::Environment;
-    use crate::state::service_status::{Commit, CommitRefStatus, Service, ServiceStatus};
+    use crate::state::service_status::{
+        Commit, CommitRange, CommitRefStatus, PendingTickets, Service, ServiceStatus,
+    };
     use test_case::test_case;
 
     #[test]
@@ -360,4 +501,136 @@ mod tests {
 
         assert_eq!(service.commit_ref_status(), expected);
     }
+
+    fn awaiting_production() -> ServiceStatus {
+        let mut service_status = ServiceStatus::new(2);
+        service_status.set_commit_ok(1, &Environment::Staging, String::from("new"));
+        service_status.set_commit_ok(1, &Environment::Preproduction, String::from("new"));
+        service_status.set_commit_ok(1, &Environment::Production, String::from("old"));
+        service_status
+    }
+
+    fn range(from: &str, to: &str) -> CommitRange {
+        CommitRange {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn start_pending_lookup_only_when_awaiting_production_and_once() {
+        let mut service_status = awaiting_production();
+        assert_eq!(service_status.start_pending_lookup(0), None);
+
+        assert_eq!(
+            service_status.start_pending_lookup(1),
+            Some(range("old", "new"))
+        );
+        assert_eq!(
+            service_status.services[1].pending_tickets,
+            PendingTickets::Loading
+        );
+        assert_eq!(service_status.start_pending_lookup(1), None);
+    }
+
+    #[test]
+    fn set_pending_tickets_shows_them_for_the_selected_service() {
+        let mut service_status = awaiting_production();
+        service_status.start_pending_lookup(1);
+        service_status.set_pending_tickets(
+            1,
+            &range("old", "new"),
+            Ok(vec![String::from("ABC-1")]),
+        );
+        assert_eq!(service_status.selected_pending_tickets(), &[] as &[String]);
+
+        service_status.table_state.select(Some(1));
+        assert_eq!(service_status.selected_pending_tickets(), ["ABC-1"]);
+    }
+
+    #[test]
+    fn deployment_clears_pending_tickets_and_drops_stale_results() {
+        let mut service_status = awaiting_production();
+        service_status.start_pending_lookup(1);
+
+        service_status.set_commit_ok(1, &Environment::Production, String::from("new"));
+        assert_eq!(
+            service_status.services[1].pending_tickets,
+            PendingTickets::Unknown
+        );
+
+        service_status.set_pending_tickets(1, &range("old", "new"), Err(String::from("late")));
+        assert_eq!(
+            service_status.services[1].pending_tickets,
+            PendingTickets::Unknown
+        );
+    }
+
+    fn service_config(name: &str, staging: &str) -> ServiceStatusConfig {
+        ServiceStatusConfig {
+            name: name.to_string(),
+            staging: staging.to_string(),
+            preproduction: String::new(),
+            production: String::new(),
+            repo: String::new(),
+            clone: None,
+        }
+    }
+
+    #[test]
+    fn reload_keeps_unchanged_services_and_rescans_the_rest() {
+        let old = vec![
+            service_config("api", "https://api"),
+            service_config("web", "https://web"),
+        ];
+        let mut service_status = ServiceStatus::new(2);
+        service_status.set_commit_ok(0, &Environment::Staging, String::from("a1"));
+        service_status.set_commit_ok(1, &Environment::Staging, String::from("b1"));
+        service_status.table_state.select(Some(1));
+
+        let new = vec![
+            service_config("web", "https://web"),
+            service_config("api", "https://api.v2"),
+            service_config("jobs", "https://jobs"),
+        ];
+        let rescan = service_status.reload(&old, &new);
+
+        assert_eq!(rescan, vec![1, 2]);
+        assert_eq!(
+            service_status.services[0].staging,
+            Commit::Ok(String::from("b1"))
+        );
+        assert_eq!(service_status.services[1], Service::default());
+        assert_eq!(service_status.services.len(), 3);
+
+        service_status.reload(&new, &[]);
+        assert_eq!(service_status.table_state.selected(), None);
+    }
+
+    #[test]
+    fn reload_bumps_the_generation_and_rescans_services_still_waiting() {
+        let config = vec![
+            service_config("api", "https://api"),
+            service_config("web", "https://web"),
+        ];
+        let mut service_status = ServiceStatus::new(2);
+        service_status.set_commit_ok(0, &Environment::Staging, String::from("a1"));
+        service_status.set_commit_fetching(1, &Environment::Staging);
+
+        let rescan = service_status.reload(&config, &config);
+
+        assert_eq!(service_status.generation, 1);
+        assert_eq!(rescan, vec![1]);
+        assert_eq!(
+            service_status.services[0].staging,
+            Commit::Ok(String::from("a1"))
+        );
+    }
+
+    #[test]
+    fn results_for_a_dropped_service_are_ignored() {
+        let mut service_status = ServiceStatus::new(1);
+        service_status.set_commit_ok(3, &Environment::Staging, String::from("late"));
+        assert_eq!(service_status.services.len(), 1);
+    }
 }
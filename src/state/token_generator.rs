@@ -1,11 +1,14 @@
+use crate::client::auth_zero::jwt::VerificationReport;
 use crate::config::model::ServiceConfig;
 use crate::state::token_generator::Token::Idle;
 use ratatui::widgets::ListState;
+use tui_text_field::TextField;
 
 #[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
 pub enum Focus {
     Service,
     Env,
+    Paste,
 }
 
 pub(crate) struct TokenGenerator {
@@ -13,6 +16,12 @@ pub(crate) struct TokenGenerator {
     pub env_list_state: ListState,
     pub service_list_state: ListState,
     pub focus: Focus,
+    pub pasted_token: TextField,
+    pub verification: Option<Verification>,
+    /// Bumped on every config reload. Token requests carry the generation they
+    /// started in, so one that finishes after the services have moved is dropped
+    /// instead of being shown, copied and exported as another service's token.
+    pub generation: u64,
 }
 
 impl TokenGenerator {
@@ -27,9 +36,45 @@ impl TokenGenerator {
             env_list_state: ListState::default().with_selected(Some(0)),
             service_list_state: ListState::default().with_selected(Some(0)),
             focus: Focus::Service,
+            pasted_token: TextField::empty(),
+            verification: None,
+            generation: 0,
         }
     }
 
+    /// Swaps `old` services for `new` after the config is reloaded. Services whose
+    /// config is unchanged keep their tokens, except requests still in flight: their
+    /// results will be dropped, so they go back to idle.
+    pub(crate) fn reload(&mut self, old: &[ServiceConfig], new: &[ServiceConfig]) {
+        self.generation += 1;
+        self.tokens = new
+            .iter()
+            .map(|config| {
+                old.iter()
+                    .position(|o| o == config)
+                    .and_then(|old_idx| self.tokens.get(old_idx).cloned())
+                    .map(|tokens| {
+                        tokens
+                            .into_iter()
+                            .map(|token| match token {
+                                Token::Requesting => Idle,
+                                token => token,
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_else(|| vec![Idle; config.credentials.len()])
+            })
+            .collect();
+        let service = self
+            .get_selected_service()
+            .min(self.tokens.len().saturating_sub(1));
+        self.service_list_state.select(Some(service));
+        let env_count = self.tokens.get(service).map_or(0, Vec::len);
+        self.env_list_state.select(Some(
+            self.get_selected_env().min(env_count.saturating_sub(1)),
+        ));
+    }
+
     pub fn get_selected_service_env(&self) -> (usize, usize) {
         (self.get_selected_service(), self.get_selected_env())
     }
@@ -53,16 +98,72 @@ impl TokenGenerator {
     }
 
     pub fn set_token_ready(&mut self, service_idx: usize, env_idx: usize, token: String) {
-        self.tokens[service_idx][env_idx] = Token::Ready(token);
+        self.set_token(service_idx, env_idx, Token::Ready(token));
     }
 
     pub fn set_token_error(&mut self, service_idx: usize, env_idx: usize) {
-        self.tokens[service_idx][env_idx] = Token::Error;
+        self.set_token(service_idx, env_idx, Token::Error);
+    }
+
+    /// A reload can drop the service while its token is being requested.
+    fn set_token(&mut self, service_idx: usize, env_idx: usize, token: Token) {
+        if let Some(slot) = self
+            .tokens
+            .get_mut(service_idx)
+            .and_then(|envs| envs.get_mut(env_idx))
+        {
+            *slot = token;
+        }
     }
 
     pub fn get_token_for_selected_service_env(&self) -> &Token {
         &self.tokens[self.get_selected_service()][self.get_selected_env()]
     }
+
+    pub fn open_paste(&mut self) {
+        self.pasted_token.clear();
+        self.focus = Focus::Paste;
+    }
+
+    /// Leaves the paste input, returning to the environment list the pasted
+    /// token is verified against.
+    pub fn close_paste(&mut self) {
+        self.focus = Focus::Env;
+    }
+
+    /// Returns the pasted token with any surrounding whitespace or `Bearer `
+    /// prefix removed, or `None` when nothing usable was pasted.
+    pub fn take_pasted_token(&mut self) -> Option<String> {
+        let value = self.pasted_token.value().trim();
+        let token = value.strip_prefix("Bearer ").unwrap_or(value).trim();
+        let token = (!token.is_empty()).then(|| token.to_string());
+        self.pasted_token.clear();
+        token
+    }
+
+    pub fn start_verification(&mut self, label: String) {
+        self.verification = Some(Verification::Verifying(label));
+    }
+
+    pub fn finish_verification(&mut self, report: VerificationReport) {
+        if let Some(Verification::Verifying(label)) = self.verification.take() {
+            self.verification = Some(Verification::Done(label, report));
+        }
+    }
+
+    pub fn fail_verification(&mut self, error: String) {
+        if let Some(Verification::Verifying(label)) = self.verification.take() {
+            self.verification = Some(Verification::Failed(label, error));
+        }
+    }
+}
+
+/// The outcome of the most recent verify action, labelled with what was verified.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Verification {
+    Verifying(String),
+    Done(String, VerificationReport),
+    Failed(String, String),
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -85,6 +186,9 @@ impl Token {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::model::Credentials;
+    use crate::environment::Environment;
+    use test_case::test_case;
 
     fn get_default_token_generator() -> TokenGenerator {
         TokenGenerator {
@@ -92,6 +196,9 @@ mod tests {
             env_list_state: Default::default(),
             service_list_state: Default::default(),
             focus: Focus::Service,
+            pasted_token: TextField::empty(),
+            verification: None,
+            generation: 0,
         }
     }
 
@@ -169,4 +276,139 @@ mod tests {
             &Token::Ready(token_string)
         )
     }
+
+    #[test]
+    fn open_paste_clears_input_and_focuses_paste() {
+        let mut token_generator = get_default_token_generator();
+        token_generator.pasted_token.insert('x');
+
+        token_generator.open_paste();
+
+        assert_eq!(token_generator.focus, Focus::Paste);
+        assert_eq!(token_generator.pasted_token.value(), "");
+    }
+
+    #[test]
+    fn close_paste_focuses_env() {
+        let mut token_generator = get_default_token_generator();
+        token_generator.open_paste();
+
+        token_generator.close_paste();
+
+        assert_eq!(token_generator.focus, Focus::Env);
+    }
+
+    #[test_case("abc.def.ghi", Some("abc.def.ghi"); "plain token")]
+    #[test_case("  abc.def.ghi \n", Some("abc.def.ghi"); "surrounding whitespace")]
+    #[test_case("Bearer abc.def.ghi", Some("abc.def.ghi"); "bearer prefix")]
+    #[test_case("   ", None; "blank")]
+    fn take_pasted_token_returns_cleaned_token(input: &str, expected: Option<&str>) {
+        let mut token_generator = get_default_token_generator();
+        input
+            .chars()
+            .for_each(|c| token_generator.pasted_token.insert(c));
+
+        assert_eq!(
+            token_generator.take_pasted_token(),
+            expected.map(String::from)
+        );
+        assert_eq!(token_generator.pasted_token.value(), "");
+    }
+
+    #[test]
+    fn finish_verification_keeps_label() {
+        let mut token_generator = get_default_token_generator();
+        let report = VerificationReport { checks: vec![] };
+        token_generator.start_verification("svc/staging".to_string());
+
+        token_generator.finish_verification(report.clone());
+
+        assert_eq!(
+            token_generator.verification,
+            Some(Verification::Done("svc/staging".to_string(), report))
+        );
+    }
+
+    #[test]
+    fn fail_verification_keeps_label() {
+        let mut token_generator = get_default_token_generator();
+        token_generator.start_verification("svc/staging".to_string());
+
+        token_generator.fail_verification("offline".to_string());
+
+        assert_eq!(
+            token_generator.verification,
+            Some(Verification::Failed(
+                "svc/staging".to_string(),
+                "offline".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn finish_verification_ignored_when_not_verifying() {
+        let mut token_generator = get_default_token_generator();
+
+        token_generator.finish_verification(VerificationReport { checks: vec![] });
+
+        assert_eq!(token_generator.verification, None);
+    }
+
+    fn service(name: &str, envs: &[Environment]) -> ServiceConfig {
+        ServiceConfig {
+            name: name.to_string(),
+            audience: format!("https://{}", name),
+            credentials: envs
+                .iter()
+                .map(|env| Credentials {
+                    env: env.clone(),
+                    client_id: String::from("id"),
+                    client_secret: String::from("secret"),
+                    token_file: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reload_keeps_tokens_for_unchanged_services() {
+        let old = vec![
+            service("api", &[Environment::Local, Environment::Staging]),
+            service("web", &[Environment::Staging]),
+        ];
+        let mut token_generator = TokenGenerator::new(&old);
+        token_generator.set_token_ready(0, 1, String::from("api-token"));
+        token_generator.set_token_ready(1, 0, String::from("web-token"));
+        token_generator.service_list_state.select(Some(1));
+
+        let new = vec![service("api", &[Environment::Local, Environment::Staging])];
+        token_generator.reload(&old, &new);
+
+        assert_eq!(
+            token_generator.tokens,
+            vec![vec![Idle, Token::Ready(String::from("api-token"))]]
+        );
+        assert_eq!(token_generator.get_selected_service_env(), (0, 0));
+
+        // A late response for the dropped service is ignored.
+        token_generator.set_token_error(1, 0);
+        assert_eq!(token_generator.tokens.len(), 1);
+    }
+
+    #[test]
+    fn reload_bumps_the_generation_and_resets_requests_in_flight() {
+        let config = vec![service("api", &[Environment::Local, Environment::Staging])];
+        let mut token_generator = TokenGenerator::new(&config);
+        token_generator.set_token_ready(0, 0, String::from("api-token"));
+        token_generator.env_list_state.select(Some(1));
+        token_generator.start_token_request();
+
+        token_generator.reload(&config, &config);
+
+        assert_eq!(token_generator.generation, 1);
+        assert_eq!(
+            token_generator.tokens,
+            vec![vec![Token::Ready(String::from("api-token")), Idle]]
+        );
+    }
 }
@@ -236,6 +236,7 @@ impl ServiceForm {
                     env,
                     client_id: id.value().trim().to_string(),
                     client_secret: secret.value().trim().to_string(),
+                    token_file: None,
                 });
             }
         }
@@ -354,6 +355,7 @@ mod tests {
                 env: Environment::Staging,
                 client_id: "cid".to_string(),
                 client_secret: "csec".to_string(),
+                token_file: None,
             }],
         };
         let form = ServiceForm::from_existing(0, &svc);
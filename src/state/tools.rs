@@ -1,51 +1,49 @@
+use crate::tools::{self, Tool};
 use ratatui::widgets::ListState;
 
 pub struct ToolList {
-    pub items: Vec<Tool>,
+    pub items: Vec<ToolId>,
     pub list_state: ListState,
 }
 
 #[derive(Clone, PartialEq, Copy, Eq, Hash, Debug)]
-pub enum Tool {
+pub enum ToolId {
     ServiceStatus,
     TokenGenerator,
     Jira,
 }
 
-impl Tool {
+impl ToolId {
+    /// The registered tool this identifies.
+    pub(crate) fn tool(self) -> &'static dyn Tool {
+        tools::get(self)
+    }
+
     pub fn title(&self) -> &'static str {
-        match self {
-            Tool::ServiceStatus => "Service Status",
-            Tool::TokenGenerator => "M2M Auth0 Token Generator",
-            Tool::Jira => "My Jira Tickets",
-        }
+        self.tool().title()
     }
 
     pub fn menu_entry(&self) -> &'static str {
-        match self {
-            Tool::ServiceStatus => "Service Status",
-            Tool::TokenGenerator => "Token Generator",
-            Tool::Jira => "Jira",
-        }
+        self.tool().menu_entry()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::app::Tool;
+    use crate::app::ToolId;
     use test_case::test_case;
 
-    #[test_case(Tool::ServiceStatus, "Service Status")]
-    #[test_case(Tool::TokenGenerator, "M2M Auth0 Token Generator")]
-    #[test_case(Tool::Jira, "My Jira Tickets")]
-    fn title_returns_expected(tool: Tool, expected: &str) {
+    #[test_case(ToolId::ServiceStatus, "Service Status")]
+    #[test_case(ToolId::TokenGenerator, "M2M Auth0 Token Generator")]
+    #[test_case(ToolId::Jira, "My Jira Tickets")]
+    fn title_returns_expected(tool: ToolId, expected: &str) {
         assert_eq!(tool.title(), expected);
     }
 
-    #[test_case(Tool::ServiceStatus, "Service Status")]
-    #[test_case(Tool::TokenGenerator, "Token Generator")]
-    #[test_case(Tool::Jira, "Jira")]
-    fn menu_entry_returns_expected(tool: Tool, expected: &str) {
+    #[test_case(ToolId::ServiceStatus, "Service Status")]
+    #[test_case(ToolId::TokenGenerator, "Token Generator")]
+    #[test_case(ToolId::Jira, "Jira")]
+    fn menu_entry_returns_expected(tool: ToolId, expected: &str) {
         assert_eq!(tool.menu_entry(), expected);
     }
 }
@@ -0,0 +1,2 @@
+pub(crate) mod token_file;
+pub(crate) mod token_server;
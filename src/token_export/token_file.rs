@@ -0,0 +1,73 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+/// Writes `token` to `path`, creating any missing parent directories. The file is
+/// readable and writable by the owner only.
+pub fn write_token_file(path: &Path, token: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+
+    // The mode above only applies when the file is created, so tighten files that
+    // were already there.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+
+    file.write_all(token.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn write_token_file_creates_parent_directories() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tokens").join("payment-staging");
+
+        write_token_file(&path, "abc.def.ghi").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "abc.def.ghi");
+    }
+
+    #[test]
+    fn write_token_file_replaces_previous_token() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("payment-staging");
+
+        write_token_file(&path, "a-much-longer-old-token").unwrap();
+        write_token_file(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_token_file_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("payment-staging");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_token_file(&path, "new").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
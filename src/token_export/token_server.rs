@@ -0,0 +1,333 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Latest generated token per (service, environment), keyed in lowercase.
+pub type TokenStore = Arc<RwLock<HashMap<(String, String), String>>>;
+
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// How long a client gets to send its request before the connection is closed.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The longest pause between retries when accepting connections keeps failing,
+/// e.g. when the process is out of file descriptors.
+const MAX_ACCEPT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// What a request must carry to be answered: a `Host` naming this server, so a web
+/// page can't reach it through DNS rebinding, and the secret for this session.
+#[derive(Clone)]
+pub struct Access {
+    port: u16,
+    secret: String,
+}
+
+impl Access {
+    /// Access for a server on `port` with a fresh random secret.
+    pub fn new(port: u16) -> Self {
+        let mut bytes = [0u8; 32];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .expect("the system random number generator failed");
+        Self {
+            port,
+            secret: URL_SAFE_NO_PAD.encode(bytes),
+        }
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// The rejection for a request whose headers don't pass, if they don't.
+    fn check(&self, request: &str) -> Option<(&'static str, String)> {
+        let header = |name: &str| {
+            request
+                .lines()
+                .skip(1)
+                .take_while(|line| !line.is_empty())
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.trim())
+        };
+        let host_allowed = header("host").is_some_and(|host| {
+            ["127.0.0.1", "localhost"]
+                .iter()
+                .any(|name| host.eq_ignore_ascii_case(&format!("{}:{}", name, self.port)))
+        });
+        if !host_allowed {
+            return Some(("403 Forbidden", "Unexpected Host header".to_string()));
+        }
+        let authorized = header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|secret| same_secret(secret.trim(), &self.secret));
+        if !authorized {
+            return Some((
+                "401 Unauthorized",
+                "Expected Authorization: Bearer <token server secret>".to_string(),
+            ));
+        }
+        None
+    }
+}
+
+pub fn store_token(store: &TokenStore, service: &str, env: &str, token: String) {
+    store.write().unwrap().insert(key(service, env), token);
+}
+
+/// Serves `GET /token/{service}/{env}` from `store` to requests `access` allows,
+/// until the listener is dropped.
+pub async fn serve(listener: TcpListener, store: TokenStore, access: Access) {
+    let mut backoff = Duration::ZERO;
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(_) => {
+                backoff = (backoff * 2).clamp(Duration::from_millis(10), MAX_ACCEPT_BACKOFF);
+                tokio::time::sleep(backoff).await;
+                continue;
+            }
+        };
+        backoff = Duration::ZERO;
+        let store = store.clone();
+        let access = access.clone();
+        tokio::spawn(async move {
+            let _ = handle_connection(stream, store, access).await;
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    store: TokenStore,
+    access: Access,
+) -> std::io::Result<()> {
+    // A client that goes quiet is dropped rather than holding the connection open.
+    let Ok(request) = tokio::time::timeout(READ_TIMEOUT, read_request(&mut stream)).await else {
+        return Ok(());
+    };
+    let request = request?;
+    let request = String::from_utf8_lossy(&request);
+    let request_line = request.lines().next().unwrap_or_default();
+    let (status, body) = access
+        .check(&request)
+        .unwrap_or_else(|| route(request_line, &store));
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Reads up to the blank line ending the headers, or `MAX_REQUEST_BYTES`.
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < MAX_REQUEST_BYTES {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+    Ok(request)
+}
+
+fn route(request_line: &str, store: &TokenStore) -> (&'static str, String) {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return ("400 Bad Request", "Bad request".to_string());
+    };
+    if method != "GET" {
+        return (
+            "405 Method Not Allowed",
+            "Only GET is supported".to_string(),
+        );
+    }
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let ["token", service, env] = segments.as_slice() else {
+        return (
+            "404 Not Found",
+            "Expected /token/{service}/{env}".to_string(),
+        );
+    };
+
+    let (service, env) = (percent_decode(service), percent_decode(env));
+    match store.read().unwrap().get(&key(&service, &env)) {
+        Some(token) => ("200 OK", token.clone()),
+        None => (
+            "404 Not Found",
+            format!("No token generated for {}/{}", service, env),
+        ),
+    }
+}
+
+/// Compares secrets without stopping at the first difference, so response timing
+/// doesn't give away how much of a guess was right.
+fn same_secret(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn key(service: &str, env: &str) -> (String, String) {
+    (service.to_lowercase(), env.to_lowercase())
+}
+
+/// Decodes `%XX` escapes so service names containing spaces can be requested.
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| segment.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn store() -> TokenStore {
+        let store = TokenStore::default();
+        store_token(
+            &store,
+            "Payment Service",
+            "Staging",
+            "abc.def.ghi".to_string(),
+        );
+        store
+    }
+
+    #[test_case("GET /token/payment%20service/staging HTTP/1.1", "200 OK", "abc.def.ghi"; "token found")]
+    #[test_case("GET /token/Payment%20Service/STAGING/ HTTP/1.1", "200 OK", "abc.def.ghi"; "case insensitive")]
+    #[test_case("GET /token/payment%20service/production HTTP/1.1", "404 Not Found", "No token generated for payment service/production"; "no token for env")]
+    #[test_case("GET /tokens HTTP/1.1", "404 Not Found", "Expected /token/{service}/{env}"; "unknown path")]
+    #[test_case("POST /token/payment%20service/staging HTTP/1.1", "405 Method Not Allowed", "Only GET is supported"; "wrong method")]
+    #[test_case("", "400 Bad Request", "Bad request"; "empty request")]
+    fn route_responds(request_line: &str, status: &str, body: &str) {
+        assert_eq!(route(request_line, &store()), (status, body.to_string()));
+    }
+
+    #[test_case("payment-staging", "payment-staging"; "plain")]
+    #[test_case("my%20api", "my api"; "space")]
+    #[test_case("100%", "100%"; "trailing percent")]
+    #[test_case("%zz", "%zz"; "invalid escape")]
+    fn percent_decode_decodes(segment: &str, expected: &str) {
+        assert_eq!(percent_decode(segment), expected);
+    }
+
+    fn access() -> Access {
+        Access {
+            port: 7878,
+            secret: "s3cret".to_string(),
+        }
+    }
+
+    fn request(headers: &str) -> String {
+        format!(
+            "GET /token/payment%20service/staging HTTP/1.1\r\n{}\r\n",
+            headers
+        )
+    }
+
+    #[test_case("Host: 127.0.0.1:7878\r\nAuthorization: Bearer s3cret\r\n"; "loopback address")]
+    #[test_case("host: LOCALHOST:7878\r\nauthorization: Bearer s3cret\r\n"; "any case")]
+    fn check_allows(headers: &str) {
+        assert_eq!(access().check(&request(headers)), None);
+    }
+
+    #[test_case("Authorization: Bearer s3cret\r\n", "403 Forbidden"; "no host")]
+    #[test_case("Host: evil.example:7878\r\nAuthorization: Bearer s3cret\r\n", "403 Forbidden"; "rebound host")]
+    #[test_case("Host: localhost\r\nAuthorization: Bearer s3cret\r\n", "403 Forbidden"; "host without port")]
+    #[test_case("Host: localhost:7878\r\n", "401 Unauthorized"; "no secret")]
+    #[test_case("Host: localhost:7878\r\nAuthorization: Bearer guess\r\n", "401 Unauthorized"; "wrong secret")]
+    fn check_rejects(headers: &str, status: &str) {
+        assert_eq!(
+            access().check(&request(headers)).map(|(status, _)| status),
+            Some(status)
+        );
+    }
+
+    #[test]
+    fn access_secrets_differ_per_session() {
+        assert_ne!(Access::new(7878).secret(), Access::new(7878).secret());
+        assert_eq!(Access::new(7878).secret().len(), 43);
+    }
+
+    async fn get(headers: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let access = Access {
+            port: addr.port(),
+            ..access()
+        };
+        tokio::spawn(serve(listener, store(), access));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(request(&headers.replace("{port}", &addr.port().to_string())).as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn serve_returns_stored_token() {
+        let response = get("Host: 127.0.0.1:{port}\r\nAuthorization: Bearer s3cret\r\n").await;
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("\r\n\r\nabc.def.ghi"));
+    }
+
+    #[tokio::test]
+    async fn serve_refuses_other_hosts() {
+        let response = get("Host: attacker.example\r\nAuthorization: Bearer s3cret\r\n").await;
+
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+        assert!(!response.contains("abc.def.ghi"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn serve_closes_connections_that_send_nothing() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, store(), access()));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert_eq!(response, "");
+    }
+}
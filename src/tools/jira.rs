@@ -0,0 +1,146 @@
+use crate::app::App;
+use crate::config::model::{Config, Features};
+use crate::event::events::{Event, GenericEvent, JiraEvent};
+use crate::event::handlers::config::jira as jira_config;
+use crate::event::handlers::tools::jira;
+use crate::input::key_bindings::register_jira_bindings;
+use crate::input::key_context::KeyContext;
+use crate::input::key_event_map::KeyEventMap;
+use crate::state::app::{AppState, ToolId};
+use crate::state::jira::Focus;
+use crate::tools::{Job, SCAN_INTERVAL, Tool};
+use crate::ui::widgets::config::jira as config_widget;
+use crate::ui::widgets::footer;
+use crate::ui::widgets::tools::jira as widget;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+
+/// The Jira watchlist, saved queries and board.
+pub struct JiraTool;
+
+impl Tool for JiraTool {
+    fn id(&self) -> ToolId {
+        ToolId::Jira
+    }
+
+    fn title(&self) -> &'static str {
+        "My Jira Tickets"
+    }
+
+    fn menu_entry(&self) -> &'static str {
+        "Jira"
+    }
+
+    fn load_state(&self, state: &mut AppState, config: &Config) {
+        if let Some(jira_config) = &config.jira {
+            let names = jira_config.queries.iter().map(|q| q.name.clone()).collect();
+            state.jira.set_queries(names);
+        }
+    }
+
+    fn reload_state(&self, app: &mut App, old: &Config) {
+        // Rebuilding the tabs drops their results, so only do it for new queries.
+        let queries = |config: &Config| config.jira.as_ref().map(|jira| jira.queries.clone());
+        if queries(old) != queries(&app.config) {
+            self.load_state(&mut app.state, &app.config);
+        }
+    }
+
+    fn is_configured(&self, config: &Config) -> bool {
+        config.jira.is_some()
+    }
+
+    fn is_enabled(&self, features: &Features) -> bool {
+        features.jira
+    }
+
+    fn set_enabled(&self, features: &mut Features, enabled: bool) {
+        features.jira = enabled;
+    }
+
+    fn handle_event(&self, app: &mut App, event: Event) -> Option<Event> {
+        match event {
+            Event::Jira(event) => jira::handle_event(app, event),
+            Event::JiraConfig(event) => jira_config::handle_event(app, event),
+            event => return Some(event),
+        }
+        None
+    }
+
+    fn handle_generic_event(&self, app: &mut App, event: GenericEvent) {
+        jira::handle_generic_event(app, event);
+    }
+
+    fn handle_tick(&self, app: &mut App) {
+        jira::handle_tick(app);
+    }
+
+    fn jobs(&self) -> Vec<Job> {
+        vec![Job {
+            every: SCAN_INTERVAL,
+            event: JiraEvent::ScanTickets.into(),
+        }]
+    }
+
+    fn register_bindings(&self, key_event_map: &mut KeyEventMap) {
+        register_jira_bindings(key_event_map);
+    }
+
+    fn key_contexts(&self, state: &AppState) -> Vec<KeyContext> {
+        match state.jira.focus {
+            // Forms swallow every character, so no tool keys fire under them.
+            Focus::Create | Focus::Comment | Focus::Worklog | Focus::Notes => {
+                vec![KeyContext::Jira(state.jira.focus)]
+            }
+            // Scrolling the detail pane or moving around the board shadows list
+            // movement; other tool keys still apply.
+            Focus::Detail | Focus::Board => vec![
+                KeyContext::Jira(state.jira.focus),
+                KeyContext::Tool(ToolId::Jira),
+                KeyContext::ToolIgnore(ToolId::TokenGenerator),
+            ],
+            Focus::List => vec![
+                KeyContext::Tool(ToolId::Jira),
+                KeyContext::ToolIgnore(ToolId::TokenGenerator),
+            ],
+        }
+    }
+
+    fn config_key_context(&self, state: &AppState) -> KeyContext {
+        if state.jira_config_editor.has_open_form() {
+            KeyContext::ToolConfigEditing(ToolId::Jira)
+        } else {
+            KeyContext::ToolConfig(ToolId::Jira)
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config) {
+        widget::render(frame, area, &mut state.jira, config.jira.as_ref());
+    }
+
+    fn hints(&self, state: &AppState) -> (Line<'static>, Line<'static>) {
+        footer::jira_tool_hints(state)
+    }
+
+    fn render_config(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config) {
+        config_widget::render(
+            frame,
+            area,
+            &mut state.jira_config_editor,
+            config.jira.as_ref(),
+        );
+    }
+
+    fn config_hints(&self, state: &AppState) -> (Line<'static>, Line<'static>) {
+        footer::jira_config_hints(state)
+    }
+
+    fn has_open_config_form(&self, state: &AppState) -> bool {
+        state.jira_config_editor.has_open_form()
+    }
+
+    fn close_config_form(&self, state: &mut AppState) {
+        state.jira_config_editor.close_form();
+    }
+}
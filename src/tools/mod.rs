@@ -0,0 +1,160 @@
+pub(crate) mod jira;
+pub(crate) mod service_status;
+pub(crate) mod token_generator;
+
+use crate::app::App;
+use crate::config::model::{Config, Features};
+use crate::event::events::{Event, GenericEvent};
+use crate::input::key_context::KeyContext;
+use crate::input::key_event_map::KeyEventMap;
+use crate::state::app::{AppState, ToolId};
+use crate::tools::jira::JiraTool;
+use crate::tools::service_status::ServiceStatusTool;
+use crate::tools::token_generator::TokenGeneratorTool;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use std::time::Duration;
+
+/// Every tool, in sidebar order.
+///
+/// The trait gathers each tool's behaviour, but not its data. A new tool still needs
+/// a [`ToolId`] variant, a flag in [`Features`] and a section in [`Config`], a state
+/// field on [`AppState`], its own [`Event`] variants, and a [`KeyContext`] variant
+/// for any focus of its own, as well as an implementation of [`Tool`] and an entry here.
+static TOOLS: [&dyn Tool; 3] = [&ServiceStatusTool, &TokenGeneratorTool, &JiraTool];
+
+/// How often the tools that poll something refresh it.
+pub const SCAN_INTERVAL: Duration = Duration::from_mins(15);
+
+/// What the app does with one tool in the sidebar: setting up its state, handling
+/// its events, binding its keys, rendering it and its config section, and
+/// scheduling its jobs. The types those work on are still declared per tool; see
+/// [`TOOLS`].
+pub trait Tool: Sync {
+    fn id(&self) -> ToolId;
+
+    /// The heading over the tool's pane.
+    fn title(&self) -> &'static str;
+
+    /// The tool's name in the sidebar and the config list.
+    fn menu_entry(&self) -> &'static str;
+
+    // ── State ─────────────────────────────────────────────────────────────────
+
+    /// Sets the tool's state up for `config`.
+    fn load_state(&self, state: &mut AppState, config: &Config);
+
+    /// Carries the tool's state over from `old` to the reloaded `app.config`,
+    /// starting afresh unless the tool keeps what is unchanged.
+    fn reload_state(&self, app: &mut App, _old: &Config) {
+        self.load_state(&mut app.state, &app.config);
+    }
+
+    /// Whether `config` has enough for the tool to be switched on.
+    fn is_configured(&self, config: &Config) -> bool;
+
+    fn is_enabled(&self, features: &Features) -> bool;
+
+    fn set_enabled(&self, features: &mut Features, enabled: bool);
+
+    // ── Events ────────────────────────────────────────────────────────────────
+
+    /// Handles `event` if it is one of the tool's own, handing it back otherwise.
+    fn handle_event(&self, app: &mut App, event: Event) -> Option<Event>;
+
+    /// Handles a global key, such as copy or open in browser, while the tool is shown.
+    fn handle_generic_event(&self, _app: &mut App, _event: GenericEvent) {}
+
+    /// Runs on every tick, whichever tool is shown.
+    fn handle_tick(&self, _app: &mut App) {}
+
+    /// Events to send on a schedule, each first sent at startup.
+    fn jobs(&self) -> Vec<Job> {
+        Vec::new()
+    }
+
+    // ── Keys ──────────────────────────────────────────────────────────────────
+
+    fn register_bindings(&self, key_event_map: &mut KeyEventMap);
+
+    /// The key contexts to resolve keys in while the tool has focus, most specific first.
+    fn key_contexts(&self, state: &AppState) -> Vec<KeyContext>;
+
+    /// The key context while the tool's config section has focus.
+    fn config_key_context(&self, state: &AppState) -> KeyContext;
+
+    // ── Rendering ─────────────────────────────────────────────────────────────
+
+    fn render(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config);
+
+    /// The two footer lines while the tool has focus.
+    fn hints(&self, state: &AppState) -> (Line<'static>, Line<'static>);
+
+    // ── Config section ────────────────────────────────────────────────────────
+
+    fn render_config(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config);
+
+    /// The two footer lines while the tool's config section has focus.
+    fn config_hints(&self, state: &AppState) -> (Line<'static>, Line<'static>);
+
+    fn has_open_config_form(&self, state: &AppState) -> bool;
+
+    fn close_config_form(&self, state: &mut AppState);
+}
+
+/// An event a tool sends itself on a fixed interval.
+pub struct Job {
+    pub every: Duration,
+    pub event: Event,
+}
+
+pub fn all() -> &'static [&'static dyn Tool] {
+    &TOOLS
+}
+
+pub fn get(id: ToolId) -> &'static dyn Tool {
+    TOOLS
+        .iter()
+        .copied()
+        .find(|tool| tool.id() == id)
+        .expect("every tool is registered")
+}
+
+/// Hands `event` to each tool in turn until one handles it.
+pub fn dispatch(app: &mut App, event: Event) {
+    let mut event = event;
+    for tool in all() {
+        match tool.handle_event(app, event) {
+            Some(unhandled) => event = unhandled,
+            None => return,
+        }
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_tool_id_is_registered_once() {
+        let ids = all().iter().map(|tool| tool.id()).collect::<HashSet<_>>();
+        assert_eq!(ids.len(), TOOLS.len());
+        for id in [ToolId::ServiceStatus, ToolId::TokenGenerator, ToolId::Jira] {
+            assert_eq!(get(id).id(), id);
+        }
+    }
+
+    #[test]
+    fn features_round_trip_through_each_tool() {
+        let mut features = Features::default();
+        for tool in all() {
+            tool.set_enabled(&mut features, false);
+            assert!(!tool.is_enabled(&features));
+        }
+        assert!(!features.service_status && !features.token_generator && !features.jira);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::app::App;
+use crate::config::model::{Config, Features};
+use crate::environment::Environment;
+use crate::event::events::{Event, GenericEvent, ServiceStatusEvent};
+use crate::event::handlers::config::service_status as service_status_config;
+use crate::event::handlers::tools::service_status;
+use crate::input::key_bindings::register_service_status_bindings;
+use crate::input::key_context::KeyContext;
+use crate::input::key_event_map::KeyEventMap;
+use crate::state::app::{AppState, ToolId};
+use crate::state::service_status::ServiceStatus;
+use crate::tools::{Job, SCAN_INTERVAL, Tool};
+use crate::ui::widgets::config::service_status as config_widget;
+use crate::ui::widgets::footer;
+use crate::ui::widgets::tools::service_status as widget;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+
+/// Health checks and deployed commits for each service across environments.
+pub struct ServiceStatusTool;
+
+impl Tool for ServiceStatusTool {
+    fn id(&self) -> ToolId {
+        ToolId::ServiceStatus
+    }
+
+    fn title(&self) -> &'static str {
+        "Service Status"
+    }
+
+    fn menu_entry(&self) -> &'static str {
+        "Service Status"
+    }
+
+    fn load_state(&self, state: &mut AppState, config: &Config) {
+        state.service_status = ServiceStatus::new(config.servicestatus.len());
+    }
+
+    fn reload_state(&self, app: &mut App, old: &Config) {
+        let rescan = app
+            .state
+            .service_status
+            .reload(&old.servicestatus, &app.config.servicestatus);
+        for service_idx in rescan {
+            for env in [
+                Environment::Staging,
+                Environment::Preproduction,
+                Environment::Production,
+            ] {
+                app.event_sender
+                    .send_service_status_event(ServiceStatusEvent::ScanServiceEnv(
+                        service_idx,
+                        env,
+                    ));
+            }
+        }
+    }
+
+    fn is_configured(&self, config: &Config) -> bool {
+        !config.servicestatus.is_empty()
+    }
+
+    fn is_enabled(&self, features: &Features) -> bool {
+        features.service_status
+    }
+
+    fn set_enabled(&self, features: &mut Features, enabled: bool) {
+        features.service_status = enabled;
+    }
+
+    fn handle_event(&self, app: &mut App, event: Event) -> Option<Event> {
+        match event {
+            Event::ServiceStatus(event) => service_status::handle_event(app, event),
+            Event::ServiceStatusConfig(event) => service_status_config::handle_event(app, event),
+            event => return Some(event),
+        }
+        None
+    }
+
+    fn handle_generic_event(&self, app: &mut App, event: GenericEvent) {
+        service_status::handle_generic_event(app, event);
+    }
+
+    fn jobs(&self) -> Vec<Job> {
+        vec![Job {
+            every: SCAN_INTERVAL,
+            event: ServiceStatusEvent::Scan.into(),
+        }]
+    }
+
+    fn register_bindings(&self, key_event_map: &mut KeyEventMap) {
+        register_service_status_bindings(key_event_map);
+    }
+
+    fn key_contexts(&self, _state: &AppState) -> Vec<KeyContext> {
+        vec![
+            KeyContext::Tool(ToolId::ServiceStatus),
+            KeyContext::ToolIgnore(ToolId::TokenGenerator),
+        ]
+    }
+
+    fn config_key_context(&self, state: &AppState) -> KeyContext {
+        if state.service_status_config_editor.has_open_form() {
+            KeyContext::Editing(ToolId::ServiceStatus)
+        } else {
+            KeyContext::ToolConfig(ToolId::ServiceStatus)
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config) {
+        widget::render(
+            frame,
+            area,
+            &mut state.service_status,
+            &config.servicestatus,
+        );
+    }
+
+    fn hints(&self, state: &AppState) -> (Line<'static>, Line<'static>) {
+        footer::service_status_tool_hints(state)
+    }
+
+    fn render_config(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config) {
+        config_widget::render(
+            frame,
+            area,
+            &mut state.service_status_config_editor,
+            &config.servicestatus,
+        );
+    }
+
+    fn config_hints(&self, state: &AppState) -> (Line<'static>, Line<'static>) {
+        footer::service_status_config_hints(state)
+    }
+
+    fn has_open_config_form(&self, state: &AppState) -> bool {
+        state.service_status_config_editor.has_open_form()
+    }
+
+    fn close_config_form(&self, state: &mut AppState) {
+        state.service_status_config_editor.close_form();
+    }
+}
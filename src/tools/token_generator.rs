@@ -0,0 +1,128 @@
+use crate::app::App;
+use crate::config::model::{Config, Features};
+use crate::event::events::{Event, GenericEvent};
+use crate::event::handlers::config::token_generator as token_generator_config;
+use crate::event::handlers::tools::token_generator;
+use crate::input::key_bindings::register_token_generator_bindings;
+use crate::input::key_context::KeyContext;
+use crate::input::key_event_map::KeyEventMap;
+use crate::state::app::{AppState, ToolId};
+use crate::state::token_generator::{Focus, TokenGenerator};
+use crate::tools::Tool;
+use crate::ui::widgets::config::token_generator as config_widget;
+use crate::ui::widgets::footer;
+use crate::ui::widgets::tools::token_generator as widget;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+
+/// Auth0 machine-to-machine tokens for each service and environment.
+pub struct TokenGeneratorTool;
+
+impl Tool for TokenGeneratorTool {
+    fn id(&self) -> ToolId {
+        ToolId::TokenGenerator
+    }
+
+    fn title(&self) -> &'static str {
+        "M2M Auth0 Token Generator"
+    }
+
+    fn menu_entry(&self) -> &'static str {
+        "Token Generator"
+    }
+
+    fn load_state(&self, state: &mut AppState, config: &Config) {
+        state.token_generator = TokenGenerator::new(&config.tokengenerator.services);
+    }
+
+    fn reload_state(&self, app: &mut App, old: &Config) {
+        app.state.token_generator.reload(
+            &old.tokengenerator.services,
+            &app.config.tokengenerator.services,
+        );
+    }
+
+    fn is_configured(&self, config: &Config) -> bool {
+        !config.tokengenerator.services.is_empty()
+    }
+
+    fn is_enabled(&self, features: &Features) -> bool {
+        features.token_generator
+    }
+
+    fn set_enabled(&self, features: &mut Features, enabled: bool) {
+        features.token_generator = enabled;
+    }
+
+    fn handle_event(&self, app: &mut App, event: Event) -> Option<Event> {
+        match event {
+            Event::TokenGenerator(event) => token_generator::handle_event(app, event),
+            Event::TokenGeneratorConfig(event) => token_generator_config::handle_event(app, event),
+            event => return Some(event),
+        }
+        None
+    }
+
+    fn handle_generic_event(&self, app: &mut App, event: GenericEvent) {
+        token_generator::handle_generic_event(app, event);
+    }
+
+    fn register_bindings(&self, key_event_map: &mut KeyEventMap) {
+        register_token_generator_bindings(key_event_map);
+    }
+
+    fn key_contexts(&self, state: &AppState) -> Vec<KeyContext> {
+        match state.token_generator.focus {
+            // The paste input swallows every character, so tool keys must not fire.
+            Focus::Paste => vec![KeyContext::TokenGen(Focus::Paste)],
+            focus => vec![
+                KeyContext::Tool(ToolId::TokenGenerator),
+                KeyContext::TokenGen(focus),
+            ],
+        }
+    }
+
+    fn config_key_context(&self, state: &AppState) -> KeyContext {
+        if state.token_generator_config_editor.has_open_form() {
+            KeyContext::Editing(ToolId::TokenGenerator)
+        } else {
+            KeyContext::ToolConfig(ToolId::TokenGenerator)
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config) {
+        widget::render(
+            frame,
+            area,
+            &mut state.token_generator,
+            &config.tokengenerator.services,
+        );
+    }
+
+    fn hints(&self, state: &AppState) -> (Line<'static>, Line<'static>) {
+        footer::token_generator_tool_hints(state)
+    }
+
+    fn render_config(&self, frame: &mut Frame, area: Rect, state: &mut AppState, config: &Config) {
+        config_widget::render(
+            frame,
+            area,
+            &mut state.token_generator_config_editor,
+            &config.tokengenerator.auth0,
+            &config.tokengenerator.services,
+        );
+    }
+
+    fn config_hints(&self, state: &AppState) -> (Line<'static>, Line<'static>) {
+        footer::token_generator_config_hints(state)
+    }
+
+    fn has_open_config_form(&self, state: &AppState) -> bool {
+        state.token_generator_config_editor.has_open_form()
+    }
+
+    fn close_config_form(&self, state: &mut AppState) {
+        state.token_generator_config_editor.close_form();
+    }
+}
@@ -85,7 +85,7 @@ mod tests {
 
     #[test_case(AppFocus::List, false)]
     #[test_case(AppFocus::Tool, true)]
-    #[test_case(AppFocus::ToolConfig(crate::app::Tool::ServiceStatus), true)]
+    #[test_case(AppFocus::ToolConfig(crate::app::ToolId::ServiceStatus), true)]
     fn tool_has_focus_returns_expected(focus: AppFocus, expected: bool) {
         assert_eq!(tool_has_focus(focus), expected)
     }
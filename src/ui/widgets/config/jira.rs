@@ -22,9 +22,14 @@ pub fn render(
 }
 
 fn render_values_section(frame: &mut Frame, area: Rect, config: Option<&JiraConfig>) {
-    let (url, email, token) = match config {
-        Some(c) => (c.url.as_str(), c.email.as_str(), c.token.as_str()),
-        None => ("", "", ""),
+    let (url, email, token, deployment) = match config {
+        Some(c) => (
+            c.url.as_str(),
+            c.email.as_str(),
+            c.token.as_str(),
+            c.deployment.to_string(),
+        ),
+        None => ("", "", "", String::new()),
     };
 
     // Mask the token in display mode — shown in full only when editing.
@@ -38,6 +43,7 @@ fn render_values_section(frame: &mut Frame, area: Rect, config: Option<&JiraConf
         display_line("URL  ", url),
         display_line("Email", email),
         display_line("Token", token_display),
+        display_line("Type ", &deployment),
     ];
     frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
 }
@@ -1,5 +1,6 @@
-use crate::state::app::{AppFocus, AppState, Tool};
-use crate::state::token_generator::Token;
+use crate::state::app::{AppFocus, AppState};
+use crate::state::jira::Focus as JiraFocus;
+use crate::state::token_generator::{Focus, Token};
 use crate::ui::styles::{key_desc_style, key_style};
 use ratatui::Frame;
 use ratatui::layout::Rect;
@@ -27,21 +28,45 @@ enum Hint {
     // Actions
     Toggle,
     Add,
+    Import,
+    NewIssue,
     Edit,
     Remove,
     Scan,
+    WatchTickets,
     Generate,
     OpenInBrowser,
     CopyUrl,
     CopyToken,
+    Verify,
+    PasteToken,
+    VerifyPasted,
     Retry,
     MoveItem,
+    Arrange,
+    Filter,
+    Restore,
+    Board,
+    MoveCard,
+    SwitchTab,
+    Transition,
+    People,
+    Branch,
+    CommitPrefix,
+    Comment,
+    LogWork,
+    Timer,
+    Notes,
+    Details,
+    Scroll,
+    Close,
     // Form
     Save,
     NextField,
     NavigateFields,
     Cancel,
     Submit,
+    PostComment,
     // Popup
     Dismiss,
     // One-off with custom text (key_text, desc_text)
@@ -60,9 +85,15 @@ impl Hint {
             Hint::Logs => vec![Span::styled("[3]", k), Span::styled(" Logs  ", d)],
             Hint::Toggle => vec![Span::styled("[return]", k), Span::styled(" Toggle  ", d)],
             Hint::Add => vec![Span::styled("[a]", k), Span::styled(" Add  ", d)],
+            Hint::Import => vec![
+                Span::styled("[i/f]", k),
+                Span::styled(" Import clipboard/file  ", d),
+            ],
+            Hint::NewIssue => vec![Span::styled("[n]", k), Span::styled(" New ticket  ", d)],
             Hint::Edit => vec![Span::styled("[e]", k), Span::styled(" Edit  ", d)],
             Hint::Remove => vec![Span::styled("[x]", k), Span::styled(" Remove  ", d)],
             Hint::Scan => vec![Span::styled("[s]", k), Span::styled(" Scan  ", d)],
+            Hint::WatchTickets => vec![Span::styled("[w]", k), Span::styled(" Watch tickets  ", d)],
             Hint::Generate => vec![Span::styled("[return]", k), Span::styled(" Generate  ", d)],
             Hint::OpenInBrowser => vec![
                 Span::styled("[o]", k),
@@ -70,8 +101,43 @@ impl Hint {
             ],
             Hint::CopyUrl => vec![Span::styled("[c]", k), Span::styled(" Copy url  ", d)],
             Hint::CopyToken => vec![Span::styled("[c]", k), Span::styled(" Copy token  ", d)],
+            Hint::Verify => vec![Span::styled("[v]", k), Span::styled(" Verify  ", d)],
+            Hint::PasteToken => vec![Span::styled("[p]", k), Span::styled(" Paste token  ", d)],
+            Hint::VerifyPasted => vec![Span::styled("[return]", k), Span::styled(" Verify  ", d)],
             Hint::Retry => vec![Span::styled("[return]", k), Span::styled(" Retry  ", d)],
             Hint::MoveItem => vec![Span::styled("[shift+↑↓]", k), Span::styled(" Move  ", d)],
+            Hint::Arrange => vec![
+                Span::styled("[/ z h]", k),
+                Span::styled(" Filter/sort/group  ", d),
+            ],
+            Hint::Filter => vec![Span::styled("[/]", k), Span::styled(" Filter  ", d)],
+            Hint::Restore => vec![Span::styled("[u]", k), Span::styled(" Restore  ", d)],
+            Hint::Board => vec![Span::styled("[v]", k), Span::styled(" Board  ", d)],
+            Hint::MoveCard => vec![
+                Span::styled("[shift+←→]", k),
+                Span::styled(" Move card  ", d),
+            ],
+            Hint::Details => vec![Span::styled("[return]", k), Span::styled(" Details  ", d)],
+            Hint::Scroll => vec![
+                Span::styled("[↑↓ PgUp PgDn]", k),
+                Span::styled(" Scroll  ", d),
+            ],
+            Hint::Close => vec![Span::styled("[esc]", k), Span::styled(" Close  ", d)],
+            Hint::Branch => vec![
+                Span::styled("[b/g]", k),
+                Span::styled(" Copy/check out branch  ", d),
+            ],
+            Hint::CommitPrefix => vec![Span::styled("[m]", k), Span::styled(" Commit prefix  ", d)],
+            Hint::Comment => vec![Span::styled("[r]", k), Span::styled(" Comment  ", d)],
+            Hint::LogWork => vec![Span::styled("[w]", k), Span::styled(" Log work  ", d)],
+            Hint::Notes => vec![Span::styled("[e]", k), Span::styled(" Notes  ", d)],
+            Hint::Timer => vec![
+                Span::styled("[s]", k),
+                Span::styled(" Start/stop timer  ", d),
+            ],
+            Hint::Transition => vec![Span::styled("[t]", k), Span::styled(" Transition  ", d)],
+            Hint::People => vec![Span::styled("[p]", k), Span::styled(" Assign/watch  ", d)],
+            Hint::SwitchTab => vec![Span::styled("[tab]", k), Span::styled(" Switch list  ", d)],
             Hint::Save => vec![Span::styled("[return]", k), Span::styled(" Save  ", d)],
             Hint::NextField => vec![Span::styled("[tab]", k), Span::styled(" Next field  ", d)],
             Hint::NavigateFields => vec![
@@ -79,6 +145,7 @@ impl Hint {
                 Span::styled(" Navigate fields  ", d),
             ],
             Hint::Cancel => vec![Span::styled("[esc]", k), Span::styled(" Cancel  ", d)],
+            Hint::PostComment => vec![Span::styled("[ctrl+s]", k), Span::styled(" Post  ", d)],
             Hint::Submit => vec![
                 Span::styled("[return]", k),
                 Span::styled(" Add ticket  ", d),
@@ -104,11 +171,7 @@ fn build_lines(state: &AppState) -> (Line<'static>, Line<'static>) {
             hints(&[Hint::Navigate, Hint::Config, Hint::Logs, Hint::Quit]),
             Line::from(""),
         ),
-        AppFocus::Tool => match state.current_tool {
-            Tool::ServiceStatus => service_status_tool_hints(state),
-            Tool::TokenGenerator => token_generator_tool_hints(state),
-            Tool::Jira => jira_tool_hints(state),
-        },
+        AppFocus::Tool => state.current_tool.tool().hints(state),
         AppFocus::Config => (
             hints(&[
                 Hint::Navigate,
@@ -124,41 +187,157 @@ fn build_lines(state: &AppState) -> (Line<'static>, Line<'static>) {
             hints(&[Hint::Navigate, Hint::Tools, Hint::Config, Hint::Quit]),
             Line::from(""),
         ),
-        AppFocus::ToolConfig(Tool::ServiceStatus) => service_status_config_hints(state),
-        AppFocus::ToolConfig(Tool::TokenGenerator) => token_generator_config_hints(state),
-        AppFocus::ToolConfig(Tool::Jira) => jira_config_hints(state),
+        AppFocus::ToolConfig(tool) => tool.tool().config_hints(state),
     }
 }
 
-fn service_status_tool_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+pub(crate) fn service_status_tool_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
     let line2 = if state.service_status.has_link() {
         hints(&[Hint::OpenInBrowser, Hint::CopyUrl])
     } else {
         Line::from("")
     };
-    (hints(&[Hint::Navigate, Hint::Scan, Hint::Quit]), line2)
+    let line1 = if state.service_status.selected_pending_tickets().is_empty() {
+        hints(&[Hint::Navigate, Hint::Scan, Hint::Quit])
+    } else {
+        hints(&[Hint::Navigate, Hint::Scan, Hint::WatchTickets, Hint::Quit])
+    };
+    (line1, line2)
 }
 
-fn token_generator_tool_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+pub(crate) fn token_generator_tool_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+    if state.token_generator.focus == Focus::Paste {
+        return (hints(&[Hint::VerifyPasted, Hint::Cancel]), Line::from(""));
+    }
     let line2 = match state.token_generator.get_token_for_selected_service_env() {
-        Token::Idle => Line::from(""),
+        Token::Idle => hints(&[Hint::PasteToken]),
         Token::Requesting => hints(&[Hint::Status("Generating token…")]),
-        Token::Ready(_) => hints(&[Hint::CopyToken]),
-        Token::Error => hints(&[Hint::Retry]),
+        Token::Ready(_) => hints(&[Hint::CopyToken, Hint::Verify, Hint::PasteToken]),
+        Token::Error => hints(&[Hint::Retry, Hint::PasteToken]),
     };
     (hints(&[Hint::Navigate, Hint::Generate, Hint::Quit]), line2)
 }
 
-fn jira_tool_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
-    let line2 = if state.jira.list_state.selected().is_some() {
-        hints(&[Hint::Remove, Hint::OpenInBrowser, Hint::MoveItem])
+pub(crate) fn jira_tool_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+    if state.jira.focus == JiraFocus::Create {
+        return (
+            hints(&[Hint::Save, Hint::NextField, Hint::Cancel]),
+            Line::from(""),
+        );
+    }
+    if matches!(state.jira.focus, JiraFocus::Worklog | JiraFocus::Notes) {
+        return (
+            hints(&[Hint::Save, Hint::NextField, Hint::Cancel]),
+            Line::from(""),
+        );
+    }
+    if state.jira.focus == JiraFocus::Comment {
+        return (hints(&[Hint::PostComment, Hint::Cancel]), Line::from(""));
+    }
+    if state.jira.focus == JiraFocus::Detail {
+        return (
+            hints(&[Hint::Scroll, Hint::Close, Hint::Quit]),
+            hints(&[Hint::Transition, Hint::OpenInBrowser]),
+        );
+    }
+    let line2 = match state.jira.selected_ticket() {
+        Some(_) if state.jira.is_archive_tab() => hints(&[
+            Hint::Details,
+            Hint::Restore,
+            Hint::Remove,
+            Hint::OpenInBrowser,
+        ]),
+        Some(_) if state.jira.is_watchlist_tab() && state.jira.is_manual_order() => hints(&[
+            Hint::Details,
+            Hint::Transition,
+            Hint::People,
+            Hint::Comment,
+            Hint::LogWork,
+            Hint::Timer,
+            Hint::Notes,
+            Hint::Branch,
+            Hint::CommitPrefix,
+            Hint::Remove,
+            Hint::OpenInBrowser,
+            Hint::MoveItem,
+        ]),
+        Some(_) if state.jira.is_watchlist_tab() => hints(&[
+            Hint::Details,
+            Hint::Transition,
+            Hint::People,
+            Hint::Comment,
+            Hint::LogWork,
+            Hint::Timer,
+            Hint::Notes,
+            Hint::Branch,
+            Hint::CommitPrefix,
+            Hint::Remove,
+            Hint::OpenInBrowser,
+        ]),
+        Some(_) => hints(&[
+            Hint::Details,
+            Hint::Transition,
+            Hint::People,
+            Hint::Comment,
+            Hint::LogWork,
+            Hint::Branch,
+            Hint::CommitPrefix,
+            Hint::OpenInBrowser,
+        ]),
+        None => Line::from(""),
+    };
+    // The archive gets a tab once anything is in it, as saved queries do.
+    let has_tabs = !state.jira.queries.is_empty() || !state.jira.archive.tickets.is_empty();
+    let line1 = if state.jira.focus == JiraFocus::Board {
+        if !has_tabs {
+            hints(&[Hint::Navigate, Hint::MoveCard, Hint::Close, Hint::Quit])
+        } else {
+            hints(&[
+                Hint::Navigate,
+                Hint::MoveCard,
+                Hint::SwitchTab,
+                Hint::Close,
+                Hint::Quit,
+            ])
+        }
+    } else if state.jira.is_archive_tab() {
+        hints(&[Hint::Navigate, Hint::SwitchTab, Hint::Filter, Hint::Quit])
+    } else if !has_tabs {
+        hints(&[
+            Hint::Navigate,
+            Hint::Add,
+            Hint::Import,
+            Hint::NewIssue,
+            Hint::Arrange,
+            Hint::Board,
+            Hint::Quit,
+        ])
+    } else if state.jira.is_watchlist_tab() {
+        hints(&[
+            Hint::Navigate,
+            Hint::SwitchTab,
+            Hint::Add,
+            Hint::Import,
+            Hint::NewIssue,
+            Hint::Arrange,
+            Hint::Board,
+            Hint::Quit,
+        ])
     } else {
-        Line::from("")
+        hints(&[
+            Hint::Navigate,
+            Hint::SwitchTab,
+            Hint::Add,
+            Hint::Import,
+            Hint::NewIssue,
+            Hint::Board,
+            Hint::Quit,
+        ])
     };
-    (hints(&[Hint::Navigate, Hint::Add, Hint::Quit]), line2)
+    (line1, line2)
 }
 
-fn service_status_config_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+pub(crate) fn service_status_config_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
     if state.service_status_config_editor.has_open_form() {
         return edit_form_lines();
     }
@@ -175,7 +354,7 @@ fn service_status_config_hints(state: &AppState) -> (Line<'static>, Line<'static
     (hints(&[Hint::Navigate, Hint::Add, Hint::Quit]), line2)
 }
 
-fn token_generator_config_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+pub(crate) fn token_generator_config_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
     use crate::state::token_generator_config::ConfigFocus;
     if state.token_generator_config_editor.has_open_form() {
         return edit_form_lines();
@@ -198,7 +377,7 @@ fn token_generator_config_hints(state: &AppState) -> (Line<'static>, Line<'stati
     }
 }
 
-fn jira_config_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
+pub(crate) fn jira_config_hints(state: &AppState) -> (Line<'static>, Line<'static>) {
     if state.jira_config_editor.has_open_form() {
         return edit_form_lines();
     }
@@ -10,6 +10,10 @@ pub enum Type {
     Error,
     Confirm,
     Success,
+    /// Lists every action on its own line for the user to pick from.
+    Select,
+    /// A reminder the user set on a ticket.
+    Reminder,
 }
 
 pub enum Part {
@@ -22,6 +26,8 @@ pub fn render(frame: &mut Frame, popup: &Popup) {
         Type::Error => Color::Red,
         Type::Confirm => Color::Gray,
         Type::Success => Color::Green,
+        Type::Select => Color::Cyan,
+        Type::Reminder => Color::Yellow,
     };
 
     let style = Style::default().fg(color).add_modifier(Modifier::BOLD);
@@ -39,14 +45,23 @@ pub fn render(frame: &mut Frame, popup: &Popup) {
             })
             .collect::<Vec<_>>(),
     );
-    let content = Paragraph::new(vec![
+    let mut lines = vec![
         Line::from(Span::styled(popup.title.as_str(), style)),
         Line::from(""),
-        body_line,
-    ])
-    .block(block);
+    ];
+    if let Type::Select = popup.popup_type {
+        lines.extend(popup.actions.iter().map(|action| {
+            Line::from(vec![
+                Span::styled(format!("[{}]", action.key), key),
+                Span::raw(format!(" {}", action.label)),
+            ])
+        }));
+    }
+    lines.push(body_line);
+    let height = lines.len() as u16 + 2;
+    let content = Paragraph::new(lines).block(block);
 
-    let area = overlay_area(frame.area(), 40, 5);
+    let area = overlay_area(frame.area(), 40, height);
     frame.render_widget(Clear, area);
     frame.render_widget(content, area);
 }
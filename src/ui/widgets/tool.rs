@@ -1,13 +1,11 @@
 use crate::config::model::Config;
-use crate::state::app::{AppFocus, AppState, Tool};
+use crate::state::app::{AppFocus, AppState, ToolId};
 use crate::ui::styles;
-use crate::ui::widgets::config;
-use crate::ui::widgets::tools::{jira, service_status, token_generator};
 use ratatui::Frame;
 use ratatui::prelude::Alignment;
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 pub fn render(
     frame: &mut Frame,
@@ -18,94 +16,34 @@ pub fn render(
     let content_block_border_style =
         styles::block_style(styles::tool_has_focus(state.effective_focus()));
 
-    // ── Config preview mode (AppFocus::Config) ───────────────────────────────
-    if state.effective_focus() == AppFocus::Config
-        && let Some(idx) = state.config_editor.list_state.selected()
-        && let Some(item) = state.config_editor.items.get(idx)
-    {
-        let tool = item.tool;
-        let title = match tool {
-            Tool::ServiceStatus => " Service Status — Config ",
-            Tool::TokenGenerator => " Token Generator — Config ",
-            Tool::Jira => " Jira — Config ",
-        };
+    // ── Config preview (AppFocus::Config) and Tool Config (AppFocus::ToolConfig) ──
+    let config_tool = match state.effective_focus() {
+        AppFocus::Config => (state.config_editor.list_state.selected())
+            .and_then(|idx| state.config_editor.items.get(idx))
+            .map(|item| item.tool),
+        AppFocus::ToolConfig(tool) => Some(tool),
+        _ => None,
+    };
+    if let Some(tool) = config_tool.map(ToolId::tool) {
         let pane = Block::default()
             .borders(Borders::ALL)
             .border_style(content_block_border_style)
-            .title(title);
+            .title(format!(" {} — Config ", tool.menu_entry()));
         let inner = pane.inner(area);
         frame.render_widget(pane, area);
-        match tool {
-            Tool::ServiceStatus => {
-                config::service_status::render(
-                    frame,
-                    inner,
-                    &mut state.service_status_config_editor,
-                    &config_data.servicestatus,
-                );
-            }
-            Tool::TokenGenerator => {
-                config::token_generator::render(
-                    frame,
-                    inner,
-                    &mut state.token_generator_config_editor,
-                    &config_data.tokengenerator.auth0,
-                    &config_data.tokengenerator.services,
-                );
-            }
-            Tool::Jira => {
-                config::jira::render(
-                    frame,
-                    inner,
-                    &mut state.jira_config_editor,
-                    config_data.jira.as_ref(),
-                );
-            }
-        }
+        tool.render_config(frame, inner, state, config_data);
         return;
     }
 
-    // ── Tool Config mode (AppFocus::ToolConfig) ──────────────────────────────
-    if let AppFocus::ToolConfig(tool) = state.effective_focus() {
-        let title = match tool {
-            Tool::ServiceStatus => " Service Status — Config ",
-            Tool::TokenGenerator => " Token Generator — Config ",
-            Tool::Jira => " Jira — Config ",
-        };
+    // ── Config broken ────────────────────────────────────────────────────────
+    if !state.config_problems.is_empty() {
         let pane = Block::default()
             .borders(Borders::ALL)
-            .border_style(content_block_border_style)
-            .title(title);
+            .border_style(Style::default().fg(Color::Red))
+            .title(" Config broken ");
         let inner = pane.inner(area);
         frame.render_widget(pane, area);
-
-        match tool {
-            Tool::ServiceStatus => {
-                config::service_status::render(
-                    frame,
-                    inner,
-                    &mut state.service_status_config_editor,
-                    &config_data.servicestatus,
-                );
-            }
-            Tool::TokenGenerator => {
-                config::token_generator::render(
-                    frame,
-                    inner,
-                    &mut state.token_generator_config_editor,
-                    &config_data.tokengenerator.auth0,
-                    &config_data.tokengenerator.services,
-                );
-            }
-            Tool::Jira => {
-                config::jira::render(
-                    frame,
-                    inner,
-                    &mut state.jira_config_editor,
-                    config_data.jira.as_ref(),
-                );
-            }
-        }
+        render_config_problems(frame, inner, state);
         return;
     }
 
@@ -140,19 +78,30 @@ pub fn render(
 
     frame.render_widget(pane, area);
 
-    match state.current_tool {
-        Tool::ServiceStatus => service_status::render(
-            frame,
-            inner,
-            &mut state.service_status,
-            &config_data.servicestatus,
-        ),
-        Tool::TokenGenerator => token_generator::render(
-            frame,
-            inner,
-            &mut state.token_generator,
-            &config_data.tokengenerator.services,
-        ),
-        Tool::Jira => jira::render(frame, inner, &mut state.jira),
-    }
+    state
+        .current_tool
+        .tool()
+        .render(frame, inner, state, config_data);
+}
+
+/// Lists what's wrong with config.yaml. The file is reloaded as soon as it's
+/// saved, so fixing it brings the tools back.
+fn render_config_problems(frame: &mut Frame, area: ratatui::layout::Rect, state: &AppState) {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "~/.devtool/config.yaml couldn't be loaded. Fix these and save — it reloads by itself.",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::default(),
+    ];
+    lines.extend(state.config_problems.iter().map(|problem| {
+        Line::from(vec![
+            Span::styled(
+                format!("{:>4}:{:<3} ", problem.line, problem.column),
+                styles::key_style(),
+            ),
+            Span::raw(problem.message.clone()),
+        ])
+    }));
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
 }
@@ -1,16 +1,61 @@
-use crate::state::jira::Jira;
+use crate::client::jira::models::{IssueDetails, Named, Status, StatusCategory};
+use crate::config::model::JiraConfig;
+use crate::state::jira::{DetailView, Jira, QueryStatus, Ticket, TicketDetail, TicketInput};
+use crate::state::jira_board::{Board, BoardView};
+use crate::state::jira_comment::CommentForm;
+use crate::state::jira_create::{CreateField, CreateIssueForm};
+use crate::state::jira_notes::{NotesField, NotesForm, REMIND_AT_FORMAT};
+use crate::state::jira_view::{self, Row};
+use crate::state::jira_worklog::{WorklogField, WorklogForm};
 use crate::ui::styles::{edit_border_style, selection_highlight};
+use crate::utils::git::TicketBranch;
+use crate::utils::{adf, duration};
+use chrono::Local;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::Span;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Line;
-use ratatui::widgets::{Block, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Tabs, Wrap};
+use std::str::FromStr;
 use tui_text_field::TextField;
 
-pub fn render(frame: &mut Frame, area: Rect, state: &mut Jira) {
+/// How many of the most recent comments the detail pane shows.
+const DETAIL_COMMENTS: usize = 5;
+/// How many of the most recent field changes the detail pane shows.
+const DETAIL_HISTORY: usize = 10;
+
+pub fn render(frame: &mut Frame, area: Rect, state: &mut Jira, config: Option<&JiraConfig>) {
+    if let Some(form) = &state.create {
+        render_create_form(frame, area, form);
+        return;
+    }
+    if let Some(form) = &state.comment {
+        render_comment_form(frame, area, form);
+        return;
+    }
+    if let Some(form) = &state.worklog {
+        render_worklog_form(frame, area, form);
+        return;
+    }
+    if let Some(form) = &state.notes {
+        render_notes_form(frame, area, form);
+        return;
+    }
+
     let adding_ticket = state.adding_ticket;
 
+    // The detail pane takes the right-hand side, keeping the list visible beside it.
+    let area = match state.detail.as_mut() {
+        Some(detail) => {
+            let columns =
+                Layout::horizontal([Constraint::Percentage(40), Constraint::Fill(1)]).split(area);
+            render_detail(frame, columns[1], detail, config);
+            columns[0]
+        }
+        None => area,
+    };
+
     // When adding a ticket, reserve 3 rows for the inline input; otherwise use full area.
     let ticket_area = if adding_ticket {
         let vertical = Layout::default()
@@ -18,58 +63,434 @@ pub fn render(frame: &mut Frame, area: Rect, state: &mut Jira) {
             .constraints([Constraint::Min(0), Constraint::Length(3)])
             .split(area);
         let input_area = vertical[1];
-        render_add_ticket_input(frame, input_area, &state.new_ticket_id);
+        // With more than one site, the border shows where the tickets will go.
+        let site = config
+            .filter(|c| {
+                !c.sites.is_empty()
+                    && matches!(state.ticket_input, TicketInput::Ids | TicketInput::File)
+            })
+            .map(|_| match state.new_ticket_site.as_deref() {
+                None => "by project",
+                Some("") => "main",
+                Some(site) => site,
+            });
+        render_add_ticket_input(
+            frame,
+            input_area,
+            state.ticket_input,
+            &state.new_ticket_id,
+            site,
+        );
         vertical[0]
     } else {
         area
     };
 
-    let list_items: Vec<ListItem> = state
-        .tickets
+    // Saved queries and the archive get a tab row above the list; without them the
+    // watchlist is all there is.
+    let list_area = if state.queries.is_empty() && state.archive.tickets.is_empty() {
+        ticket_area
+    } else {
+        let vertical = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(ticket_area);
+        render_tabs(frame, vertical[0], state);
+        vertical[1]
+    };
+
+    if let Some(board) = &state.board {
+        render_board(frame, list_area, state, board);
+        return;
+    }
+
+    if state.is_archive_tab() {
+        render_archive(frame, list_area, state, config);
+        return;
+    }
+    let Some(query_idx) = state.tab.checked_sub(1) else {
+        render_watchlist(frame, list_area, state, config);
+        return;
+    };
+
+    let query = &mut state.queries[query_idx];
+    // Keep showing the last results if a refresh fails; the failure is in the logs.
+    let placeholder = match &query.status {
+        _ if !query.tickets.is_empty() => None,
+        QueryStatus::Pending => Some(("Loading…".to_string(), Color::DarkGray)),
+        QueryStatus::Failed(error) => Some((format!("Query failed: {}", error), Color::Red)),
+        QueryStatus::Loaded => Some(("No tickets match this query".to_string(), Color::DarkGray)),
+    };
+    match placeholder {
+        Some((text, color)) => frame.render_widget(
+            Paragraph::new(text).style(Style::default().fg(color)),
+            list_area,
+        ),
+        None => {
+            let list_items: Vec<ListItem> = query
+                .tickets
+                .iter()
+                .map(|t| ticket_item(t, None, state.branches.get(&t.id), config))
+                .collect();
+            render_ticket_list(frame, list_area, list_items, &mut query.list_state);
+        }
+    }
+}
+
+/// The watchlist as filtered, sorted and grouped, with a line saying how when it
+/// isn't in manual order.
+fn render_watchlist(frame: &mut Frame, area: Rect, state: &mut Jira, config: Option<&JiraConfig>) {
+    let rows = state.rows();
+    let filter = state.active_filter();
+    let mut area = area;
+    if !filter.is_empty() || !state.layout.is_manual() {
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut summary = Vec::new();
+        if !filter.is_empty() {
+            summary.push(Span::styled("Filter: ", dim));
+            summary.push(Span::raw(format!("{}  ", filter)));
+        }
+        summary.push(Span::styled("Sort: ", dim));
+        summary.push(Span::raw(format!("{}  ", state.layout.sort.label())));
+        summary.push(Span::styled("Group: ", dim));
+        summary.push(Span::raw(state.layout.group.label()));
+        let split = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+        frame.render_widget(Paragraph::new(Line::from(summary)), split[0]);
+        area = split[1];
+    }
+
+    if rows.is_empty() && !filter.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No tickets match the filter")
+                .style(Style::default().fg(Color::DarkGray)),
+            area,
+        );
+        return;
+    }
+
+    let selected = state.list_state.selected();
+    let list_items: Vec<ListItem> = rows
         .iter()
-        .map(|ticket| {
-            let status_color = match ticket.status.to_lowercase().as_str() {
-                s if s.contains("complete") => Color::Green,
-                s if s.contains("release") => Color::Magenta,
-                s if s.contains("in test") => Color::LightCyan,
-                s if s.contains("testing") => Color::Cyan,
-                s if s.contains("review") => Color::Yellow,
-                s if s.contains("progress") => Color::Blue,
-                s if s.contains("development") => Color::Gray,
-                s if s.contains("failed") => Color::Red,
-                _ => Color::DarkGray,
-            };
-
-            let mut lines: Vec<Line> = Vec::new();
-            lines.push(Line::from(vec![
-                Span::styled(ticket.id.clone(), Style::default().fg(Color::Cyan)),
-                Span::raw(format!(" - {}", ticket.title)),
-            ]));
-            lines.push(Line::from(vec![
-                Span::styled(ticket.status.clone(), Style::default().fg(status_color)),
-                Span::styled(
-                    format!("  @{}", ticket.assignee),
-                    Style::default().fg(Color::LightBlue),
-                ),
-            ]));
-            lines.push(Line::from(""));
-            ListItem::from(lines)
+        .map(|row| match row {
+            Row::Heading(heading) => ListItem::new(Line::styled(
+                heading.clone(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Row::Ticket(i) => {
+                let t = &state.tickets[*i];
+                ticket_item(
+                    t,
+                    state.scan_errors.get(&t.id),
+                    state.branches.get(&t.id),
+                    config,
+                )
+            }
         })
         .collect();
+    // The list state selects a ticket; on screen that is its row among the headings.
+    let mut display = ListState::default()
+        .with_offset(state.list_state.offset())
+        .with_selected(
+            rows.iter()
+                .position(|row| Some(row) == selected.map(Row::Ticket).as_ref()),
+        );
+    render_ticket_list(frame, area, list_items, &mut display);
+    *state.list_state.offset_mut() = display.offset();
+}
+
+/// Archived tickets, most recently archived first, narrowed by the archive's filter.
+fn render_archive(frame: &mut Frame, area: Rect, state: &mut Jira, config: Option<&JiraConfig>) {
+    let order = jira_view::ticket_order(&state.rows());
+    let filter = state.active_filter().to_string();
+    let mut area = area;
+    if !filter.is_empty() {
+        let split = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Filter: ", Style::default().fg(Color::DarkGray)),
+                Span::raw(filter),
+            ])),
+            split[0],
+        );
+        area = split[1];
+    }
+
+    if order.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No archived tickets match the filter")
+                .style(Style::default().fg(Color::DarkGray)),
+            area,
+        );
+        return;
+    }
+
+    let archive = &mut state.archive;
+    let list_items: Vec<ListItem> = order
+        .iter()
+        .map(|&i| ticket_item(&archive.tickets[i], None, None, config))
+        .collect();
+    let selected = archive.list_state.selected();
+    let mut display = ListState::default()
+        .with_offset(archive.list_state.offset())
+        .with_selected(order.iter().position(|&i| Some(i) == selected));
+    render_ticket_list(frame, area, list_items, &mut display);
+    *archive.list_state.offset_mut() = display.offset();
+}
+
+fn render_tabs(frame: &mut Frame, area: Rect, state: &Jira) {
+    let archive = Some(format!(" Archive ({}) ", state.archive.tickets.len()))
+        .filter(|_| !state.archive.tickets.is_empty());
+    let titles = std::iter::once(format!(" Watchlist ({}) ", state.tickets.len()))
+        .chain(state.queries.iter().map(|q| match q.status {
+            QueryStatus::Loaded => format!(" {} ({}) ", q.name, q.tickets.len()),
+            _ => format!(" {} ", q.name),
+        }))
+        .chain(archive);
+    frame.render_widget(
+        Tabs::new(titles)
+            .select(state.tab)
+            .highlight_style(selection_highlight())
+            .divider("│"),
+        area,
+    );
+}
+
+/// The current tab's tickets laid out in the board's columns, one card per ticket.
+fn render_board(frame: &mut Frame, area: Rect, state: &Jira, board: &Board) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let (name, columns) = match &board.view {
+        BoardView::Loading => {
+            frame.render_widget(Paragraph::new("Loading board…").style(dim), area);
+            return;
+        }
+        BoardView::Failed(error) => {
+            frame.render_widget(
+                Paragraph::new(format!("Board failed to load: {}", error))
+                    .style(Style::default().fg(Color::Red)),
+                area,
+            );
+            return;
+        }
+        BoardView::Loaded { name, columns } => (name, columns),
+    };
 
+    let tickets = state.current_tickets();
+    let layout = board.layout(tickets);
+    let off_board = tickets.len() - layout.iter().map(Vec::len).sum::<usize>();
+    let mut header = vec![Span::styled(
+        name.clone(),
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    if off_board > 0 {
+        header.push(Span::styled(
+            format!("  · {} not in any column", off_board),
+            dim,
+        ));
+    }
+    let rows = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
+    frame.render_widget(Paragraph::new(Line::from(header)), rows[0]);
+
+    if columns.is_empty() {
+        frame.render_widget(
+            Paragraph::new("This board has no columns").style(dim),
+            rows[1],
+        );
+        return;
+    }
+
+    let selected = state.selected_ticket().map(|t| t.id.as_str());
+    let areas = Layout::horizontal(vec![
+        Constraint::Ratio(1, columns.len() as u32);
+        columns.len()
+    ])
+    .split(rows[1]);
+    for ((column, cards), area) in columns.iter().zip(&layout).zip(areas.iter()) {
+        let row = cards
+            .iter()
+            .position(|&i| Some(tickets[i].id.as_str()) == selected);
+        let border = if row.is_some() {
+            Style::default().fg(Color::Cyan)
+        } else {
+            dim
+        };
+        let items = cards
+            .iter()
+            .map(|&i| card_item(&tickets[i]))
+            .collect::<Vec<_>>();
+        frame.render_stateful_widget(
+            List::new(items)
+                .highlight_style(selection_highlight())
+                .block(
+                    Block::bordered()
+                        .title(format!(" {} ({}) ", column.name, cards.len()))
+                        .border_style(border),
+                ),
+            *area,
+            &mut ListState::default().with_selected(row),
+        );
+    }
+}
+
+fn card_item(ticket: &Ticket) -> ListItem<'static> {
+    ListItem::from(vec![
+        Line::styled(ticket.id.clone(), Style::default().fg(Color::Cyan)),
+        Line::from(ticket.title.clone()),
+        Line::styled(
+            format!("@{}", ticket.assignee),
+            Style::default().fg(Color::LightBlue),
+        ),
+        Line::from(""),
+    ])
+}
+
+fn render_ticket_list(
+    frame: &mut Frame,
+    area: Rect,
+    list_items: Vec<ListItem>,
+    list_state: &mut ListState,
+) {
     frame.render_stateful_widget(
         List::new(list_items)
             .highlight_style(selection_highlight())
             .block(Block::default()),
-        ticket_area,
-        &mut state.list_state,
+        area,
+        list_state,
     );
 }
 
-fn render_add_ticket_input(frame: &mut Frame, area: Rect, field: &TextField) {
-    let block = Block::bordered()
-        .title(" Add Jira Ticket ")
+/// The colour for a status: a `status_colours` override when configured, otherwise
+/// the colour of its Jira status category.
+fn status_color(
+    config: Option<&JiraConfig>,
+    project: &str,
+    status: &str,
+    category: StatusCategory,
+) -> Color {
+    let configured = config
+        .and_then(|c| c.status_colour(project, status))
+        .and_then(|colour| Color::from_str(colour).ok());
+    configured.unwrap_or(match category {
+        StatusCategory::ToDo => Color::Gray,
+        StatusCategory::InProgress => Color::Blue,
+        StatusCategory::Done => Color::Green,
+        StatusCategory::Unknown => Color::DarkGray,
+    })
+}
+
+/// [`status_color`] for a status from an API response about issue `key`.
+fn issue_status_color(config: Option<&JiraConfig>, key: &str, status: &Status) -> Color {
+    let project = key.split_once('-').map_or(key, |(project, _)| project);
+    status_color(config, project, &status.name, status.category())
+}
+
+/// `scan_error` is set when the last refresh of the ticket failed, so what's shown may be stale.
+fn ticket_item(
+    ticket: &Ticket,
+    scan_error: Option<&String>,
+    branches: Option<&Vec<TicketBranch>>,
+    config: Option<&JiraConfig>,
+) -> ListItem<'static> {
+    let status_color = status_color(
+        config,
+        ticket.project(),
+        &ticket.status,
+        ticket.status_category,
+    );
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(vec![
+        Span::styled(ticket.id.clone(), Style::default().fg(Color::Cyan)),
+        Span::raw(format!(" - {}", ticket.title)),
+    ]));
+    let mut status_line = vec![
+        Span::styled(ticket.status.clone(), Style::default().fg(status_color)),
+        Span::styled(
+            format!("  @{}", ticket.assignee),
+            Style::default().fg(Color::LightBlue),
+        ),
+    ];
+    if ticket.watching {
+        status_line.push(Span::styled("  👁", Style::default().fg(Color::LightBlue)));
+    }
+    if let Some(started) = ticket.timer_started {
+        let elapsed = (Local::now() - started).num_seconds().max(0) as u64;
+        status_line.push(Span::styled(
+            format!("  ⏱ {}", duration::format(elapsed)),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if let Some(archived_at) = ticket.archived_at {
+        status_line.push(Span::styled(
+            format!("  archived {}", archived_at.format("%Y-%m-%d")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    lines.push(Line::from(status_line));
+    if let Some(error) = scan_error {
+        lines.push(Line::styled(
+            format!("⚠ stale — last refresh failed: {}", error),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    if !ticket.notes.is_empty() {
+        lines.push(Line::styled(
+            format!("✎ {}", ticket.notes),
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+        ));
+    }
+    if let Some(remind_at) = ticket.remind_at {
+        lines.push(Line::styled(
+            format!("⏰ {}", remind_at.format(REMIND_AT_FORMAT)),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    lines.extend(branches.into_iter().flatten().map(branch_line));
+    lines.push(Line::from(""));
+    ListItem::from(lines)
+}
+
+/// e.g. "⎇ app feat/ABC-12-login  ↑2 ↓1  2024-03-01".
+fn branch_line(branch: &TicketBranch) -> Line<'static> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut spans = vec![
+        Span::styled(format!("⎇ {} ", branch.repository), dim),
+        Span::styled(branch.branch.clone(), Style::default().fg(Color::Magenta)),
+    ];
+    if let Some((ahead, behind)) = branch.ahead_behind {
+        spans.push(Span::styled(format!("  ↑{} ↓{}", ahead, behind), dim));
+    }
+    if let Some(last_commit) = branch.last_commit {
+        spans.push(Span::styled(
+            format!("  {}", last_commit.format("%Y-%m-%d")),
+            dim,
+        ));
+    }
+    Line::from(spans)
+}
+
+fn render_add_ticket_input(
+    frame: &mut Frame,
+    area: Rect,
+    input: TicketInput,
+    field: &TextField,
+    site: Option<&str>,
+) {
+    let title = match input {
+        TicketInput::Ids => " Add Jira Tickets — IDs or ranges, e.g. ABC-1, ABC-7..9 ",
+        TicketInput::File => " Import Jira Tickets from file ",
+        TicketInput::Filter => " Filter — ID, title, assignee or status ",
+        TicketInput::Assignee => " Assign to — search people by name ",
+    };
+    let mut block = Block::bordered()
+        .title(title)
         .border_style(edit_border_style());
+    if let Some(site) = site {
+        block = block.title(Line::from(format!(" [tab] Site: {} ", site)).right_aligned());
+    }
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -86,3 +507,416 @@ fn render_add_ticket_input(frame: &mut Frame, area: Rect, field: &TextField) {
     let char_offset = field.value()[..field.cursor()].chars().count() as u16;
     frame.set_cursor_position((inner.x + char_offset, inner.y));
 }
+
+fn render_create_form(frame: &mut Frame, area: Rect, form: &CreateIssueForm) {
+    let block = Block::bordered()
+        .title(" New Jira Ticket ")
+        .border_style(edit_border_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let af = form.active_field;
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![
+        form_line("Project", form.project.value(), af == CreateField::Project),
+        form_line(
+            "Type",
+            form.issue_type.value(),
+            af == CreateField::IssueType,
+        ),
+        form_line("Summary", form.summary.value(), af == CreateField::Summary),
+        form_line(
+            "Description",
+            form.description.value(),
+            af == CreateField::Description,
+        ),
+        form_line(
+            "Assignee",
+            form.assignee.value(),
+            af == CreateField::Assignee,
+        ),
+        form_line("Labels", form.labels.value(), af == CreateField::Labels),
+        Line::from(""),
+        Line::styled(
+            "Assignee: \"me\", an account ID / username, or blank. Labels: comma separated.",
+            dim,
+        ),
+    ];
+    if form.submitting {
+        lines.push(Line::styled("Creating…", dim));
+    } else if let Some(error) = &form.error {
+        lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red)));
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+
+    // One field per row, each "{label:<11}: {value}" → value starts at column 13.
+    let row = match af {
+        CreateField::Project => 0,
+        CreateField::IssueType => 1,
+        CreateField::Summary => 2,
+        CreateField::Description => 3,
+        CreateField::Assignee => 4,
+        CreateField::Labels => 5,
+    };
+    let field = form.active_field();
+    let char_offset = field.value()[..field.cursor()].chars().count() as u16;
+    frame.set_cursor_position((inner.x + 13 + char_offset, inner.y + row));
+}
+
+fn render_comment_form(frame: &mut Frame, area: Rect, form: &CommentForm) {
+    let block = Block::bordered()
+        .title(format!(" Comment on {} ", form.ticket_id))
+        .border_style(edit_border_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(inner);
+    // No wrapping, so each line of the body is one row and the cursor lines up.
+    frame.render_widget(
+        Paragraph::new(form.body.value().to_string()).style(
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        rows[0],
+    );
+
+    let dim = Style::default().fg(Color::DarkGray);
+    let status = if form.submitting {
+        Line::styled("Posting…", dim)
+    } else if let Some(error) = &form.error {
+        Line::styled(error.clone(), Style::default().fg(Color::Red))
+    } else {
+        Line::styled(
+            "[return] adds a line; a blank line starts a paragraph.",
+            dim,
+        )
+    };
+    frame.render_widget(Paragraph::new(status), rows[1]);
+
+    let (line, col) = form.cursor_line_col();
+    frame.set_cursor_position((rows[0].x + col as u16, rows[0].y + line as u16));
+}
+
+fn render_worklog_form(frame: &mut Frame, area: Rect, form: &WorklogForm) {
+    let block = Block::bordered()
+        .title(format!(" Log work on {} ", form.ticket_id))
+        .border_style(edit_border_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let af = form.active_field;
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![
+        form_line(
+            "Time spent",
+            form.duration.value(),
+            af == WorklogField::Duration,
+        ),
+        form_line("Date", form.date.value(), af == WorklogField::Date),
+        form_line("Comment", form.comment.value(), af == WorklogField::Comment),
+        Line::from(""),
+        Line::styled("Time spent: e.g. 1h30m, 45m, 1d. Date: YYYY-MM-DD.", dim),
+    ];
+    if form.submitting {
+        lines.push(Line::styled("Logging…", dim));
+    } else if let Some(error) = &form.error {
+        lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red)));
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+
+    // Same layout as the new issue form: values start at column 13.
+    let row = match af {
+        WorklogField::Duration => 0,
+        WorklogField::Date => 1,
+        WorklogField::Comment => 2,
+    };
+    let field = form.active_field();
+    let char_offset = field.value()[..field.cursor()].chars().count() as u16;
+    frame.set_cursor_position((inner.x + 13 + char_offset, inner.y + row));
+}
+
+fn render_notes_form(frame: &mut Frame, area: Rect, form: &NotesForm) {
+    let block = Block::bordered()
+        .title(format!(" Notes on {} ", form.ticket_id))
+        .border_style(edit_border_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let af = form.active_field;
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![
+        form_line("Notes", form.notes.value(), af == NotesField::Notes),
+        form_line(
+            "Remind at",
+            form.remind_at.value(),
+            af == NotesField::RemindAt,
+        ),
+        Line::from(""),
+        Line::styled(
+            "Notes stay on this machine. Remind at: HH:MM, YYYY-MM-DD or YYYY-MM-DD HH:MM; blank for none.",
+            dim,
+        ),
+    ];
+    if let Some(error) = &form.error {
+        lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red)));
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+
+    let row = match af {
+        NotesField::Notes => 0,
+        NotesField::RemindAt => 1,
+    };
+    let field = form.active_field();
+    let char_offset = field.value()[..field.cursor()].chars().count() as u16;
+    frame.set_cursor_position((inner.x + 13 + char_offset, inner.y + row));
+}
+
+fn form_line(label: &str, value: &str, active: bool) -> Line<'static> {
+    let (label_style, value_style) = if active {
+        (
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        (
+            Style::default().fg(Color::Gray),
+            Style::default().fg(Color::White),
+        )
+    };
+    Line::from(vec![
+        Span::styled(format!("{label:<11}: "), label_style),
+        Span::styled(value.to_string(), value_style),
+    ])
+}
+
+fn render_detail(
+    frame: &mut Frame,
+    area: Rect,
+    detail: &mut TicketDetail,
+    config: Option<&JiraConfig>,
+) {
+    let block = Block::bordered().title(format!(" {} ", detail.id));
+    let inner = block.inner(area);
+
+    let lines = match &detail.view {
+        DetailView::Loading => vec![Line::styled(
+            "Loading…",
+            Style::default().fg(Color::DarkGray),
+        )],
+        DetailView::Failed(error) => vec![Line::styled(
+            format!("Failed to load ticket: {}", error),
+            Style::default().fg(Color::Red),
+        )],
+        DetailView::Loaded(details) => detail_lines(details, config),
+    };
+
+    // Clamp here rather than in state: only the renderer knows the wrapped height.
+    let width = inner.width.max(1) as usize;
+    let height: usize = lines.iter().map(|l| l.width().div_ceil(width).max(1)).sum();
+    let max_scroll = height.saturating_sub(inner.height as usize);
+    detail.scroll = detail.scroll.min(max_scroll as u16);
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((detail.scroll, 0))
+            .block(block),
+        area,
+    );
+}
+
+fn detail_lines(details: &IssueDetails, config: Option<&JiraConfig>) -> Vec<Line<'static>> {
+    let fields = &details.fields;
+    let label = Style::default().fg(Color::DarkGray);
+    let named = |n: Option<&Named>| n.map_or_else(|| "None".to_string(), |n| n.name.clone());
+
+    let mut lines = vec![
+        Line::styled(
+            fields.summary.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Line::from(vec![
+            Span::styled("Type ", label),
+            Span::raw(named(fields.issuetype.as_ref())),
+            Span::styled("  Priority ", label),
+            Span::raw(named(fields.priority.as_ref())),
+            Span::styled("  Status ", label),
+            Span::styled(
+                fields.status.name.clone(),
+                Style::default().fg(issue_status_color(config, &details.key, &fields.status)),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Assignee ", label),
+            Span::styled(
+                fields
+                    .assignee
+                    .as_ref()
+                    .map(|a| a.display_name.clone())
+                    .unwrap_or_else(|| "Unassigned".to_string()),
+                Style::default().fg(Color::LightBlue),
+            ),
+        ]),
+    ];
+    if !fields.labels.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Labels ", label),
+            Span::raw(fields.labels.join(", ")),
+        ]));
+    }
+    if !fields.fix_versions.is_empty() {
+        let versions = fields
+            .fix_versions
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect::<Vec<_>>();
+        lines.push(Line::from(vec![
+            Span::styled("Fix versions ", label),
+            Span::raw(versions.join(", ")),
+        ]));
+    }
+
+    section(&mut lines, "Description".to_string());
+    match fields.description.as_ref().map(adf::to_lines) {
+        Some(description) if !description.is_empty() => lines.extend(description),
+        _ => lines.push(Line::styled("No description", label)),
+    }
+
+    if !fields.subtasks.is_empty() {
+        section(&mut lines, format!("Subtasks ({})", fields.subtasks.len()));
+        for subtask in &fields.subtasks {
+            lines.push(linked_issue_line(
+                None,
+                &subtask.key,
+                &subtask.fields.summary,
+                &subtask.fields.status.name,
+                issue_status_color(config, &subtask.key, &subtask.fields.status),
+            ));
+        }
+    }
+
+    let links = fields
+        .issuelinks
+        .iter()
+        .filter_map(|l| l.describe())
+        .collect::<Vec<_>>();
+    if !links.is_empty() {
+        section(&mut lines, format!("Linked issues ({})", links.len()));
+        for (relation, issue) in links {
+            lines.push(linked_issue_line(
+                Some(relation),
+                &issue.key,
+                &issue.fields.summary,
+                &issue.fields.status.name,
+                issue_status_color(config, &issue.key, &issue.fields.status),
+            ));
+        }
+    }
+
+    let comments = fields
+        .comment
+        .as_ref()
+        .map(|c| c.comments.as_slice())
+        .unwrap_or_default();
+    if !comments.is_empty() {
+        let shown = comments.len().min(DETAIL_COMMENTS);
+        section(
+            &mut lines,
+            format!("Comments (latest {} of {})", shown, comments.len()),
+        );
+        for comment in comments.iter().rev().take(DETAIL_COMMENTS) {
+            let author = comment
+                .author
+                .as_ref()
+                .map(|a| a.display_name.clone())
+                .unwrap_or_else(|| "Unknown".to_string());
+            lines.push(Line::from(vec![
+                Span::styled(author, Style::default().fg(Color::LightBlue)),
+                Span::styled(format!(" · {}", format_timestamp(&comment.created)), label),
+            ]));
+            lines.extend(adf::to_lines(&comment.body).into_iter().map(|mut line| {
+                line.spans.insert(0, Span::raw("  "));
+                line
+            }));
+            lines.push(Line::from(""));
+        }
+    }
+
+    let changes = details
+        .changelog
+        .as_ref()
+        .map(|c| c.changes_since(None))
+        .unwrap_or_default();
+    if !changes.is_empty() {
+        let shown = changes.len().min(DETAIL_HISTORY);
+        section(
+            &mut lines,
+            format!("History (latest {} of {})", shown, changes.len()),
+        );
+        for change in changes.iter().rev().take(DETAIL_HISTORY) {
+            lines.push(Line::from(vec![
+                Span::styled(change.author.clone(), Style::default().fg(Color::LightBlue)),
+                Span::styled(format!(" · {}", change.at.format("%Y-%m-%d %H:%M")), label),
+            ]));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("{}: ", change.field), label),
+                Span::raw(format!(
+                    "{} → {}",
+                    or_none(&change.from),
+                    or_none(&change.to)
+                )),
+            ]));
+        }
+    }
+
+    lines
+}
+
+fn or_none(value: &str) -> &str {
+    if value.is_empty() { "None" } else { value }
+}
+
+fn section(lines: &mut Vec<Line<'static>>, title: String) {
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        format!("── {} ──", title),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ));
+}
+
+fn linked_issue_line(
+    relation: Option<&str>,
+    key: &str,
+    summary: &str,
+    status: &str,
+    status_color: Color,
+) -> Line<'static> {
+    let mut spans = vec![Span::raw("  ")];
+    if let Some(relation) = relation {
+        spans.push(Span::styled(
+            format!("{} ", relation),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans.extend([
+        Span::styled(key.to_string(), Style::default().fg(Color::Cyan)),
+        Span::raw(format!(" {} ", summary)),
+        Span::styled(format!("[{}]", status), Style::default().fg(status_color)),
+    ]);
+    Line::from(spans)
+}
+
+/// Jira timestamps look like `2024-01-31T09:15:00.000+0000`; shown to the minute.
+fn format_timestamp(raw: &str) -> String {
+    chrono::DateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|_| raw.to_string())
+}
@@ -1,5 +1,7 @@
 use crate::config::model::ServiceStatusConfig;
-use crate::state::service_status::{Commit, CommitRefStatus, ServiceStatus};
+use crate::state::service_status::{
+    Commit, CommitRefStatus, PendingTickets, Service, ServiceStatus,
+};
 use crate::ui::styles::selection_highlight;
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
@@ -41,36 +43,26 @@ pub fn render(
 
     let table_length = (state.services.len() + 1) as u16; // services + header row
 
-    // Count error lines for the selected service to size the error area dynamically.
-    let error_line_count = if let Some(idx) = state.table_state.selected() {
-        if let Some(service) = state.services.get(idx) {
-            [
-                &service.staging,
-                &service.preproduction,
-                &service.production,
-            ]
-            .iter()
-            .filter(|c| c.get_error().is_some())
-            .count() as u16
-        } else {
-            0
-        }
-    } else {
-        0
-    };
+    // Request errors and pending tickets for the selected service, sized to fit.
+    let detail_lines = state
+        .table_state
+        .selected()
+        .and_then(|idx| state.services.get(idx))
+        .map(detail_lines)
+        .unwrap_or_default();
 
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(table_length),     // table
-            Constraint::Length(error_line_count), // request errors (0 when none)
-            Constraint::Min(0),                   // filler
-            Constraint::Length(2),                // color legend
+            Constraint::Length(table_length),              // table
+            Constraint::Length(detail_lines.len() as u16), // errors and pending tickets
+            Constraint::Min(0),                            // filler
+            Constraint::Length(2),                         // color legend
         ])
         .split(area);
 
     let table_area = vertical[0];
-    let error_area = vertical[1];
+    let detail_area = vertical[1];
     let legend_area = vertical[3];
 
     let headers = Row::new(vec!["Service", "Staging", "Preproduction", "Production"]);
@@ -137,23 +129,10 @@ pub fn render(
 
     frame.render_stateful_widget(table, table_area, &mut state.table_state);
 
-    // ── Render errors
-    if let Some(service_idx) = state.table_state.selected() {
-        let mut lines: Vec<Line> = vec![];
-        let service = &state.services[service_idx];
-        let commits = vec![
-            (&service.staging, "Staging"),
-            (&service.preproduction, "Preproduction"),
-            (&service.production, "Production"),
-        ];
-        for commit in commits {
-            let (c, env) = commit;
-            if let Some(error) = c.get_error() {
-                lines.push(format!("{}: {}", env, error).into());
-            }
-        }
-        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), error_area);
-    }
+    frame.render_widget(
+        Paragraph::new(detail_lines).wrap(Wrap { trim: false }),
+        detail_area,
+    );
 
     let legend_text = Line::from(vec![
         Span::styled("▍ ", Style::default().bg(ALL_MATCH)),
@@ -171,3 +150,36 @@ pub fn render(
         legend_area,
     );
 }
+
+fn detail_lines(service: &Service) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = [
+        (&service.staging, "Staging"),
+        (&service.preproduction, "Preproduction"),
+        (&service.production, "Production"),
+    ]
+    .into_iter()
+    .filter_map(|(commit, env)| {
+        commit
+            .get_error()
+            .map(|error| format!("{}: {}", env, error).into())
+    })
+    .collect();
+
+    let dim = Style::default().fg(Color::DarkGray);
+    match &service.pending_tickets {
+        PendingTickets::Unknown => {}
+        PendingTickets::Loading => lines.push(Line::styled("Looking up tickets…", dim)),
+        PendingTickets::Found(ids) if ids.is_empty() => {
+            lines.push(Line::styled("No tickets named in the pending commits", dim))
+        }
+        PendingTickets::Found(ids) => lines.push(Line::from(vec![
+            Span::styled("Going to production: ", dim),
+            Span::raw(ids.join(", ")),
+        ])),
+        PendingTickets::Failed(error) => lines.push(Line::styled(
+            format!("Couldn't list tickets: {}", error),
+            Style::default().fg(Color::Red),
+        )),
+    }
+    lines
+}
@@ -1,11 +1,12 @@
 use crate::config::model::ServiceConfig;
-use crate::state::token_generator::{Focus, Token, TokenGenerator};
-use crate::ui::styles::{block_style, selection_highlight};
+use crate::state::token_generator::{Focus, Token, TokenGenerator, Verification};
+use crate::ui::styles::{block_style, edit_border_style, selection_highlight};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use tui_text_field::TextField;
 
 pub fn render(
     frame: &mut Frame,
@@ -32,10 +33,33 @@ pub fn render(
     const ERROR_COLOR: Color = Color::Red;
     const REQUESTING_COLOR: Color = Color::Yellow;
 
+    // Reserve rows below the lists for the paste input and the verification result.
+    let pasting = state.focus == Focus::Paste;
+    let verification_height = match &state.verification {
+        Some(Verification::Done(_, report)) => report.checks.len() as u16 + 3,
+        Some(_) => 3,
+        None => 0,
+    };
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if pasting { 3 } else { 0 }),
+            Constraint::Length(verification_height),
+        ])
+        .split(area);
+
+    if pasting {
+        render_paste_input(frame, vertical[1], &state.pasted_token);
+    }
+    if let Some(verification) = &state.verification {
+        render_verification(frame, vertical[2], verification);
+    }
+
     let inner_horizontal = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(area);
+        .split(vertical[0]);
 
     let service_focused = matches!(state.focus, Focus::Service);
     let env_focused = matches!(state.focus, Focus::Env);
@@ -84,3 +108,91 @@ pub fn render(
 
     frame.render_stateful_widget(environments, inner_horizontal[1], &mut state.env_list_state);
 }
+
+fn render_paste_input(frame: &mut Frame, area: Rect, field: &TextField) {
+    let block = Block::bordered()
+        .title(" Paste token to verify ")
+        .border_style(edit_border_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Tokens are far wider than the panel, so show the tail being typed or pasted.
+    let value = field.value();
+    let width = inner.width.saturating_sub(1) as usize;
+    let count = value.chars().count();
+    let visible: String = if count > width {
+        value.chars().skip(count - width).collect()
+    } else {
+        value.to_string()
+    };
+
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            visible.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        inner,
+    );
+
+    let char_offset = visible.chars().count() as u16;
+    frame.set_cursor_position((inner.x + char_offset, inner.y));
+}
+
+fn render_verification(frame: &mut Frame, area: Rect, verification: &Verification) {
+    let (title, lines) = match verification {
+        Verification::Verifying(label) => (
+            label,
+            vec![Line::styled(
+                "Verifying…",
+                Style::default().fg(Color::Yellow),
+            )],
+        ),
+        Verification::Failed(label, error) => (
+            label,
+            vec![Line::styled(
+                format!("Could not verify: {}", error),
+                Style::default().fg(Color::Red),
+            )],
+        ),
+        Verification::Done(label, report) => {
+            let summary = if report.is_valid() {
+                Line::styled(
+                    "Token would be accepted",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Line::styled(
+                    "Token would be rejected",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )
+            };
+            let mut lines = vec![summary];
+            lines.extend(report.checks.iter().map(|check| {
+                let (mark, color) = if check.passed {
+                    ("[✓]", Color::Green)
+                } else {
+                    ("[x]", Color::Red)
+                };
+                Line::from(vec![
+                    Span::styled(mark, Style::default().fg(color)),
+                    Span::raw(format!(" {:<11}", check.name)),
+                    Span::styled(check.detail.clone(), Style::default().fg(Color::Gray)),
+                ])
+            }));
+            (label, lines)
+        }
+    };
+
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::new()
+                .borders(Borders::ALL)
+                .title(format!(" Verify — {} ", title)),
+        ),
+        area,
+    );
+}
@@ -0,0 +1,331 @@
+//! Renders Atlassian Document Format (the JSON rich-text format Jira Cloud uses for
+//! descriptions and comments) as styled terminal lines, and builds it from plain text.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use serde_json::{Value, json};
+
+/// Converts an ADF document to lines. A plain JSON string is rendered as-is, one
+/// line per newline.
+pub fn to_lines(doc: &Value) -> Vec<Line<'static>> {
+    match doc {
+        Value::String(text) => text.lines().map(|l| Line::from(l.to_string())).collect(),
+        Value::Null => Vec::new(),
+        _ if node_type(doc) == "doc" => {
+            // Top-level blocks are separated by a blank line, like Jira's own rendering.
+            let mut lines = Vec::new();
+            for (idx, block) in children(doc).enumerate() {
+                if idx > 0 {
+                    lines.push(Line::from(""));
+                }
+                lines.extend(block_lines(block));
+            }
+            lines
+        }
+        _ => block_lines(doc),
+    }
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or_default()
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|attrs| attrs.get(name))
+}
+
+fn children(node: &Value) -> impl Iterator<Item = &Value> {
+    node.get("content")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn dim() -> Style {
+    Style::default().add_modifier(Modifier::DIM)
+}
+
+fn code() -> Style {
+    Style::default().fg(Color::Yellow)
+}
+
+fn block_lines(node: &Value) -> Vec<Line<'static>> {
+    match node_type(node) {
+        "paragraph" => inline_lines(node, Style::default()),
+        "heading" => {
+            let style = match attr(node, "level").and_then(Value::as_u64) {
+                Some(1) => Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                _ => Style::default().add_modifier(Modifier::BOLD),
+            };
+            inline_lines(node, style)
+        }
+        "bulletList" => children(node)
+            .flat_map(|item| prefixed(container_lines(item), "• "))
+            .collect(),
+        "orderedList" => {
+            let start = attr(node, "order").and_then(Value::as_u64).unwrap_or(1);
+            children(node)
+                .zip(start..)
+                .flat_map(|(item, n)| prefixed(container_lines(item), &format!("{}. ", n)))
+                .collect()
+        }
+        "codeBlock" => plain_text(node)
+            .lines()
+            .map(|l| Line::from(vec![Span::raw("  "), Span::styled(l.to_string(), code())]))
+            .collect(),
+        "blockquote" | "panel" => container_lines(node)
+            .into_iter()
+            .map(|mut line| {
+                line.spans.insert(0, Span::styled("│ ", dim()));
+                line
+            })
+            .collect(),
+        "rule" => vec![Line::styled("─".repeat(24), dim())],
+        "table" => children(node)
+            .map(|row| {
+                let cells = children(row)
+                    .map(|cell| plain_text(cell).trim().to_string())
+                    .collect::<Vec<_>>();
+                Line::from(cells.join(" │ "))
+            })
+            .collect(),
+        "mediaSingle" | "mediaGroup" | "media" => vec![Line::styled("[attachment]", dim())],
+        _ => container_lines(node),
+    }
+}
+
+fn container_lines(node: &Value) -> Vec<Line<'static>> {
+    children(node).flat_map(block_lines).collect()
+}
+
+/// Puts `marker` in front of the first line and aligns the rest underneath it.
+fn prefixed(lines: Vec<Line<'static>>, marker: &str) -> Vec<Line<'static>> {
+    let indent = " ".repeat(marker.chars().count());
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(idx, mut line)| {
+            let prefix = if idx == 0 { marker } else { indent.as_str() };
+            line.spans.insert(0, Span::raw(prefix.to_string()));
+            line
+        })
+        .collect()
+}
+
+fn inline_lines(node: &Value, base: Style) -> Vec<Line<'static>> {
+    let mut lines = vec![Vec::new()];
+    for child in children(node) {
+        let span = match node_type(child) {
+            "hardBreak" => {
+                lines.push(Vec::new());
+                continue;
+            }
+            "text" => {
+                let text = child
+                    .get("text")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                Span::styled(text.to_string(), with_marks(base, child))
+            }
+            "mention" | "emoji" | "status" => {
+                let text = attr(child, "text")
+                    .or_else(|| attr(child, "shortName"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                Span::styled(text.to_string(), base.fg(Color::LightBlue))
+            }
+            "inlineCard" => {
+                let url = attr(child, "url")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                Span::styled(url.to_string(), link(base))
+            }
+            _ => Span::styled(plain_text(child), base),
+        };
+        if let Some(current) = lines.last_mut() {
+            current.push(span);
+        }
+    }
+    lines.into_iter().map(Line::from).collect()
+}
+
+fn with_marks(base: Style, node: &Value) -> Style {
+    let marks = node.get("marks").and_then(Value::as_array);
+    marks
+        .into_iter()
+        .flatten()
+        .fold(base, |style, mark| match node_type(mark) {
+            "strong" => style.add_modifier(Modifier::BOLD),
+            "em" => style.add_modifier(Modifier::ITALIC),
+            "underline" => style.add_modifier(Modifier::UNDERLINED),
+            "strike" => style.add_modifier(Modifier::CROSSED_OUT),
+            "code" => style.patch(code()),
+            "link" => link(style),
+            _ => style,
+        })
+}
+
+fn link(style: Style) -> Style {
+    style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED)
+}
+
+/// The text content of a node with all formatting dropped.
+pub fn plain_text(node: &Value) -> String {
+    match node_type(node) {
+        "text" => node
+            .get("text")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        "hardBreak" => "\n".to_string(),
+        _ => children(node).map(plain_text).collect(),
+    }
+}
+
+/// Wraps plain text in an ADF document: blank lines separate paragraphs and
+/// single newlines become hard breaks.
+pub fn from_plain_text(text: &str) -> Value {
+    let paragraphs = text
+        .split("\n\n")
+        .map(|p| p.trim_matches('\n'))
+        .filter(|p| !p.is_empty())
+        .map(|paragraph| {
+            let mut content = Vec::new();
+            for (idx, line) in paragraph.lines().enumerate() {
+                if idx > 0 {
+                    content.push(json!({ "type": "hardBreak" }));
+                }
+                if !line.is_empty() {
+                    content.push(json!({ "type": "text", "text": line }));
+                }
+            }
+            json!({ "type": "paragraph", "content": content })
+        })
+        .collect::<Vec<_>>();
+    json!({ "type": "doc", "version": 1, "content": paragraphs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_of(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    fn doc(content: Value) -> Value {
+        json!({ "type": "doc", "version": 1, "content": content })
+    }
+
+    #[test]
+    fn plain_string_is_split_on_newlines() {
+        let lines = to_lines(&json!("first\nsecond"));
+        assert_eq!(text_of(&lines), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn null_renders_nothing() {
+        assert!(to_lines(&Value::Null).is_empty());
+    }
+
+    #[test]
+    fn paragraphs_are_separated_by_blank_lines() {
+        let lines = to_lines(&doc(json!([
+            { "type": "paragraph", "content": [{ "type": "text", "text": "One" }] },
+            { "type": "paragraph", "content": [{ "type": "text", "text": "Two" }] }
+        ])));
+        assert_eq!(text_of(&lines), vec!["One", "", "Two"]);
+    }
+
+    #[test]
+    fn hard_break_starts_a_new_line() {
+        let lines = to_lines(&doc(json!([{ "type": "paragraph", "content": [
+            { "type": "text", "text": "a" },
+            { "type": "hardBreak" },
+            { "type": "text", "text": "b" }
+        ]}])));
+        assert_eq!(text_of(&lines), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn marks_are_styled() {
+        let lines = to_lines(&doc(json!([{ "type": "paragraph", "content": [
+            { "type": "text", "text": "bold", "marks": [{ "type": "strong" }] },
+            { "type": "text", "text": "url", "marks": [{ "type": "link", "attrs": { "href": "https://x" } }] }
+        ]}])));
+        let spans = &lines[0].spans;
+        assert!(spans[0].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(spans[1].style.fg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn lists_are_prefixed_and_nested_items_indented() {
+        let lines = to_lines(&doc(json!([
+            { "type": "bulletList", "content": [
+                { "type": "listItem", "content": [
+                    { "type": "paragraph", "content": [{ "type": "text", "text": "outer" }] },
+                    { "type": "orderedList", "content": [
+                        { "type": "listItem", "content": [
+                            { "type": "paragraph", "content": [{ "type": "text", "text": "inner" }] }
+                        ]}
+                    ]}
+                ]}
+            ]}
+        ])));
+        assert_eq!(text_of(&lines), vec!["• outer", "  1. inner"]);
+    }
+
+    #[test]
+    fn code_block_keeps_its_lines() {
+        let lines = to_lines(&doc(json!([{ "type": "codeBlock", "content": [
+            { "type": "text", "text": "fn main() {\n}" }
+        ]}])));
+        assert_eq!(text_of(&lines), vec!["  fn main() {", "  }"]);
+    }
+
+    #[test]
+    fn mentions_and_tables_render_their_text() {
+        let lines = to_lines(&doc(json!([
+            { "type": "paragraph", "content": [
+                { "type": "mention", "attrs": { "id": "1", "text": "@Alice" } }
+            ]},
+            { "type": "table", "content": [{ "type": "tableRow", "content": [
+                { "type": "tableHeader", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "A" }] }] },
+                { "type": "tableCell", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "B" }] }] }
+            ]}]}
+        ])));
+        assert_eq!(text_of(&lines), vec!["@Alice", "", "A │ B"]);
+    }
+
+    #[test]
+    fn plain_text_drops_formatting() {
+        let node = json!({ "type": "paragraph", "content": [
+            { "type": "text", "text": "a", "marks": [{ "type": "em" }] },
+            { "type": "text", "text": "b" }
+        ]});
+        assert_eq!(plain_text(&node), "ab");
+    }
+
+    #[test]
+    fn from_plain_text_builds_paragraphs_and_breaks() {
+        let doc = from_plain_text("first\nsecond\n\nthird");
+        assert_eq!(
+            doc,
+            json!({ "type": "doc", "version": 1, "content": [
+                { "type": "paragraph", "content": [
+                    { "type": "text", "text": "first" },
+                    { "type": "hardBreak" },
+                    { "type": "text", "text": "second" }
+                ]},
+                { "type": "paragraph", "content": [{ "type": "text", "text": "third" }] }
+            ]})
+        );
+        assert_eq!(
+            text_of(&to_lines(&doc)),
+            vec!["first", "second", "", "third"]
+        );
+    }
+}
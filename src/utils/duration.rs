@@ -0,0 +1,83 @@
+//! Reads and writes Jira-style durations such as `1h 30m` for the worklog.
+
+/// Jira's default working day and week, which `d` and `w` are counted in.
+const HOURS_PER_DAY: u64 = 8;
+const DAYS_PER_WEEK: u64 = 5;
+
+/// Parses a duration like `1h30m`, `1h 30m`, `45m` or `1d` into seconds. Every
+/// number needs a unit, and the total must be at least a minute.
+pub fn parse(input: &str) -> Result<u64, String> {
+    let invalid = || format!("Not a duration: {} (try 1h30m)", input.trim());
+    let mut seconds: u64 = 0;
+    let mut number = String::new();
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            'w' => DAYS_PER_WEEK * HOURS_PER_DAY * 3600,
+            'd' => HOURS_PER_DAY * 3600,
+            'h' => 3600,
+            'm' => 60,
+            _ => return Err(invalid()),
+        };
+        let value = number.parse::<u64>().map_err(|_| invalid())?;
+        seconds = value
+            .checked_mul(unit)
+            .and_then(|part| seconds.checked_add(part))
+            .ok_or_else(|| format!("Duration is too long: {}", input.trim()))?;
+        number.clear();
+    }
+    if !number.is_empty() || seconds == 0 {
+        return Err(invalid());
+    }
+    Ok(seconds)
+}
+
+/// Formats seconds the way Jira shows time spent, to the minute: `1h 30m`, `45m`.
+pub fn format(seconds: u64) -> String {
+    let minutes = seconds / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("1h30m", 5400; "hours and minutes")]
+    #[test_case(" 1h 30m ", 5400; "with spaces")]
+    #[test_case("45m", 2700; "minutes only")]
+    #[test_case("2H", 7200; "uppercase unit")]
+    #[test_case("1d", 28800; "working day")]
+    #[test_case("1w", 144000; "working week")]
+    fn parse_accepts(input: &str, expected: u64) {
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test_case(""; "empty")]
+    #[test_case("90"; "missing unit")]
+    #[test_case("1h30"; "trailing number")]
+    #[test_case("h"; "missing number")]
+    #[test_case("1x"; "unknown unit")]
+    #[test_case("0m"; "zero")]
+    #[test_case("99999999999999999999m"; "number too big")]
+    #[test_case("5000000000000000w"; "unit overflows")]
+    #[test_case("18446744073709551615m 1m"; "total overflows")]
+    fn parse_rejects(input: &str) {
+        assert!(parse(input).is_err());
+    }
+
+    #[test_case(5400, "1h 30m"; "hours and minutes")]
+    #[test_case(7200, "2h"; "whole hours")]
+    #[test_case(2759, "45m"; "seconds dropped")]
+    #[test_case(30, "0m"; "under a minute")]
+    fn format_shows_hours_and_minutes(seconds: u64, expected: &str) {
+        assert_eq!(format(seconds), expected);
+    }
+}
@@ -0,0 +1,389 @@
+//! Runs `git` against local clones for the Jira tool's branch actions and the
+//! tickets Service Status shows waiting for production.
+
+use crate::utils::ticket_ids;
+use chrono::{DateTime, FixedOffset};
+use std::collections::HashSet;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// A local branch whose name mentions a watched ticket.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TicketBranch {
+    /// The clone's directory name.
+    pub repository: String,
+    pub branch: String,
+    /// Commits ahead of and behind the default branch, when one could be found.
+    pub ahead_behind: Option<(u32, u32)>,
+    pub last_commit: Option<DateTime<FixedOffset>>,
+}
+
+/// Whether checking out a branch had to create it first.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Checkout {
+    Created,
+    Existing,
+}
+
+/// Checks out `branch` in `repo`, creating it from the current HEAD if it doesn't
+/// exist yet. Git refuses to switch over conflicting local changes, and that error
+/// is passed back as-is.
+pub fn check_out_branch(repo: &Path, branch: &str) -> Result<Checkout, String> {
+    git(repo, &["check-ref-format", "--branch", branch])
+        .map_err(|_| format!("Not a valid branch name: {}", branch))?;
+
+    let exists = git(
+        repo,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", branch),
+        ],
+    )
+    .is_ok();
+    if exists {
+        git(repo, &["switch", branch])?;
+        Ok(Checkout::Existing)
+    } else {
+        git(repo, &["switch", "-c", branch])?;
+        Ok(Checkout::Created)
+    }
+}
+
+/// Finds the branches in `repo` whose names contain one of the `watched` ticket IDs,
+/// paired with the ID they matched. Matching ignores case and treats `_` like `-`,
+/// so `feature/abc-12_login` belongs to `ABC-12` but not to `ABC-1`.
+pub fn ticket_branches(
+    repo: &Path,
+    watched: &HashSet<String>,
+) -> Result<Vec<(String, TicketBranch)>, String> {
+    let refs = git(
+        repo,
+        &[
+            "for-each-ref",
+            "--format=%(refname:short)%09%(committerdate:iso-strict)",
+            "refs/heads",
+        ],
+    )?;
+    let repository = repo
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| repo.display().to_string());
+    let base = default_branch(repo);
+
+    let mut found = Vec::new();
+    for line in refs.lines() {
+        let Some((branch, date)) = line.split_once('\t') else {
+            continue;
+        };
+        let ids = ticket_ids::extract(&branch.to_ascii_uppercase().replace('_', "-"))
+            .into_iter()
+            .filter(|id| watched.contains(id))
+            .collect::<Vec<_>>();
+        if ids.is_empty() {
+            continue;
+        }
+        let ticket_branch = TicketBranch {
+            repository: repository.clone(),
+            branch: branch.to_string(),
+            ahead_behind: base
+                .as_deref()
+                .and_then(|base| ahead_behind(repo, base, branch).ok()),
+            last_commit: DateTime::parse_from_rfc3339(date).ok(),
+        };
+        found.extend(ids.into_iter().map(|id| (id, ticket_branch.clone())));
+    }
+    Ok(found)
+}
+
+/// The ticket IDs mentioned in the messages of commits reachable from `to` but not
+/// `from`, oldest first. If either commit isn't in the clone yet, it is fetched once.
+/// Both come from a service's healthcheck, so anything but a commit SHA is refused
+/// rather than handed to git.
+pub fn tickets_between(repo: &Path, from: &str, to: &str) -> Result<Vec<String>, String> {
+    if let Some(bad) = [from, to].into_iter().find(|sha| !is_commit_sha(sha)) {
+        return Err(format!("Not a commit SHA: {}", bad));
+    }
+    let missing = [from, to]
+        .into_iter()
+        .any(|sha| git(repo, &["cat-file", "-e", &format!("{}^{{commit}}", sha)]).is_err());
+    if missing {
+        git(repo, &["fetch", "--quiet"])?;
+    }
+    let messages = git(
+        repo,
+        &[
+            "log",
+            "--reverse",
+            "--format=%B",
+            "--end-of-options",
+            &format!("{}..{}", from, to),
+        ],
+    )?;
+    Ok(ticket_ids::extract(&messages))
+}
+
+/// Whether `value` is a full or abbreviated commit SHA: 7 to 40 lowercase hex digits.
+fn is_commit_sha(value: &str) -> bool {
+    (7..=40).contains(&value.len())
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+/// The branch work is merged into: the remote's HEAD when there is one, otherwise
+/// a local `main` or `master`.
+fn default_branch(repo: &Path) -> Option<String> {
+    if let Ok(remote_head) = git(
+        repo,
+        &[
+            "symbolic-ref",
+            "--quiet",
+            "--short",
+            "refs/remotes/origin/HEAD",
+        ],
+    ) {
+        return Some(remote_head);
+    }
+    ["main", "master"]
+        .into_iter()
+        .find(|name| {
+            git(
+                repo,
+                &[
+                    "rev-parse",
+                    "--verify",
+                    "--quiet",
+                    &format!("refs/heads/{}", name),
+                ],
+            )
+            .is_ok()
+        })
+        .map(str::to_string)
+}
+
+fn ahead_behind(repo: &Path, base: &str, branch: &str) -> Result<(u32, u32), String> {
+    let output = git(
+        repo,
+        &[
+            "rev-list",
+            "--left-right",
+            "--count",
+            &format!("{}...{}", base, branch),
+        ],
+    )?;
+    // Left is only on the base (behind), right only on the branch (ahead).
+    let mut counts = output.split_whitespace().map(str::parse::<u32>);
+    match (counts.next(), counts.next()) {
+        (Some(Ok(behind)), Some(Ok(ahead))) => Ok((ahead, behind)),
+        _ => Err(format!("Unexpected rev-list output: {}", output)),
+    }
+}
+
+/// Runs `git -C repo args…`, returning stdout or git's error message. Git gets no
+/// terminal to prompt on, so a missing credential fails rather than waiting.
+fn git(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to run git: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() {
+            format!("git {} failed", args.join(" "))
+        } else {
+            stderr
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn init_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        git(
+            dir.path(),
+            &["-c", "init.defaultBranch=main", "init", "--quiet"],
+        )
+        .unwrap();
+        commit(dir.path(), "init");
+        dir
+    }
+
+    fn commit(repo: &Path, message: &str) {
+        git(
+            repo,
+            &[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "-c",
+                "commit.gpgsign=false",
+                "commit",
+                "--quiet",
+                "--allow-empty",
+                "-m",
+                message,
+            ],
+        )
+        .unwrap();
+    }
+
+    fn watched(ids: &[&str]) -> HashSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn current_branch(repo: &Path) -> String {
+        git(repo, &["rev-parse", "--abbrev-ref", "HEAD"]).unwrap()
+    }
+
+    #[test]
+    fn check_out_branch_creates_then_reuses_branch() {
+        let repo = init_repo();
+        let default_branch = current_branch(repo.path());
+
+        assert_eq!(
+            check_out_branch(repo.path(), "feat/ABC-1-login"),
+            Ok(Checkout::Created)
+        );
+        assert_eq!(current_branch(repo.path()), "feat/ABC-1-login");
+
+        git(repo.path(), &["switch", "--quiet", &default_branch]).unwrap();
+        assert_eq!(
+            check_out_branch(repo.path(), "feat/ABC-1-login"),
+            Ok(Checkout::Existing)
+        );
+        assert_eq!(current_branch(repo.path()), "feat/ABC-1-login");
+    }
+
+    #[test]
+    fn check_out_branch_rejects_invalid_names() {
+        let repo = init_repo();
+        assert_eq!(
+            check_out_branch(repo.path(), "feat/bad..name"),
+            Err("Not a valid branch name: feat/bad..name".to_string())
+        );
+    }
+
+    #[test]
+    fn check_out_branch_fails_outside_a_repository() {
+        let dir = TempDir::new().unwrap();
+        assert!(check_out_branch(dir.path(), "feat/ABC-1").is_err());
+    }
+
+    #[test]
+    fn ticket_branches_finds_watched_branches_with_ahead_behind() {
+        let repo = init_repo();
+        git(
+            repo.path(),
+            &["switch", "--quiet", "-c", "feat/abc-12_login"],
+        )
+        .unwrap();
+        commit(repo.path(), "one");
+        commit(repo.path(), "two");
+        git(
+            repo.path(),
+            &["switch", "--quiet", "-c", "fix/ABC-123", "main"],
+        )
+        .unwrap();
+        git(
+            repo.path(),
+            &["switch", "--quiet", "-c", "chore/XYZ-9", "main"],
+        )
+        .unwrap();
+        git(repo.path(), &["switch", "--quiet", "main"]).unwrap();
+        commit(repo.path(), "three");
+
+        let found = ticket_branches(repo.path(), &watched(&["ABC-12", "XYZ-1"])).unwrap();
+
+        assert_eq!(found.len(), 1);
+        let (id, branch) = &found[0];
+        assert_eq!(id, "ABC-12");
+        assert_eq!(branch.branch, "feat/abc-12_login");
+        assert_eq!(branch.ahead_behind, Some((2, 1)));
+        assert!(branch.last_commit.is_some());
+        assert_eq!(
+            branch.repository,
+            repo.path().file_name().unwrap().to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn ticket_branches_without_default_branch_has_no_counts() {
+        let repo = init_repo();
+        git(repo.path(), &["branch", "-m", "trunk"]).unwrap();
+        git(repo.path(), &["branch", "feat/ABC-1"]).unwrap();
+
+        let found = ticket_branches(repo.path(), &watched(&["ABC-1"])).unwrap();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1.ahead_behind, None);
+    }
+
+    #[test]
+    fn tickets_between_lists_ids_from_newer_commits() {
+        let repo = init_repo();
+        let from = git(repo.path(), &["rev-parse", "HEAD"]).unwrap();
+        commit(repo.path(), "ABC-2 Fix login\n\nAlso touches XYZ-7");
+        commit(repo.path(), "Merge pull request #4 from feat/ABC-1-signup");
+        commit(repo.path(), "ABC-2 Follow-up");
+        let to = git(repo.path(), &["rev-parse", "HEAD"]).unwrap();
+
+        assert_eq!(
+            tickets_between(repo.path(), &from, &to),
+            Ok(vec![
+                "ABC-2".to_string(),
+                "XYZ-7".to_string(),
+                "ABC-1".to_string()
+            ])
+        );
+        assert_eq!(tickets_between(repo.path(), &to, &to), Ok(vec![]));
+    }
+
+    #[test]
+    fn tickets_between_fails_for_unknown_commits() {
+        let repo = init_repo();
+        let head = git(repo.path(), &["rev-parse", "HEAD"]).unwrap();
+        assert!(tickets_between(repo.path(), "deadbeef", &head).is_err());
+    }
+
+    #[test]
+    fn tickets_between_refuses_anything_but_a_sha() {
+        let repo = init_repo();
+        let head = git(repo.path(), &["rev-parse", "HEAD"]).unwrap();
+        for bad in [
+            "--output=/tmp/x",
+            "HEAD",
+            "abc123",
+            "DEADBEEF",
+            "deadbeef~1",
+        ] {
+            assert_eq!(
+                tickets_between(repo.path(), bad, &head),
+                Err(format!("Not a commit SHA: {}", bad))
+            );
+        }
+        assert_eq!(
+            tickets_between(repo.path(), &head, "-n1"),
+            Err("Not a commit SHA: -n1".to_string())
+        );
+    }
+
+    #[test]
+    fn ticket_branches_fails_outside_a_repository() {
+        let dir = TempDir::new().unwrap();
+        assert!(ticket_branches(dir.path(), &watched(&["ABC-1"])).is_err());
+    }
+}
@@ -1,4 +1,10 @@
+pub(crate) mod adf;
 pub(crate) mod browser;
+pub(crate) mod duration;
+pub(crate) mod git;
 pub(crate) mod overlay;
+pub(crate) mod path;
 pub(crate) mod string_copy;
+pub(crate) mod ticket_ids;
+pub(crate) mod ticket_template;
 pub(crate) mod update_list_state;
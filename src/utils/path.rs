@@ -0,0 +1,43 @@
+use std::path::{Path, PathBuf};
+
+/// Expands a leading `~` to the user's home directory so config paths can be
+/// written the way they would be typed in a shell.
+pub fn expand_tilde(path: &str) -> PathBuf {
+    expand_tilde_with_home(path, dirs::home_dir().as_deref())
+}
+
+fn expand_tilde_with_home(path: &str, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix('~'), home) {
+        (Some(""), Some(home)) => home.to_path_buf(),
+        (Some(rest), Some(home)) if rest.starts_with('/') => {
+            home.join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("~", "/home/dev"; "bare tilde")]
+    #[test_case("~/.devtool/jwks.json", "/home/dev/.devtool/jwks.json"; "tilde prefix")]
+    #[test_case("/etc/jwks.json", "/etc/jwks.json"; "absolute path")]
+    #[test_case("jwks.json", "jwks.json"; "relative path")]
+    #[test_case("~other/jwks.json", "~other/jwks.json"; "other user untouched")]
+    fn expand_tilde_with_home_expands(path: &str, expected: &str) {
+        assert_eq!(
+            expand_tilde_with_home(path, Some(Path::new("/home/dev"))),
+            PathBuf::from(expected)
+        );
+    }
+
+    #[test]
+    fn expand_tilde_with_home_leaves_path_without_home() {
+        assert_eq!(
+            expand_tilde_with_home("~/jwks.json", None),
+            PathBuf::from("~/jwks.json")
+        );
+    }
+}
@@ -21,6 +21,42 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
     Err("No clipboard tool found. Install wl-copy (Wayland), xclip, or xsel (X11).".to_string())
 }
 
+pub fn read_from_clipboard() -> Result<String, String> {
+    if which::which("wl-paste").is_ok() {
+        return read_from("wl-paste", &["--no-newline"]);
+    }
+
+    if cfg!(target_os = "macos") {
+        return read_from("pbpaste", &[]);
+    }
+
+    if which::which("xclip").is_ok() {
+        return read_from("xclip", &["-selection", "clipboard", "-o"]);
+    }
+
+    if which::which("xsel").is_ok() {
+        return read_from("xsel", &["--clipboard", "--output"]);
+    }
+
+    Err("No clipboard tool found. Install wl-paste (Wayland), xclip, or xsel (X11).".to_string())
+}
+
+fn read_from(cmd: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(cmd)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run {cmd}: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{cmd} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 fn pipe_to(cmd: &str, args: &[&str], text: &str) -> Result<(), String> {
     let mut child = Command::new(cmd)
         .args(args)
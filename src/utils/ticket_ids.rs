@@ -0,0 +1,132 @@
+//! Parses Jira ticket IDs out of typed input and pasted text for bulk imports.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// The largest range accepted in one `ABC-1..N` token, to catch typos like `..1200`.
+pub const MAX_RANGE: u64 = 100;
+
+static TICKET_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b([A-Z]+)-(\d+)\b").expect("valid ticket ID pattern"));
+
+/// Parses the inline input: IDs separated by commas or whitespace, where a token
+/// may also be a range such as `ABC-120..125` or `ABC-120..ABC-125`. Returns the
+/// IDs in the order given, without duplicates.
+pub fn parse(input: &str) -> Result<Vec<String>, String> {
+    let mut ids = Vec::new();
+    for token in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+    {
+        let token = token.to_ascii_uppercase();
+        match token.split_once("..") {
+            Some((start, end)) => ids.extend(expand_range(start, end)?),
+            None => {
+                split_id(&token).ok_or_else(|| format!("Not a ticket ID: {}", token))?;
+                ids.push(token);
+            }
+        }
+    }
+    Ok(dedup(ids))
+}
+
+/// Pulls every ticket ID out of free text such as a pasted Slack thread or a
+/// release note, in order of first appearance.
+pub fn extract(text: &str) -> Vec<String> {
+    dedup(
+        TICKET_ID
+            .find_iter(text)
+            .map(|m| m.as_str().to_string())
+            .collect(),
+    )
+}
+
+fn expand_range(start: &str, end: &str) -> Result<Vec<String>, String> {
+    let invalid = || format!("Not a ticket range: {}..{}", start, end);
+    let (project, first) = split_id(start).ok_or_else(invalid)?;
+    let last = match split_id(end) {
+        Some((end_project, last)) if end_project == project => last,
+        Some(_) => return Err(format!("Range spans projects: {}..{}", start, end)),
+        None => end.parse::<u64>().map_err(|_| invalid())?,
+    };
+    if last < first {
+        return Err(invalid());
+    }
+    if last - first >= MAX_RANGE {
+        return Err(format!(
+            "Range {}..{} is larger than {} tickets",
+            start, end, MAX_RANGE
+        ));
+    }
+    Ok((first..=last)
+        .map(|n| format!("{}-{}", project, n))
+        .collect())
+}
+
+/// Splits a whole-token ID into its project key and number.
+fn split_id(id: &str) -> Option<(&str, u64)> {
+    let captures = TICKET_ID.captures(id)?;
+    if captures.get(0)?.as_str() != id {
+        return None;
+    }
+    let project = captures.get(1)?.as_str();
+    let number = captures.get(2)?.as_str().parse().ok()?;
+    Some((project, number))
+}
+
+fn dedup(ids: Vec<String>) -> Vec<String> {
+    let mut unique = Vec::with_capacity(ids.len());
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test_case("ABC-1", &["ABC-1"]; "single id")]
+    #[test_case("ABC-1, ABC-2 DEF-3", &["ABC-1", "ABC-2", "DEF-3"]; "commas and spaces")]
+    #[test_case("abc-1,,abc-2", &["ABC-1", "ABC-2"]; "lowercase and empty tokens")]
+    #[test_case("ABC-120..123", &["ABC-120", "ABC-121", "ABC-122", "ABC-123"]; "range with bare end")]
+    #[test_case("ABC-7..ABC-8", &["ABC-7", "ABC-8"]; "range with full end")]
+    #[test_case("ABC-2 ABC-1..3", &["ABC-2", "ABC-1", "ABC-3"]; "duplicates dropped")]
+    #[test_case("", &[]; "empty input")]
+    fn parse_accepts(input: &str, expected: &[&str]) {
+        assert_eq!(parse(input), Ok(ids(expected)));
+    }
+
+    #[test_case("ABC"; "missing number")]
+    #[test_case("ABC-1 nope"; "bad token")]
+    #[test_case("ABC-5..3"; "backwards range")]
+    #[test_case("ABC-1..DEF-3"; "range across projects")]
+    #[test_case("ABC-1..x"; "bad range end")]
+    #[test_case("ABC-1..101"; "range too large")]
+    fn parse_rejects(input: &str) {
+        assert!(parse(input).is_err());
+    }
+
+    #[test]
+    fn parse_allows_range_of_max_size() {
+        assert_eq!(parse("ABC-1..100").unwrap().len(), MAX_RANGE as usize);
+    }
+
+    #[test]
+    fn extract_finds_ids_in_text() {
+        let text = "Fixed in ABC-12 (see also DEF-3).\nReverted ABC-12, follow-up in ABC-14";
+        assert_eq!(extract(text), ids(&["ABC-12", "DEF-3", "ABC-14"]));
+    }
+
+    #[test]
+    fn extract_ignores_partial_matches() {
+        assert_eq!(extract("abc-1 xABC-2 ABC-3x ABC-"), Vec::<String>::new());
+    }
+}
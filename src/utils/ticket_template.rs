@@ -0,0 +1,103 @@
+//! Fills `{name}` / `{slug(name)}` placeholders in branch and commit templates.
+
+/// Slugs are cut to this many characters so long titles still give usable branch names.
+const SLUG_MAX_LEN: usize = 50;
+
+/// Replaces each placeholder in `template` with its value from `vars`. Unknown
+/// placeholders and unclosed braces are errors, so typos in config are caught.
+pub fn render(template: &str, vars: &[(&str, &str)]) -> Result<String, String> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| format!("Unclosed placeholder in template: {}", template))?;
+        output.push_str(&expand(&after[..end], vars)?);
+        rest = &after[end + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn expand(placeholder: &str, vars: &[(&str, &str)]) -> Result<String, String> {
+    let placeholder = placeholder.trim();
+    let (name, slugged) = match placeholder
+        .strip_prefix("slug(")
+        .and_then(|p| p.strip_suffix(')'))
+    {
+        Some(inner) => (inner.trim(), true),
+        None => (placeholder, false),
+    };
+    let value = vars
+        .iter()
+        .find(|(var, _)| *var == name)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| format!("Unknown placeholder: {{{}}}", placeholder))?;
+    Ok(if slugged {
+        slug(value)
+    } else {
+        value.to_string()
+    })
+}
+
+/// Lowercases `text` and joins its words with dashes, dropping everything that
+/// isn't a letter or digit: "Fix: login (SSO) flow" → "fix-login-sso-flow".
+pub fn slug(text: &str) -> String {
+    let words = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase);
+    let mut slug = String::new();
+    for word in words {
+        let len = slug.chars().count();
+        if len > 0 {
+            if len + 1 + word.chars().count() > SLUG_MAX_LEN {
+                break;
+            }
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+    slug.chars().take(SLUG_MAX_LEN).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const VARS: &[(&str, &str)] = &[
+        ("id", "ABC-123"),
+        ("type", "feat"),
+        ("title", "Fix: login (SSO) flow"),
+    ];
+
+    #[test_case("{type}/{id}-{slug(title)}", "feat/ABC-123-fix-login-sso-flow"; "branch template")]
+    #[test_case("{type}({id}): ", "feat(ABC-123): "; "commit template")]
+    #[test_case("{ slug( id ) }", "abc-123"; "whitespace inside braces")]
+    #[test_case("no placeholders", "no placeholders"; "plain text")]
+    fn render_fills_placeholders(template: &str, expected: &str) {
+        assert_eq!(render(template, VARS), Ok(expected.to_string()));
+    }
+
+    #[test_case("{component}/{id}"; "unknown placeholder")]
+    #[test_case("{id"; "unclosed brace")]
+    fn render_rejects_bad_templates(template: &str) {
+        assert!(render(template, VARS).is_err());
+    }
+
+    #[test]
+    fn slug_cuts_long_titles_at_a_word() {
+        let title = "Update the rate limiting documentation for every public API endpoint";
+        let slug = slug(title);
+        assert_eq!(slug, "update-the-rate-limiting-documentation-for-every");
+        assert!(slug.len() <= SLUG_MAX_LEN);
+    }
+
+    #[test]
+    fn slug_truncates_a_single_long_word() {
+        assert_eq!(slug(&"a".repeat(80)).len(), SLUG_MAX_LEN);
+    }
+}